@@ -1,5 +1,5 @@
 import { RAFFLE_PROGRAM_ID } from "@/utils/constants";
-import { IDL, SolanaRaffle } from "@/utils/solana_raffle";
+import { IDL, SolanaRaffle } from "@/utils/atlas3_raffle";
 import { Wallet, AnchorProvider, Program } from "@coral-xyz/anchor";
 import { useAnchorWallet, useConnection } from "@solana/wallet-adapter-react";
 import { Keypair } from "@solana/web3.js";
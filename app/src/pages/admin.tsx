@@ -1,6 +1,6 @@
 import { Header } from "@/components/Header"
 import { useRaffleProgram } from "@/hooks/useRaffleProgram";
-import { findGlobalPda, shortenPublicKey } from "@/utils";
+import { findEventAuthorityPda, findGlobalPda, findWhitelistPda, shortenPublicKey } from "@/utils";
 import { useConnection, useWallet } from "@solana/wallet-adapter-react";
 import { PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram } from "@solana/web3.js";
 import { BN } from "bn.js";
@@ -10,6 +10,7 @@ import toast from "react-hot-toast";
 
 type InputField = {
   vault: string;
+  permitSigner: string;
   collection: string;
   newAuthority: string;
 };
@@ -24,6 +25,7 @@ export default function Home() {
 
   const { register, handleSubmit, watch, setValue, formState: { errors } } = useForm<InputField>();
   const vaultStr = watch('vault');
+  const permitSignerStr = watch('permitSigner');
   const newAuthStr = watch('newAuthority');
   const collectionStr = watch('collection');
 
@@ -37,10 +39,12 @@ export default function Home() {
       if (globalAccount) {
         setValue('newAuthority', globalAccount.authority.toBase58());
         setValue('vault', globalAccount.vault.toBase58());
+        setValue('permitSigner', globalAccount.permitSigner.toBase58());
 
-        const collections = globalAccount.wlCollections
-          .filter(t => !t.equals(PublicKey.default))
-          .map(t => t.toBase58());
+        const entries = await program.account.whitelistEntry.all();
+        const collections = entries
+          .filter(t => t.account.isEnabled)
+          .map(t => t.account.key.toBase58());
         setWlCollections(collections);
       }
     }
@@ -68,14 +72,18 @@ export default function Home() {
 
     try {
       const vault = new PublicKey(vaultStr);
+      const permitSigner = new PublicKey(permitSignerStr);
 
       const tx = await program.methods.initialize()
         .accounts({
           authority: publicKey,
           globalAccount: globalPda,
           vault,
+          permitSigner,
           systemProgram: SystemProgram.programId,
-          rent: SYSVAR_RENT_PUBKEY
+          rent: SYSVAR_RENT_PUBKEY,
+          eventAuthority: findEventAuthorityPda(),
+          program: program.programId,
         })
         .rpc({
           commitment: 'confirmed',
@@ -110,6 +118,7 @@ export default function Home() {
     try {
       const vault = new PublicKey(vaultStr);
       const newAuthority = new PublicKey(newAuthStr);
+      const permitSigner = new PublicKey(permitSignerStr);
 
       const tx = await program.methods.updateSetting()
         .accounts({
@@ -117,7 +126,10 @@ export default function Home() {
           newAuthority,
           globalAccount: globalPda,
           vault,
+          permitSigner,
           systemProgram: SystemProgram.programId,
+          eventAuthority: findEventAuthorityPda(),
+          program: program.programId,
         })
         .rpc({
           commitment: 'confirmed',
@@ -157,11 +169,14 @@ export default function Home() {
     try {
       const collection = new PublicKey(collectionStr);
 
-      const tx = await program.methods.addWhitelist(collection)
+      const tx = await program.methods.addWhitelist(collection, { collection: {} })
         .accounts({
           authority: publicKey,
           globalAccount: globalPda,
+          whitelistEntry: findWhitelistPda(collection),
           systemProgram: SystemProgram.programId,
+          eventAuthority: findEventAuthorityPda(),
+          program: program.programId,
         })
         .rpc({
           commitment: 'confirmed',
@@ -206,7 +221,10 @@ export default function Home() {
         .accounts({
           authority: publicKey,
           globalAccount: globalPda,
+          whitelistEntry: findWhitelistPda(collection),
           systemProgram: SystemProgram.programId,
+          eventAuthority: findEventAuthorityPda(),
+          program: program.programId,
         })
         .rpc({
           commitment: 'confirmed',
@@ -246,6 +264,11 @@ export default function Home() {
             <input type="text" className='w-full p-2' {...register("vault")} />
           </div>
 
+          <div className='flex flex-col gap-2 w-full'>
+            <label className="text-white">Permit Signer Address</label>
+            <input type="text" className='w-full p-2' {...register("permitSigner")} />
+          </div>
+
           {
             globalState &&
             <div className='flex flex-col gap-2 w-full'>
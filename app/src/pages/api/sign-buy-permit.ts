@@ -0,0 +1,89 @@
+import { bs58 } from '@coral-xyz/anchor/dist/cjs/utils/bytes';
+import { BN } from '@coral-xyz/anchor';
+import { Ed25519Program, Keypair, PublicKey } from '@solana/web3.js';
+import type { NextApiRequest, NextApiResponse } from 'next'
+import { buyPermitMessage } from '@/utils';
+import { getSession } from '@/utils/session';
+
+const PERMIT_TTL = 60;
+
+type ResponseData = {
+    message?: string
+    permit?: {
+        maxAmount: number
+        expiry: string
+        nonce: string
+    }
+    verifyIxData?: string
+}
+
+export default function handler(
+    req: NextApiRequest,
+    res: NextApiResponse<ResponseData>
+) {
+
+    if (req.method != "POST") {
+        return res.status(400).json({
+            message: "Unsupported method"
+        });
+    }
+
+    try {
+
+        // Permits are only issued to the signed in user, for their own uid and wallet
+        const session = getSession(req);
+        if (!session) {
+            return res.status(401).json({
+                message: "Unauthorized"
+            });
+        }
+
+        const { raffleIdx, uid, buyer, amount } = req.body;
+
+        if (typeof uid != "string" || uid.toLowerCase() != session.uid.toLowerCase()) {
+            return res.status(403).json({
+                message: "User id does not match session"
+            });
+        }
+        if (session.wallet && session.wallet != buyer) {
+            return res.status(403).json({
+                message: "Buyer does not match session wallet"
+            });
+        }
+        if (!/^[0-9a-fA-F]{24}$/.test(uid)
+            || !Number.isInteger(Number(raffleIdx)) || Number(raffleIdx) < 0
+            || !Number.isInteger(Number(amount)) || Number(amount) <= 0) {
+            return res.status(400).json({
+                message: "Invalid request"
+            });
+        }
+
+        const permitSignerPrivkey = process.env.PERMIT_SIGNER_PRIVKEY ?? "";
+        const permitSigner = Keypair.fromSecretKey(bs58.decode(permitSignerPrivkey));
+
+        const permit = {
+            maxAmount: Number(amount),
+            expiry: new BN(Math.floor(Date.now() / 1000) + PERMIT_TTL),
+            nonce: new BN(Date.now()),
+        };
+
+        // Only the permit message is signed, the transaction stays with the buyer
+        const verifyIx = Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: permitSigner.secretKey,
+            message: buyPermitMessage(Number(raffleIdx), uid, new PublicKey(buyer), permit),
+        });
+
+        res.status(200).json({
+            permit: {
+                maxAmount: permit.maxAmount,
+                expiry: permit.expiry.toString(),
+                nonce: permit.nonce.toString(),
+            },
+            verifyIxData: bs58.encode(verifyIx.data),
+        })
+    }
+    catch (ex: any) {
+        console.log(ex);
+        res.status(500).json({ message: ex.toString() })
+    }
+}
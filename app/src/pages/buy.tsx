@@ -1,15 +1,15 @@
 import { Header } from "@/components/Header"
 import { useRaffleProgram } from "@/hooks/useRaffleProgram";
-import { findEscrowPda, findGlobalPda, findRafflePda, findUserPda, hexStringToNumArray, shortenPublicKey } from "@/utils";
+import { findEventAuthorityPda, findGlobalPda, findRafflePda, findShardEscrowPda, findShardPda, findTokenProgramId, findUserPda, hexStringToNumArray, shardAccounts, shortenPublicKey, walletUid } from "@/utils";
 import { useConnection, useWallet } from "@solana/wallet-adapter-react";
-import { LAMPORTS_PER_SOL, SYSVAR_RENT_PUBKEY, SystemProgram, Transaction } from "@solana/web3.js";
+import { Ed25519Program, LAMPORTS_PER_SOL, SYSVAR_INSTRUCTIONS_PUBKEY, SYSVAR_RENT_PUBKEY, SystemProgram, TransactionInstruction } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 import { useForm } from "react-hook-form";
 import toast from "react-hot-toast";
 import { useEffect, useState } from "react";
 import axios from "axios";
 import { bs58 } from "@coral-xyz/anchor/dist/cjs/utils/bytes";
-import { NATIVE_MINT, TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { NATIVE_MINT, getAssociatedTokenAddressSync } from "@solana/spl-token";
 
 type InputField = {
   raffle: number;
@@ -33,7 +33,7 @@ export default function Home() {
   const fetchRaffles = async () => {
 
     if (program) {
-      const raffleAccounts = await program.account.raffleAccount.all();
+      const raffleAccounts = await program.account.raffleAccountV2.all();
       const now = Math.floor(Date.now() / 1000);
       const validAccounts = raffleAccounts.filter(m => m.account.endDate.toNumber() > now);
       console.log(validAccounts)
@@ -61,11 +61,9 @@ export default function Home() {
 
     try {
       const globalPda = findGlobalPda();
-      const globalAccount = await program.account.globalAccount.fetch(globalPda);
 
-      const uid = input.uid;
       const rafflePda = findRafflePda(new BN(raffleId));
-      const raffleAccount = await program.account.raffleAccount.fetch(rafflePda);
+      const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
       if (!raffleAccount.isDeposited) {
         toast.error("Prize not deposited yet.", {
           id: toastId
@@ -73,41 +71,79 @@ export default function Home() {
         return;
       }
 
-      const splMint = raffleAccount.splMint || NATIVE_MINT;
+      // Public raffles key users by wallet and need no permit
+      const uid = raffleAccount.isPublic ? walletUid(publicKey) : input.uid;
+
+      const splMint = raffleAccount.splMint;
       const isNative = splMint.equals(NATIVE_MINT);
+      const tokenProgram = await findTokenProgramId(connection, splMint);
 
       const userPda = findUserPda(uid, rafflePda);
-      const escrowPda = findEscrowPda(rafflePda);
 
-      const tx = await program.methods.buyTicket(raffleId, hexStringToNumArray(uid), amount)
+      // Spread purchases over shards with room left, so buyers don't contend on one account
+      const shards = await program.account.ticketShard.fetchMultiple(
+        shardAccounts(Number(raffleId), raffleAccount.totalShards).map(t => t.pubkey)
+      );
+      const openShards = shards
+        .map((ticketShard, shard) => ({ ticketShard, shard }))
+        .filter(t => t.ticketShard && t.ticketShard.totalSales + Number(amount) <= t.ticketShard.capacity);
+      if (openShards.length == 0) {
+        toast.error("Not enough tickets left.", {
+          id: toastId
+        });
+        return;
+      }
+      const shard = openShards[Math.floor(Math.random() * openShards.length)].shard;
+
+      let permit = null;
+      const preInstructions: TransactionInstruction[] = [];
+      if (!raffleAccount.isPublic) {
+        // Purchase permit issued by backend for the signed in user, no admin signature required
+        const { data } = await axios.post('/api/sign-buy-permit', {
+          raffleIdx: raffleId,
+          uid,
+          buyer: publicKey.toBase58(),
+          amount,
+        });
+        permit = {
+          maxAmount: data.permit.maxAmount,
+          expiry: new BN(data.permit.expiry),
+          nonce: new BN(data.permit.nonce),
+        };
+        preInstructions.push(new TransactionInstruction({
+          programId: Ed25519Program.programId,
+          keys: [],
+          data: Buffer.from(bs58.decode(data.verifyIxData)),
+        }));
+      }
+
+      const tx = await program.methods.buyTicket(raffleId, hexStringToNumArray(uid), amount, shard, permit)
         .accounts({
-          authority: globalAccount.authority,
           buyer: publicKey,
           globalAccount: globalPda,
           raffleAccount: rafflePda,
+          ticketShard: findShardPda(Number(raffleId), shard),
           userAccount: userPda,
           splMint,
-          userTokenAccount: isNative ? publicKey : getAssociatedTokenAddressSync(splMint, publicKey),
-          escrowAccount: escrowPda,
-          vaultTokenAccount: isNative ? globalAccount.vault : getAssociatedTokenAddressSync(splMint, globalAccount.vault),
+          userTokenAccount: isNative ? publicKey : getAssociatedTokenAddressSync(splMint, publicKey, false, tokenProgram),
+          escrowAccount: findShardEscrowPda(rafflePda, shard),
+          referralAccount: null,
+          referralEscrow: null,
+          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
           systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenProgram,
           rent: SYSVAR_RENT_PUBKEY,
+          eventAuthority: findEventAuthorityPda(),
+          program: program.programId,
         })
+        .preInstructions(preInstructions)
         .transaction();
 
       const latestestBlockhash = await connection.getLatestBlockhash();
       tx.recentBlockhash = latestestBlockhash.blockhash;
       tx.feePayer = publicKey;
 
-      const serializedTx = tx.serialize({
-        requireAllSignatures: false
-      });
-      const { data } = await axios.post('/api/sign-buy-tx', {
-        serializedTx: bs58.encode(serializedTx)
-      })
-      const signedTx = Transaction.from(bs58.decode(data.tx));
-      const walletSignedTx = await signTransaction(signedTx);
+      const walletSignedTx = await signTransaction(tx);
       const signature = await connection.sendRawTransaction(walletSignedTx.serialize(), {
         preflightCommitment: 'confirmed',
         maxRetries: 10
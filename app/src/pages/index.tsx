@@ -1,8 +1,8 @@
 import { Header } from "@/components/Header"
 import { useRaffleProgram } from "@/hooks/useRaffleProgram";
-import { findGlobalPda, findRafflePda } from "@/utils";
+import { findEventAuthorityPda, findGlobalPda, findRafflePda, findShardPda } from "@/utils";
 import { useConnection, useWallet } from "@solana/wallet-adapter-react";
-import { LAMPORTS_PER_SOL, PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram } from "@solana/web3.js";
+import { LAMPORTS_PER_SOL, PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram, Transaction } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 import { useForm } from "react-hook-form";
 import toast from "react-hot-toast";
@@ -11,6 +11,11 @@ import { NATIVE_MINT } from "@solana/spl-token";
 
 type InputField = {
   totalSupply: number;
+  totalPrizes: number;
+  totalShards: number;
+  minSales: number;
+  maxTicketPercent: string;
+  isPublic: boolean;
   price: number;
   splMint: string;
   startDate: string;
@@ -26,6 +31,9 @@ export default function Home() {
   const { register, handleSubmit, reset, formState: { errors } } = useForm<InputField>({
     defaultValues: {
       splMint: NATIVE_MINT.toBase58(),
+      totalPrizes: 1,
+      totalShards: 1,
+      minSales: 0,
     }
   });
 
@@ -56,14 +64,31 @@ export default function Home() {
       const endDate = Math.floor(moment(data.endDate).toDate().getTime() / 1000);
       const price = new BN(data.price * LAMPORTS_PER_SOL);
 
-      const tx = await program.methods.createRaffle(raffleIdx, data.totalSupply, price, new BN(startDate), new BN(endDate))
+      const totalShards = Number(data.totalShards);
+      // Wallet cap in basis points, the global one when left empty
+      const maxTicketPercent = data.maxTicketPercent ? Number(data.maxTicketPercent) : null;
+
+      const tx = await program.methods.createRaffle(
+        raffleIdx,
+        Number(data.totalSupply),
+        Number(data.totalPrizes),
+        price,
+        new BN(startDate),
+        new BN(endDate),
+        Number(data.minSales),
+        totalShards,
+        data.isPublic,
+        maxTicketPercent,
+      )
         .accounts({
           authority: publicKey,
           globalAccount: globalPda,
           raffleAccount: rafflePda,
           splMint,
           systemProgram: SystemProgram.programId,
-          rent: SYSVAR_RENT_PUBKEY
+          rent: SYSVAR_RENT_PUBKEY,
+          eventAuthority: findEventAuthorityPda(),
+          program: program.programId,
         })
         .rpc({
           commitment: 'confirmed',
@@ -72,6 +97,26 @@ export default function Home() {
         });
       console.log(tx);
 
+      // Tickets are sold only once every shard exists
+      const shardIxs = await Promise.all([...Array(totalShards).keys()].map(shard =>
+        program.methods.createShard(raffleIdx, shard)
+          .accounts({
+            authority: publicKey,
+            raffleAccount: rafflePda,
+            ticketShard: findShardPda(raffleIdx, shard),
+            systemProgram: SystemProgram.programId,
+            eventAuthority: findEventAuthorityPda(),
+            program: program.programId,
+          })
+          .instruction()
+      ));
+      const shardTx = await program.provider.sendAndConfirm!(new Transaction().add(...shardIxs), [], {
+        commitment: 'confirmed',
+        preflightCommitment: 'confirmed',
+        maxRetries: 10,
+      });
+      console.log(shardTx);
+
       toast.success("Raffle register successed.", {
         id: toastId
       });
@@ -104,6 +149,31 @@ export default function Home() {
             <input type="number" className='w-full p-2' {...register("totalSupply")} />
           </div>
 
+          <div className='flex flex-col gap-2 w-full'>
+            <label className="text-white">Total Prizes</label>
+            <input type="number" className='w-full p-2' {...register("totalPrizes")} />
+          </div>
+
+          <div className='flex flex-col gap-2 w-full'>
+            <label className="text-white">Ticket Shards</label>
+            <input type="number" className='w-full p-2' {...register("totalShards")} />
+          </div>
+
+          <div className='flex flex-col gap-2 w-full'>
+            <label className="text-white">Min Sales</label>
+            <input type="number" className='w-full p-2' {...register("minSales")} />
+          </div>
+
+          <div className='flex flex-col gap-2 w-full'>
+            <label className="text-white">Max Ticket Percent (bps)</label>
+            <input type="text" className='w-full p-2' {...register("maxTicketPercent")} />
+          </div>
+
+          <div className='flex items-center gap-2 w-full'>
+            <input type="checkbox" {...register("isPublic")} />
+            <label className="text-white">Public</label>
+          </div>
+
           <div className='flex flex-col gap-2 w-full'>
             <label className="text-white">Price</label>
             <input type="text" className='w-full p-2' {...register("price")} />
@@ -1,6 +1,6 @@
 import { Header } from "@/components/Header"
 import { useRaffleProgram } from "@/hooks/useRaffleProgram";
-import { AUTHRULE_PROGRAM_ID, METADATA_PROGRAM_ID, byteArrayToHexString, findEditionPda, findEscrowPda, findGlobalPda, findMetadataPda, findRafflePda, findTokenRecordPda, findUserPda, hexStringToNumArray, shortenPublicKey } from "@/utils";
+import { AUTHRULE_PROGRAM_ID, METADATA_PROGRAM_ID, findEditionPda, findEscrowPda, findEventAuthorityPda, findFeeConfigPda, findGlobalFeeConfigPda, findGlobalPda, findMetadataPda, findPrizeWhitelistPda, findRafflePda, findShardEscrowPda, findShardPda, findTicketUid, findTokenProgramId, findTokenRecordPda, findUserPda, hexStringToNumArray, shardEscrowAccounts, shortenPublicKey } from "@/utils";
 import { RAFFLE_PROGRAM_ID } from "@/utils/constants";
 import { Metadata, Metaplex } from "@metaplex-foundation/js";
 import { MPL_TOKEN_METADATA_PROGRAM_ID, TokenStandard } from "@metaplex-foundation/mpl-token-metadata";
@@ -17,6 +17,11 @@ type InputField = {
   prize: string;
 };
 
+// `RandomnessSource` and `PrizeKind` discriminants of the program
+const RANDOMNESS_SOURCE_NONE = 0;
+const PRIZE_KIND_NFT = 0;
+const PRIZE_KIND_PNFT = 1;
+
 type NftField = {
   mint: string;
   name: string;
@@ -40,7 +45,7 @@ export default function Home() {
 
   const fetchRaffles = async () => {
     if (program) {
-      const raffleAccounts = await program.account.raffleAccount.all();
+      const raffleAccounts = await program.account.raffleAccountV2.all();
       setRaffleAccounts(raffleAccounts);
     }
     else {
@@ -113,15 +118,15 @@ export default function Home() {
 
     try {
       const rafflePda = findRafflePda(new BN(raffleId));
-      const raffleAccount = await program.account.raffleAccount.fetch(rafflePda);
+      const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
       if (raffleAccount.isWithdrawn) {
         toast.error(`Funds already withdrawn.`, {
           id: toastId
         });
         return;
       }
-      if (raffleAccount.endDate.toNumber() > Date.now()) {
-        toast.error(`Reaffle not ends.`, {
+      if (!raffleAccount.isRevealed) {
+        toast.error(`Winners not revealed.`, {
           id: toastId
         });
         return;
@@ -133,21 +138,67 @@ export default function Home() {
         return;
       }
 
-      const splMint = raffleAccount.splMint || NATIVE_MINT;
+      const splMint = raffleAccount.splMint;
+      const isNative = splMint.equals(NATIVE_MINT);
+      const tokenProgram = await findTokenProgramId(connection, splMint);
 
-      const escrowPda = findEscrowPda(rafflePda);
+      // Fees held by shard escrows are swept first, to the split in effect or the vault
+      const globalPda = findGlobalPda();
+      const globalAccount = await program.account.globalAccount.fetch(globalPda);
+      const raffleFeeConfig = raffleAccount.hasFeeConfig ? findFeeConfigPda(rafflePda) : null;
+      const globalFeeConfig = !raffleAccount.hasFeeConfig && raffleAccount.feeConfigVersion ? findGlobalFeeConfigPda(raffleAccount.feeConfigVersion) : null;
+      const feeConfig = raffleFeeConfig ?? globalFeeConfig;
+      const feeConfigAccount = feeConfig ? await program.account.feeConfig.fetch(feeConfig) : null;
+      const feeRecipients = feeConfigAccount ? feeConfigAccount.recipients.slice(0, feeConfigAccount.totalRecipients) : [];
+      const feeAccounts = feeRecipients.map(r => ({
+        pubkey: isNative ? r.wallet : getAssociatedTokenAddressSync(splMint, r.wallet, false, tokenProgram),
+        isSigner: false,
+        isWritable: true,
+      }));
+
+      for (let shard = 0; shard < raffleAccount.totalShards; shard++) {
+        const ticketShard = await program.account.ticketShard.fetch(findShardPda(Number(raffleId), shard));
+        if (ticketShard.fees.isZero()) {
+          continue;
+        }
+
+        await program.methods.sweepFees(raffleId, shard)
+          .accounts({
+            payer: publicKey,
+            globalAccount: globalPda,
+            raffleAccount: rafflePda,
+            ticketShard: findShardPda(Number(raffleId), shard),
+            splMint,
+            escrowAccount: findShardEscrowPda(rafflePda, shard),
+            vaultTokenAccount: isNative ? globalAccount.vault : getAssociatedTokenAddressSync(splMint, globalAccount.vault, false, tokenProgram),
+            raffleFeeConfig,
+            globalFeeConfig,
+            systemProgram: SystemProgram.programId,
+            tokenProgram,
+            eventAuthority: findEventAuthorityPda(),
+            program: program.programId,
+          })
+          .remainingAccounts(feeAccounts)
+          .rpc({
+            commitment: 'confirmed',
+            preflightCommitment: 'confirmed',
+            maxRetries: 10,
+          });
+      }
 
       const tx = await program.methods.withdrawRaffle(raffleId)
         .accounts({
           authority: publicKey,
           raffleAccount: rafflePda,
           splMint,
-          treasuryTokenAccount: splMint.equals(NATIVE_MINT) ? publicKey : getAssociatedTokenAddressSync(splMint, publicKey),
-          escrowAccount: escrowPda,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          treasuryTokenAccount: isNative ? publicKey : getAssociatedTokenAddressSync(splMint, publicKey, false, tokenProgram),
+          tokenProgram,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
+          eventAuthority: findEventAuthorityPda(),
+          program: program.programId,
         })
+        .remainingAccounts(shardEscrowAccounts(Number(raffleId), raffleAccount.totalShards))
         .rpc({
           commitment: 'confirmed',
           preflightCommitment: 'confirmed',
@@ -181,11 +232,14 @@ export default function Home() {
 
       const nftItem = walletNfts?.filter(t => t.mint == prizeNft)[0];
 
-      const globalPda = findGlobalPda();
       const rafflePda = findRafflePda(new BN(raffleId));
-      const raffleAccount = await program.account.raffleAccount.fetch(rafflePda);
-      if (raffleAccount.isDeposited) {
-        toast.error(`Prize already deposited.`, {
+      const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+      // Prizes fill the first empty slot
+      const slot = raffleAccount.prizes
+        .slice(0, raffleAccount.totalPrizes)
+        .findIndex(prize => !prize.isDeposited);
+      if (slot < 0) {
+        toast.error(`Prizes already deposited.`, {
           id: toastId
         });
         return;
@@ -198,6 +252,7 @@ export default function Home() {
       }
 
       const escrowPda = findEscrowPda(rafflePda);
+      const whitelistEntry = await findPrizeWhitelistPda(connection, mint);
       const tokenAccount = getAssociatedTokenAddressSync(mint, publicKey);
       const metadata = findMetadataPda(mint);
       const edition = findEditionPda(mint);
@@ -206,11 +261,11 @@ export default function Home() {
         const tokenRecord = findTokenRecordPda(mint, tokenAccount);
         const delegateRecord = findTokenRecordPda(mint, escrowPda);
 
-        const tx = await program.methods.addPrizePnft(raffleId)
+        const tx = await program.methods.addPrizePnft(raffleId, slot)
           .accounts({
             authority: publicKey,
-            globalAccount: globalPda,
             raffleAccount: rafflePda,
+            whitelistEntry,
             escrowAccount: escrowPda,
             mint,
             metadata,
@@ -224,6 +279,8 @@ export default function Home() {
             sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
             authorizationRules: RAFFLE_PROGRAM_ID,
             authorizationRulesProgram: AUTHRULE_PROGRAM_ID,
+            eventAuthority: findEventAuthorityPda(),
+            program: program.programId,
           })
           .rpc({
             commitment: 'confirmed',
@@ -233,11 +290,11 @@ export default function Home() {
         console.log(tx);
       }
       else {
-        const tx = await program.methods.addPrize(raffleId)
+        const tx = await program.methods.addPrize(raffleId, slot)
           .accounts({
             authority: publicKey,
-            globalAccount: globalPda,
             raffleAccount: rafflePda,
+            whitelistEntry,
             escrowAccount: escrowPda,
             mint,
             metadata,
@@ -247,6 +304,8 @@ export default function Home() {
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
             rent: SYSVAR_RENT_PUBKEY,
+            eventAuthority: findEventAuthorityPda(),
+            program: program.programId,
           })
           .rpc({
             commitment: 'confirmed',
@@ -257,7 +316,7 @@ export default function Home() {
       }
 
       fetchRaffles();
-      toast.success("Add prize successed.", {
+      toast.success(`Add prize #${slot + 1} successed.`, {
         id: toastId
       });
     }
@@ -280,32 +339,58 @@ export default function Home() {
     try {
       const globalPda = findGlobalPda();
       const rafflePda = findRafflePda(new BN(raffleId));
-      const raffleAccount = await program.account.raffleAccount.fetch(rafflePda);
-      if (raffleAccount.winnerIdx > 0) {
+      let raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+      if (raffleAccount.isRevealed) {
         toast.error(`Already revealed winner.`, {
           id: toastId
         });
         return;
       }
-      if (raffleAccount.endDate.toNumber() > Date.now()) {
-        toast.error(`Reaffle not ends.`, {
-          id: toastId
-        });
-        return;
+
+      // Commit to a future slot hash, anyone can once the raffle ended
+      if (raffleAccount.randomness.source == RANDOMNESS_SOURCE_NONE) {
+        const splMint = raffleAccount.splMint;
+        const tokenProgram = await findTokenProgramId(connection, splMint);
+
+        const tx = await program.methods.requestRandomness(raffleId, { slotHash: {} })
+          .accounts({
+            payer: publicKey,
+            globalAccount: globalPda,
+            raffleAccount: rafflePda,
+            splMint,
+            payerTokenAccount: splMint.equals(NATIVE_MINT) ? publicKey : getAssociatedTokenAddressSync(splMint, publicKey, false, tokenProgram),
+            oracleRequest: null,
+            committedRequest: null,
+            recentSlothashes: SYSVAR_SLOT_HASHES_PUBKEY,
+            systemProgram: SystemProgram.programId,
+            tokenProgram,
+            eventAuthority: findEventAuthorityPda(),
+            program: program.programId,
+          })
+          .remainingAccounts(shardEscrowAccounts(Number(raffleId), raffleAccount.totalShards))
+          .rpc({
+            commitment: 'confirmed',
+            preflightCommitment: 'confirmed',
+            maxRetries: 10,
+          });
+        console.log(tx);
+
+        raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
       }
-      if (!publicKey.equals(globalState.authority)) {
-        toast.error(`You need to connect ${shortenPublicKey(globalState.authority.toBase58())}.`, {
-          id: toastId
-        });
-        return;
+
+      // Committed slot hash is available once the slot has passed
+      while (await connection.getSlot('confirmed') <= raffleAccount.randomness.slot.toNumber()) {
+        await new Promise(resolve => setTimeout(resolve, 400));
       }
 
-      const tx = await program.methods.revealWinner(raffleId)
+      const tx = await program.methods.settleRandomness(raffleId)
         .accounts({
-          authority: publicKey,
-          globalAccount: globalPda,
+          payer: publicKey,
           raffleAccount: rafflePda,
+          oracleRequest: null,
           recentSlothashes: SYSVAR_SLOT_HASHES_PUBKEY,
+          eventAuthority: findEventAuthorityPda(),
+          program: program.programId,
         })
         .rpc({
           commitment: 'confirmed',
@@ -339,43 +424,48 @@ export default function Home() {
       const rafflePda = findRafflePda(new BN(raffleId));
       const escrowPda = findEscrowPda(rafflePda);
 
-      const raffleAccount = await program.account.raffleAccount.fetch(rafflePda);
-      if (raffleAccount.winnerIdx == 0) {
+      const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+      if (!raffleAccount.isRevealed) {
         toast.error("Winner not revealed.", {
           id: toastId
         });
         return;
       }
-      if (raffleAccount.isClaimed) {
-        toast.error("Prize already claimed.", {
-          id: toastId
-        });
-        return;
-      }
 
-      const winnerUid = byteArrayToHexString(raffleAccount.tickets[raffleAccount.winnerIdx - 1].uid);
-      const userPda = findUserPda(winnerUid, rafflePda);
-      const userAccount = await program.account.userAccount.fetch(userPda);
-      if (!publicKey.equals(userAccount.authority)) {
-        toast.error(`You need to connect ${shortenPublicKey(userAccount.authority.toBase58())}.`, {
+      // First unclaimed nft prize won by a user of the connected wallet
+      let winner = null;
+      for (let slot = 0; slot < raffleAccount.totalPrizes && !winner; slot++) {
+        const prize = raffleAccount.prizes[slot];
+        if (prize.isClaimed || (prize.kind != PRIZE_KIND_NFT && prize.kind != PRIZE_KIND_PNFT)) {
+          continue;
+        }
+
+        const ticket = await findTicketUid(program, Number(raffleId), prize.winnerIdx - 1);
+        if (!ticket) {
+          continue;
+        }
+        const userPda = findUserPda(ticket.uid, rafflePda);
+        const userAccount = await program.account.userAccount.fetch(userPda);
+        if (publicKey.equals(userAccount.authority)) {
+          winner = { slot, prize, userPda, ...ticket };
+        }
+      }
+      if (!winner) {
+        toast.error("No prize to claim for this wallet.", {
           id: toastId
         });
         return;
       }
 
-      const mint = raffleAccount.mint;
-
-      const mplex = new Metaplex(connection);
-      const nftItem = await mplex.nfts().findByMint({
-        mintAddress: mint
-      });
+      const { slot, prize, userPda, uid: winnerUid } = winner;
+      const ticketShard = findShardPda(Number(raffleId), winner.shard);
+      const mint = prize.mint;
 
       const creatorAta = getAssociatedTokenAddressSync(mint, raffleAccount.authority);
       const metadata = findMetadataPda(mint);
       const edition = findEditionPda(mint);
       const owner = raffleAccount.authority;
-      console.log(nftItem)
-      if (nftItem.tokenStandard == TokenStandard.ProgrammableNonFungible) {
+      if (prize.kind == PRIZE_KIND_PNFT) {
         const ownerTokenAccount = getAssociatedTokenAddressSync(mint, owner);
         const destTokenAta = getAssociatedTokenAddressSync(mint, publicKey);
         const ownerTokenRecord = findTokenRecordPda(mint, ownerTokenAccount);
@@ -388,10 +478,11 @@ export default function Home() {
           ataIxs.push(createAtaIx);
         }
 
-        const tx = await program.methods.claimPrizePnft(raffleId, hexStringToNumArray(winnerUid))
+        const tx = await program.methods.claimPrizePnft(raffleId, slot, hexStringToNumArray(winnerUid))
           .accounts({
             authority: publicKey,
             raffleAccount: rafflePda,
+            ticketShard,
             escrowAccount: escrowPda,
             userAccount: userPda,
             mint,
@@ -409,6 +500,8 @@ export default function Home() {
             sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
             authorizationRules: METADATA_PROGRAM_ID,
             authorizationRulesProgram: AUTHRULE_PROGRAM_ID,
+            eventAuthority: findEventAuthorityPda(),
+            program: program.programId,
           })
           .preInstructions(ataIxs)
           .rpc({
@@ -427,10 +520,11 @@ export default function Home() {
           ataIxs.push(createAtaIx);
         }
 
-        const tx = await program.methods.claimPrize(raffleId, hexStringToNumArray(winnerUid))
+        const tx = await program.methods.claimPrize(raffleId, slot, hexStringToNumArray(winnerUid))
           .accounts({
             authority: publicKey,
             raffleAccount: rafflePda,
+            ticketShard,
             userAccount: userPda,
             escrowAccount: escrowPda,
             mint,
@@ -442,6 +536,8 @@ export default function Home() {
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
             rent: SYSVAR_RENT_PUBKEY,
+            eventAuthority: findEventAuthorityPda(),
+            program: program.programId,
           })
           .preInstructions(ataIxs)
           .rpc({
@@ -453,7 +549,7 @@ export default function Home() {
       }
 
       fetchRaffles();
-      toast.success("Claim prize successed.", {
+      toast.success(`Claim prize #${slot + 1} successed.`, {
         id: toastId
       });
     }
@@ -15,6 +15,11 @@ export type SolanaRaffle = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "permitSigner",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "globalAccount",
           "isMut": true,
@@ -29,6 +34,16 @@ export type SolanaRaffle = {
           "name": "rent",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
@@ -51,6 +66,11 @@ export type SolanaRaffle = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "permitSigner",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "globalAccount",
           "isMut": true,
@@ -60,12 +80,22 @@ export type SolanaRaffle = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "addWhitelist",
+      "name": "updateFees",
       "accounts": [
         {
           "name": "authority",
@@ -78,20 +108,37 @@ export type SolanaRaffle = {
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "collection",
-          "type": "publicKey"
+          "name": "feePercent",
+          "type": "u16"
+        },
+        {
+          "name": "maxTicketPercent",
+          "type": "u16"
+        },
+        {
+          "name": "referralPercent",
+          "type": "u16"
+        },
+        {
+          "name": "crankRewardPercent",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "removeWhitelist",
+      "name": "setFeeConfig",
       "accounts": [
         {
           "name": "authority",
@@ -103,21 +150,44 @@ export type SolanaRaffle = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "feeConfig",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Next version of the global split, omitted when clearing it"
+          ]
+        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "collection",
-          "type": "publicKey"
+          "name": "recipients",
+          "type": {
+            "vec": {
+              "defined": "FeeRecipient"
+            }
+          }
         }
       ]
     },
     {
-      "name": "createRaffle",
+      "name": "setRaffleFeeConfig",
       "accounts": [
         {
           "name": "authority",
@@ -126,16 +196,16 @@ export type SolanaRaffle = {
         },
         {
           "name": "globalAccount",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "splMint",
-          "isMut": false,
+          "name": "raffleAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "raffleAccount",
+          "name": "feeConfig",
           "isMut": true,
           "isSigner": false
         },
@@ -145,7 +215,12 @@ export type SolanaRaffle = {
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
@@ -156,25 +231,17 @@ export type SolanaRaffle = {
           "type": "u32"
         },
         {
-          "name": "totalSupply",
-          "type": "u32"
-        },
-        {
-          "name": "price",
-          "type": "u64"
-        },
-        {
-          "name": "startDate",
-          "type": "u64"
-        },
-        {
-          "name": "endDate",
-          "type": "u64"
+          "name": "recipients",
+          "type": {
+            "vec": {
+              "defined": "FeeRecipient"
+            }
+          }
         }
       ]
     },
     {
-      "name": "addPrize",
+      "name": "addWhitelist",
       "accounts": [
         {
           "name": "authority",
@@ -187,38 +254,55 @@ export type SolanaRaffle = {
           "isSigner": false
         },
         {
-          "name": "raffleAccount",
+          "name": "whitelistEntry",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "edition",
+          "name": "program",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "key",
+          "type": "publicKey"
         },
         {
-          "name": "tokenAccount",
+          "name": "kind",
+          "type": {
+            "defined": "WhitelistKind"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeWhitelist",
+      "accounts": [
+        {
+          "name": "authority",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "escrowAccount",
-          "isMut": true,
+          "name": "globalAccount",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadataProgram",
-          "isMut": false,
+          "name": "whitelistEntry",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -227,25 +311,25 @@ export type SolanaRaffle = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "idx",
-          "type": "u32"
+          "name": "key",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "addPrizePnft",
+      "name": "migrateWhitelist",
       "accounts": [
         {
           "name": "authority",
@@ -254,76 +338,77 @@ export type SolanaRaffle = {
         },
         {
           "name": "globalAccount",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "raffleAccount",
+          "name": "whitelistEntry",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadata",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "edition",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenRecord",
-          "isMut": true,
+          "name": "program",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "delegateRecord",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "collection",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "createRaffle",
+      "accounts": [
         {
-          "name": "tokenAccount",
+          "name": "authority",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "escrowAccount",
+          "name": "globalAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authorizationRules",
+          "name": "splMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sysvarInstructions",
-          "isMut": false,
+          "name": "raffleAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadataProgram",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRulesProgram",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
@@ -332,69 +417,77 @@ export type SolanaRaffle = {
         {
           "name": "idx",
           "type": "u32"
-        }
-      ]
-    },
-    {
-      "name": "buyTicket",
-      "accounts": [
+        },
         {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
+          "name": "totalSupply",
+          "type": "u32"
         },
         {
-          "name": "buyer",
-          "isMut": true,
-          "isSigner": true
+          "name": "totalPrizes",
+          "type": "u8"
         },
         {
-          "name": "globalAccount",
-          "isMut": false,
-          "isSigner": false
+          "name": "price",
+          "type": "u64"
         },
         {
-          "name": "raffleAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "startDate",
+          "type": "u64"
         },
         {
-          "name": "userAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "endDate",
+          "type": "u64"
         },
         {
-          "name": "splMint",
-          "isMut": false,
-          "isSigner": false
+          "name": "minSales",
+          "type": "u32"
         },
         {
-          "name": "userTokenAccount",
+          "name": "totalShards",
+          "type": "u8"
+        },
+        {
+          "name": "isPublic",
+          "type": "bool"
+        },
+        {
+          "name": "maxTicketPercent",
+          "type": {
+            "option": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createShard",
+      "accounts": [
+        {
+          "name": "authority",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "vaultTokenAccount",
+          "name": "raffleAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "escrowAccount",
+          "name": "ticketShard",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
@@ -405,22 +498,13 @@ export type SolanaRaffle = {
           "type": "u32"
         },
         {
-          "name": "uid",
-          "type": {
-            "array": [
-              "u8",
-              12
-            ]
-          }
-        },
-        {
-          "name": "amount",
-          "type": "u32"
+          "name": "shard",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "withdrawRaffle",
+      "name": "addPrize",
       "accounts": [
         {
           "name": "authority",
@@ -433,45 +517,79 @@ export type SolanaRaffle = {
           "isSigner": false
         },
         {
-          "name": "splMint",
+          "name": "mint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "treasuryTokenAccount",
-          "isMut": true,
+          "name": "whitelistEntry",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "escrowAccount",
-          "isMut": true,
+          "name": "metadata",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "edition",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "rent",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
           "name": "idx",
           "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "revealWinner",
+      "name": "addPrizePnft",
       "accounts": [
         {
           "name": "authority",
@@ -479,17 +597,87 @@ export type SolanaRaffle = {
           "isSigner": true
         },
         {
-          "name": "globalAccount",
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "raffleAccount",
+          "name": "whitelistEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "recentSlothashes",
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "delegateRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sysvarInstructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
@@ -498,11 +686,15 @@ export type SolanaRaffle = {
         {
           "name": "idx",
           "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "claimPrize",
+      "name": "addPrizeToken",
       "accounts": [
         {
           "name": "authority",
@@ -515,43 +707,77 @@ export type SolanaRaffle = {
           "isSigner": false
         },
         {
-          "name": "userAccount",
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "edition",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "creatorTokenAccount",
-          "isMut": true,
+          "name": "eventAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "winnerTokenAccount",
-          "isMut": true,
+          "name": "program",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
         },
         {
-          "name": "escrowAccount",
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "addPrizeSol",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metadataProgram",
-          "isMut": false,
+          "name": "prizeVault",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -560,12 +786,12 @@ export type SolanaRaffle = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
@@ -576,18 +802,17 @@ export type SolanaRaffle = {
           "type": "u32"
         },
         {
-          "name": "uid",
-          "type": {
-            "array": [
-              "u8",
-              12
-            ]
-          }
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "claimPrizePnft",
+      "name": "addPrize2022",
       "accounts": [
         {
           "name": "authority",
@@ -600,87 +825,118 @@ export type SolanaRaffle = {
           "isSigner": false
         },
         {
-          "name": "userAccount",
-          "isMut": true,
+          "name": "whitelistEntry",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "mint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "metadata",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "tokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "edition",
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerTokenRecord",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerTokenAccount",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "destTokenRecord",
-          "isMut": true,
+          "name": "eventAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "destTokenAccount",
-          "isMut": true,
+          "name": "program",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
         },
         {
-          "name": "escrowAccount",
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addPrizeCore",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authorizationRules",
+          "name": "whitelistEntry",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sysvarInstructions",
-          "isMut": false,
+          "name": "asset",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "collection",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ataProgram",
+          "name": "coreProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadataProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRulesProgram",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
@@ -691,37 +947,8118 @@ export type SolanaRaffle = {
           "type": "u32"
         },
         {
-          "name": "uid",
-          "type": {
-            "array": [
-              "u8",
-              12
-            ]
-          }
+          "name": "slot",
+          "type": "u8"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "globalAccount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "vault",
-            "type": "publicKey"
-          },
+      "name": "addPrizeCnft",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressedPrize",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "MetadataArgs"
+          }
+        },
+        {
+          "name": "nonce",
+          "type": "u64"
+        },
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "buyTicket",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Referrer of the purchase, credited a slice of the fee"
+          ]
+        },
+        {
+          "name": "referralEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sysvarInstructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u32"
+        },
+        {
+          "name": "shard",
+          "type": "u8"
+        },
+        {
+          "name": "permit",
+          "type": {
+            "option": {
+              "defined": "BuyPermit"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "sweepFees",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "raffleFeeConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Fee split of the raffle, when it has one"
+          ]
+        },
+        {
+          "name": "globalFeeConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Global fee split version snapshot by the raffle, when it has none"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "shard",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "withdrawRaffle",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "pushProceeds",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "requestRandomness",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracleRequest",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "committedRequest",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "recentSlothashes",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "source",
+          "type": {
+            "defined": "RandomnessSource"
+          }
+        }
+      ]
+    },
+    {
+      "name": "settleRandomness",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracleRequest",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "recentSlothashes",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "claimPrize",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimPrizePnft",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sysvarInstructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimPrizeToken",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimPrizeSol",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimPrize2022",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimPrizeCore",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coreProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimPrizeCnft",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "compressedPrize",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        },
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrize",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrizePnft",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sysvarInstructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrizeToken",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrizeSol",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrize2022",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrizeCore",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coreProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrizeCnft",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "compressedPrize",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        },
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "initReferral",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referralEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimReferral",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "referralAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelRaffle",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "refundTicket",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrize",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrizePnft",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sysvarInstructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrizeToken",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrizeSol",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrize2022",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrizeCore",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coreProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrizeCnft",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "compressedPrize",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "globalAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "vault",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalRaffles",
+            "type": "u32"
+          },
+          {
+            "name": "wlCollections",
+            "docs": [
+              "Legacy whitelist, drained into [`WhitelistEntry`] accounts by `migrate_whitelist`"
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                10
+              ]
+            }
+          },
+          {
+            "name": "permitSigner",
+            "docs": [
+              "Off-chain key signing purchase permits"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feePercent",
+            "docs": [
+              "Platform fee in basis points, snapshot by new raffles.",
+              "Read through [`GlobalAccount::platform_fee_percent`], unset on older deployments"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxTicketPercent",
+            "docs": [
+              "Default per-wallet ticket cap in basis points of total supply, 0 for no cap"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "hasFeeConfig",
+            "docs": [
+              "Fee of new raffles is split by the global [`FeeConfig`]"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "referralPercent",
+            "docs": [
+              "Slice of the fee credited to referrer, in basis points of the fee"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "crankRewardPercent",
+            "docs": [
+              "Paid from escrow to whoever requests the draw of an ended raffle,",
+              "in basis points of the proceeds"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feesInitialized",
+            "docs": [
+              "Fee and wallet cap were written by `initialize` or `update_fees`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "feeConfigVersion",
+            "docs": [
+              "Version of the latest global [`FeeConfig`], each split is a new account"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "reserved2",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "reserved3",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "raffleAccountV2",
+      "docs": [
+        "Raffle header, tickets are stored across `total_shards` [`TicketShard`] accounts.",
+        "",
+        "Versioned name gives the zero-copy layout its own discriminator, so raffles",
+        "created with the former Borsh `RaffleAccount` fail to load instead of being",
+        "read with the wrong layout. Those have to be settled and withdrawn with the",
+        "program release which created them before upgrading; their idx is never reused",
+        "as `total_raffles` keeps counting up."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "splMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "startDate",
+            "type": "u64"
+          },
+          {
+            "name": "endDate",
+            "type": "u64"
+          },
+          {
+            "name": "idx",
+            "type": "u32"
+          },
+          {
+            "name": "totalSupply",
+            "type": "u32"
+          },
+          {
+            "name": "totalSales",
+            "docs": [
+              "Sum of shard sales as of the last sync"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "minSales",
+            "type": "u32"
+          },
+          {
+            "name": "feePercent",
+            "docs": [
+              "Platform fee snapshot at creation, in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxTicketPercent",
+            "docs": [
+              "Per-wallet ticket cap in basis points of total supply, 0 for no cap"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "totalPrizes",
+            "type": "u8"
+          },
+          {
+            "name": "totalShards",
+            "type": "u8"
+          },
+          {
+            "name": "isDeposited",
+            "type": "u8"
+          },
+          {
+            "name": "isRevealed",
+            "type": "u8"
+          },
+          {
+            "name": "isWithdrawn",
+            "type": "u8"
+          },
+          {
+            "name": "isCancelled",
+            "type": "u8"
+          },
+          {
+            "name": "isPublic",
+            "docs": [
+              "Tickets are bought without permit, `uid` derived from buyer wallet"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "hasFeeConfig",
+            "docs": [
+              "Fee is split by the raffle [`FeeConfig`] instead of the global one"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdShards",
+            "docs": [
+              "Ticket shards created so far, tickets are sold once every shard exists"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
+          },
+          {
+            "name": "shardSales",
+            "docs": [
+              "Sales of every shard as of the last sync, maps winning index to shard"
+            ],
+            "type": {
+              "array": [
+                "u32",
+                16
+              ]
+            }
+          },
+          {
+            "name": "randomness",
+            "type": {
+              "defined": "RaffleRandomness"
+            }
+          },
+          {
+            "name": "prizes",
+            "type": {
+              "array": [
+                {
+                  "defined": "RafflePrize"
+                },
+                10
+              ]
+            }
+          },
+          {
+            "name": "crankRewardPercent",
+            "docs": [
+              "Crank reward snapshot at creation, in basis points of the proceeds"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "referralPercent",
+            "docs": [
+              "Referral slice snapshot at creation, in basis points of the fee"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeConfigVersion",
+            "docs": [
+              "Global [`FeeConfig`] version snapshot at creation, 0 when fee goes to vault"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u64",
+                7
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeConfig",
+      "docs": [
+        "Split of the platform fee, global or for a single raffle.",
+        "",
+        "Global splits are never rewritten, every change creates a new version",
+        "which only raffles created afterwards use"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "raffle",
+            "docs": [
+              "Raffle using this split, default key for the global split"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Version of the global split, 0 for a raffle split"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "totalRecipients",
+            "type": "u8"
+          },
+          {
+            "name": "recipients",
+            "type": {
+              "array": [
+                {
+                  "defined": "FeeRecipient"
+                },
+                5
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "compressedPrize",
+      "docs": [
+        "Leaf of a compressed nft prize held by raffle escrow, at `[PREFIX, raffle, PRIZE, slot]`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ticketShard",
+      "docs": [
+        "Ticket shard header, followed in account data by `total_tickets`",
+        "[`RaffleTicket`] entries which grow by realloc on every purchase"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "raffle",
+            "type": "publicKey"
+          },
+          {
+            "name": "capacity",
+            "type": "u32"
+          },
+          {
+            "name": "totalSales",
+            "type": "u32"
+          },
+          {
+            "name": "totalTickets",
+            "type": "u32"
+          },
+          {
+            "name": "shard",
+            "type": "u8"
+          },
+          {
+            "name": "escrowBump",
+            "docs": [
+              "Bump of the shard proceeds escrow `[PREFIX, raffle, ESCROW, shard]`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "fees",
+            "docs": [
+              "Fee held by the shard proceeds escrow until swept to its recipients.",
+              "Paying it on purchase would make every buyer pass the split and lock",
+              "its recipient accounts, so fee is only credited here"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u64",
+                3
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralAccount",
+      "docs": [
+        "Referral rewards of a referrer in a single payment mint, held by the",
+        "escrow at `[PREFIX, referral_account, ESCROW]` until claimed"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "referrer",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "accrued",
+            "docs": [
+              "Total rewards credited by ticket purchases"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimed",
+            "docs": [
+              "Total rewards paid out by `claim_referral`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u64",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "whitelistEntry",
+      "docs": [
+        "Whitelisted prize collection at `[PREFIX, WHITELIST, key]`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "docs": [
+              "Verified collection key, or verified first creator for legacy collections"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "addedBy",
+            "type": "publicKey"
+          },
+          {
+            "name": "addedAt",
+            "type": "i64"
+          },
+          {
+            "name": "isEnabled",
+            "type": "u8"
+          },
+          {
+            "name": "kind",
+            "docs": [
+              "[`WhitelistKind`] discriminant"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                30
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "userAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "raffleIdx",
+            "type": "u32"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "uid",
+            "type": {
+              "array": [
+                "u8",
+                12
+              ]
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u32"
+          },
+          {
+            "name": "deposited",
+            "type": "u64"
+          },
+          {
+            "name": "permitNonce",
+            "docs": [
+              "Nonce of the last purchase permit used"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved1",
+            "type": "u128"
+          },
+          {
+            "name": "reserved2",
+            "type": "u128"
+          },
+          {
+            "name": "reserved3",
+            "type": "u128"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Uses",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "useMethod",
+            "type": {
+              "defined": "UseMethod"
+            }
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          },
+          {
+            "name": "total",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Collection",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "key",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Creator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "share",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MetadataArgs",
+      "docs": [
+        "Metadata of a compressed nft, layout must match Bubblegum to reproduce leaf hashes"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "primarySaleHappened",
+            "type": "bool"
+          },
+          {
+            "name": "isMutable",
+            "type": "bool"
+          },
+          {
+            "name": "editionNonce",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "tokenStandard",
+            "type": {
+              "option": {
+                "defined": "TokenStandard"
+              }
+            }
+          },
+          {
+            "name": "collection",
+            "type": {
+              "option": {
+                "defined": "Collection"
+              }
+            }
+          },
+          {
+            "name": "uses",
+            "type": {
+              "option": {
+                "defined": "Uses"
+              }
+            }
+          },
+          {
+            "name": "tokenProgramVersion",
+            "type": {
+              "defined": "TokenProgramVersion"
+            }
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "Creator"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyPermit",
+      "docs": [
+        "Purchase permit issued off-chain by the global permit signer"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxAmount",
+            "docs": [
+              "Max tickets allowed to buy with this permit"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the permit is rejected"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Must be greater than the last nonce used by the user account"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeRecipient",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "share",
+            "docs": [
+              "Share of the fee in basis points"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RaffleRandomness",
+      "docs": [
+        "Randomness committed to by `request_randomness` and consumed by `settle_randomness`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slot",
+            "docs": [
+              "Slot whose hash is used, or slot of the oracle request"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "docs": [
+              "Unix timestamp when randomness was settled"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "request",
+            "docs": [
+              "Oracle request account, unused for slot hash source"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "value",
+            "docs": [
+              "Randomness revealed by the provider"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "seed",
+            "docs": [
+              "Seed of the winner draw, `keccak(value || raffle)`"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "source",
+            "docs": [
+              "[`RandomnessSource`] discriminant"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "attempt",
+            "docs": [
+              "Index of the request, bumped on every re-request, oracle seeds derive from it"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RafflePrize",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "winnerIdx",
+            "type": "u32"
+          },
+          {
+            "name": "kind",
+            "docs": [
+              "[`PrizeKind`] discriminant"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "isDeposited",
+            "type": "u8"
+          },
+          {
+            "name": "isClaimed",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RaffleTicket",
+      "docs": [
+        "Purchase of `amount` consecutive tickets starting at `start_idx`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startIdx",
+            "type": "u32"
+          },
+          {
+            "name": "amount",
+            "type": "u32"
+          },
+          {
+            "name": "uid",
+            "type": {
+              "array": [
+                "u8",
+                12
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenStandard",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NonFungible"
+          },
+          {
+            "name": "FungibleAsset"
+          },
+          {
+            "name": "Fungible"
+          },
+          {
+            "name": "NonFungibleEdition"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UseMethod",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Burn"
+          },
+          {
+            "name": "Multiple"
+          },
+          {
+            "name": "Single"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenProgramVersion",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Original"
+          },
+          {
+            "name": "Token2022"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CorePlugin",
+      "docs": [
+        "Owner managed plugins used to hold a deposited asset"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FreezeDelegate",
+            "fields": [
+              {
+                "name": "frozen",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "TransferDelegate"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RandomnessSource",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "None"
+          },
+          {
+            "name": "SlotHash"
+          },
+          {
+            "name": "Oracle"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PrizeKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Nft"
+          },
+          {
+            "name": "Pnft"
+          },
+          {
+            "name": "Token"
+          },
+          {
+            "name": "Sol"
+          },
+          {
+            "name": "Nft2022"
+          },
+          {
+            "name": "Core"
+          },
+          {
+            "name": "Cnft"
+          }
+        ]
+      }
+    },
+    {
+      "name": "WhitelistKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Collection"
+          },
+          {
+            "name": "Creator"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "RaffleCreated",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "idx",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "splMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "totalSupply",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "totalPrizes",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "startDate",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "endDate",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "minSales",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "totalShards",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "isPublic",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ShardCreated",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "idx",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "shard",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PrizeAdded",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "idx",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "slot",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "kind",
+          "type": {
+            "defined": "PrizeKind"
+          },
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TicketsBought",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "idx",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "shard",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "startIdx",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "paid",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referralFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FeesSwept",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "idx",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "shard",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RandomnessRequested",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "idx",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "source",
+          "type": {
+            "defined": "RandomnessSource"
+          },
+          "index": false
+        },
+        {
+          "name": "request",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "slot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalSales",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "requester",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "crankReward",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WinnersRevealed",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "idx",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "source",
+          "type": {
+            "defined": "RandomnessSource"
+          },
+          "index": false
+        },
+        {
+          "name": "request",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "slot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "value",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "seed",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalSales",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "winners",
+          "type": {
+            "vec": "u32"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PrizeClaimed",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "idx",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "slot",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "kind",
+          "type": {
+            "defined": "PrizeKind"
+          },
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "winner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PrizeReclaimed",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "idx",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "slot",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "kind",
+          "type": {
+            "defined": "PrizeKind"
+          },
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RaffleWithdrawn",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "idx",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RaffleCancelled",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "idx",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TicketsRefunded",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "idx",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReferralInitialized",
+      "fields": [
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referral",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReferralClaimed",
+      "fields": [
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WhitelistUpdated",
+      "fields": [
+        {
+          "name": "key",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "kind",
+          "type": {
+            "defined": "WhitelistKind"
+          },
+          "index": false
+        },
+        {
+          "name": "isEnabled",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProgramInitialized",
+      "fields": [
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "vault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "permitSigner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SettingsUpdated",
+      "fields": [
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "vault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "permitSigner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FeesUpdated",
+      "fields": [
+        {
+          "name": "feePercent",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "maxTicketPercent",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "referralPercent",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "crankRewardPercent",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FeeConfigUpdated",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipients",
+          "type": {
+            "vec": {
+              "defined": "FeeRecipient"
+            }
+          },
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InvalidSigner",
+      "msg": "Invalid Signer"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidWallet",
+      "msg": "Invalid Wallet"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidRaffleIdx",
+      "msg": "Invalid raffle index"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidUUID",
+      "msg": "Invalid UUID"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidDate",
+      "msg": "Invalid date"
+    },
+    {
+      "code": 6005,
+      "name": "InsufficientBalance",
+      "msg": "Token balance not enough"
+    },
+    {
+      "code": 6006,
+      "name": "RaffleNotStarted",
+      "msg": "Raffle not started"
+    },
+    {
+      "code": 6007,
+      "name": "RaffleExpired",
+      "msg": "Raffle has been expired"
+    },
+    {
+      "code": 6008,
+      "name": "RaffleNotEnded",
+      "msg": "Raffle not ended"
+    },
+    {
+      "code": 6009,
+      "name": "RaffleAlreadyWithdrawn",
+      "msg": "Raffle already withdrawn"
+    },
+    {
+      "code": 6010,
+      "name": "PrizeNotDeposited",
+      "msg": "Prize not deposited"
+    },
+    {
+      "code": 6011,
+      "name": "PrizeAlreadyDeposited",
+      "msg": "Prize already deposited"
+    },
+    {
+      "code": 6012,
+      "name": "PrizeAlreadyClaimed",
+      "msg": "Prize already claimed"
+    },
+    {
+      "code": 6013,
+      "name": "WinnerNotRevealed",
+      "msg": "Winner not revealed"
+    },
+    {
+      "code": 6014,
+      "name": "WinnerNotMatched",
+      "msg": "Winner not matched"
+    },
+    {
+      "code": 6015,
+      "name": "SupplyLimitExceed",
+      "msg": "Totaly supply limit exceed"
+    },
+    {
+      "code": 6016,
+      "name": "WalletLimitExceed",
+      "msg": "Wallet supply limit exceed"
+    },
+    {
+      "code": 6017,
+      "name": "TotalSupplyLessTotalSales",
+      "msg": "Total supply should be greater than total sales"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidAmount",
+      "msg": "Invalid amount"
+    },
+    {
+      "code": 6019,
+      "name": "NumericOverflow",
+      "msg": "Numeric Overflow Error"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidMint",
+      "msg": "Invalid Mint"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidTokenAccount",
+      "msg": "Invalid Token Account"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidPubkey",
+      "msg": "Invalid Pubkey"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidOwner",
+      "msg": "Invalid Owner"
+    },
+    {
+      "code": 6024,
+      "name": "UninitializedAccount",
+      "msg": "Uninitialized Account"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidCollection",
+      "msg": "Invalid Collection"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidTokenStandard",
+      "msg": "Invalid TokenStandard"
+    },
+    {
+      "code": 6027,
+      "name": "BadMetadata",
+      "msg": "Bad Metadata"
+    },
+    {
+      "code": 6028,
+      "name": "WhitelistFull",
+      "msg": "Whitelist is full"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidPrizeSlot",
+      "msg": "Invalid prize slot"
+    },
+    {
+      "code": 6030,
+      "name": "WinnerAlreadyRevealed",
+      "msg": "Winner already revealed"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidPrizeKind",
+      "msg": "Invalid prize kind"
+    },
+    {
+      "code": 6032,
+      "name": "RaffleHasSales",
+      "msg": "Raffle already has ticket sales"
+    },
+    {
+      "code": 6033,
+      "name": "RaffleCancelled",
+      "msg": "Raffle has been cancelled"
+    },
+    {
+      "code": 6034,
+      "name": "RaffleNotRefundable",
+      "msg": "Raffle not refundable"
+    },
+    {
+      "code": 6035,
+      "name": "MinSalesNotReached",
+      "msg": "Minimum sales not reached"
+    },
+    {
+      "code": 6036,
+      "name": "RandomnessAlreadyRequested",
+      "msg": "Randomness already requested"
+    },
+    {
+      "code": 6037,
+      "name": "RandomnessNotRequested",
+      "msg": "Randomness not requested"
+    },
+    {
+      "code": 6038,
+      "name": "RandomnessNotReady",
+      "msg": "Randomness not ready"
+    },
+    {
+      "code": 6039,
+      "name": "RandomnessExpired",
+      "msg": "Randomness expired"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidRandomnessAccount",
+      "msg": "Invalid randomness account"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidTicketShard",
+      "msg": "Invalid ticket shard"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidPermit",
+      "msg": "Invalid purchase permit"
+    },
+    {
+      "code": 6043,
+      "name": "PermitExpired",
+      "msg": "Purchase permit expired"
+    },
+    {
+      "code": 6044,
+      "name": "PermitAlreadyUsed",
+      "msg": "Purchase permit already used"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidFeeConfig",
+      "msg": "Invalid fee config"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidFeeRecipient",
+      "msg": "Invalid fee recipient"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidReferral",
+      "msg": "Invalid referral account"
+    },
+    {
+      "code": 6048,
+      "name": "SelfReferral",
+      "msg": "Self referral not allowed"
+    },
+    {
+      "code": 6049,
+      "name": "UnsupportedMintExtension",
+      "msg": "Unsupported mint extension"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidAsset",
+      "msg": "Invalid core asset"
+    },
+    {
+      "code": 6051,
+      "name": "EarlyRevealNotAllowed",
+      "msg": "Early reveal restricted to authority"
+    },
+    {
+      "code": 6052,
+      "name": "ShardsNotCreated",
+      "msg": "Ticket shards not created"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidShardEscrow",
+      "msg": "Invalid shard escrow"
+    },
+    {
+      "code": 6054,
+      "name": "FeesNotSwept",
+      "msg": "Shard fees not swept"
+    }
+  ]
+};
+
+export const IDL: SolanaRaffle = {
+  "version": "0.1.0",
+  "name": "solana_raffle",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "permitSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateSetting",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "permitSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateFees",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "feePercent",
+          "type": "u16"
+        },
+        {
+          "name": "maxTicketPercent",
+          "type": "u16"
+        },
+        {
+          "name": "referralPercent",
+          "type": "u16"
+        },
+        {
+          "name": "crankRewardPercent",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setFeeConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeConfig",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Next version of the global split, omitted when clearing it"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recipients",
+          "type": {
+            "vec": {
+              "defined": "FeeRecipient"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRaffleFeeConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "recipients",
+          "type": {
+            "vec": {
+              "defined": "FeeRecipient"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addWhitelist",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "key",
+          "type": "publicKey"
+        },
+        {
+          "name": "kind",
+          "type": {
+            "defined": "WhitelistKind"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeWhitelist",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "key",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "migrateWhitelist",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collection",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "createRaffle",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "totalSupply",
+          "type": "u32"
+        },
+        {
+          "name": "totalPrizes",
+          "type": "u8"
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "startDate",
+          "type": "u64"
+        },
+        {
+          "name": "endDate",
+          "type": "u64"
+        },
+        {
+          "name": "minSales",
+          "type": "u32"
+        },
+        {
+          "name": "totalShards",
+          "type": "u8"
+        },
+        {
+          "name": "isPublic",
+          "type": "bool"
+        },
+        {
+          "name": "maxTicketPercent",
+          "type": {
+            "option": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createShard",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "shard",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addPrize",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addPrizePnft",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "delegateRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sysvarInstructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addPrizeToken",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "addPrizeSol",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "addPrize2022",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addPrizeCore",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coreProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addPrizeCnft",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressedPrize",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "MetadataArgs"
+          }
+        },
+        {
+          "name": "nonce",
+          "type": "u64"
+        },
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "buyTicket",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Referrer of the purchase, credited a slice of the fee"
+          ]
+        },
+        {
+          "name": "referralEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sysvarInstructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u32"
+        },
+        {
+          "name": "shard",
+          "type": "u8"
+        },
+        {
+          "name": "permit",
+          "type": {
+            "option": {
+              "defined": "BuyPermit"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "sweepFees",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "raffleFeeConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Fee split of the raffle, when it has one"
+          ]
+        },
+        {
+          "name": "globalFeeConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Global fee split version snapshot by the raffle, when it has none"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "shard",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "withdrawRaffle",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "pushProceeds",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "requestRandomness",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracleRequest",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "committedRequest",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "recentSlothashes",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "source",
+          "type": {
+            "defined": "RandomnessSource"
+          }
+        }
+      ]
+    },
+    {
+      "name": "settleRandomness",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracleRequest",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "recentSlothashes",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "claimPrize",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimPrizePnft",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sysvarInstructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimPrizeToken",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimPrizeSol",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimPrize2022",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimPrizeCore",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coreProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimPrizeCnft",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "compressedPrize",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        },
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrize",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrizePnft",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sysvarInstructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrizeToken",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrizeSol",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrize2022",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrizeCore",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coreProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushPrizeCnft",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticketShard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "compressedPrize",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        },
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "initReferral",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referralEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimReferral",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "referralAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelRaffle",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "refundTicket",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrize",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrizePnft",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sysvarInstructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrizeToken",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrizeSol",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrize2022",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrizeCore",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coreProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimPrizeCnft",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "raffleAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "compressedPrize",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "idx",
+          "type": "u32"
+        },
+        {
+          "name": "slot",
+          "type": "u8"
+        },
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "globalAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "vault",
+            "type": "publicKey"
+          },
           {
             "name": "totalRaffles",
             "type": "u32"
           },
           {
             "name": "wlCollections",
+            "docs": [
+              "Legacy whitelist, drained into [`WhitelistEntry`] accounts by `migrate_whitelist`"
+            ],
             "type": {
               "array": [
                 "publicKey",
@@ -730,16 +9067,71 @@ export type SolanaRaffle = {
             }
           },
           {
-            "name": "reserved0",
-            "type": "u128"
+            "name": "permitSigner",
+            "docs": [
+              "Off-chain key signing purchase permits"
+            ],
+            "type": "publicKey"
           },
           {
-            "name": "reserved1",
-            "type": "u128"
+            "name": "feePercent",
+            "docs": [
+              "Platform fee in basis points, snapshot by new raffles.",
+              "Read through [`GlobalAccount::platform_fee_percent`], unset on older deployments"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxTicketPercent",
+            "docs": [
+              "Default per-wallet ticket cap in basis points of total supply, 0 for no cap"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "hasFeeConfig",
+            "docs": [
+              "Fee of new raffles is split by the global [`FeeConfig`]"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "referralPercent",
+            "docs": [
+              "Slice of the fee credited to referrer, in basis points of the fee"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "crankRewardPercent",
+            "docs": [
+              "Paid from escrow to whoever requests the draw of an ended raffle,",
+              "in basis points of the proceeds"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feesInitialized",
+            "docs": [
+              "Fee and wallet cap were written by `initialize` or `update_fees`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "feeConfigVersion",
+            "docs": [
+              "Version of the latest global [`FeeConfig`], each split is a new account"
+            ],
+            "type": "u32"
           },
           {
             "name": "reserved2",
-            "type": "u128"
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
           },
           {
             "name": "reserved3",
@@ -749,14 +9141,19 @@ export type SolanaRaffle = {
       }
     },
     {
-      "name": "raffleAccount",
+      "name": "raffleAccountV2",
+      "docs": [
+        "Raffle header, tickets are stored across `total_shards` [`TicketShard`] accounts.",
+        "",
+        "Versioned name gives the zero-copy layout its own discriminator, so raffles",
+        "created with the former Borsh `RaffleAccount` fail to load instead of being",
+        "read with the wrong layout. Those have to be settled and withdrawn with the",
+        "program release which created them before upgrading; their idx is never reused",
+        "as `total_raffles` keeps counting up."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "idx",
-            "type": "u32"
-          },
           {
             "name": "authority",
             "type": "publicKey"
@@ -766,67 +9163,400 @@ export type SolanaRaffle = {
             "type": "publicKey"
           },
           {
-            "name": "mint",
-            "type": "publicKey"
+            "name": "price",
+            "type": "u64"
           },
           {
-            "name": "price",
+            "name": "startDate",
+            "type": "u64"
+          },
+          {
+            "name": "endDate",
             "type": "u64"
           },
+          {
+            "name": "idx",
+            "type": "u32"
+          },
           {
             "name": "totalSupply",
             "type": "u32"
           },
           {
             "name": "totalSales",
+            "docs": [
+              "Sum of shard sales as of the last sync"
+            ],
             "type": "u32"
           },
           {
-            "name": "winnerIdx",
+            "name": "minSales",
             "type": "u32"
           },
           {
-            "name": "startDate",
-            "type": "u64"
+            "name": "feePercent",
+            "docs": [
+              "Platform fee snapshot at creation, in basis points"
+            ],
+            "type": "u16"
           },
           {
-            "name": "endDate",
-            "type": "u64"
+            "name": "maxTicketPercent",
+            "docs": [
+              "Per-wallet ticket cap in basis points of total supply, 0 for no cap"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "totalPrizes",
+            "type": "u8"
+          },
+          {
+            "name": "totalShards",
+            "type": "u8"
           },
           {
             "name": "isDeposited",
-            "type": "bool"
+            "type": "u8"
           },
           {
-            "name": "isClaimed",
-            "type": "bool"
+            "name": "isRevealed",
+            "type": "u8"
           },
           {
             "name": "isWithdrawn",
-            "type": "bool"
+            "type": "u8"
+          },
+          {
+            "name": "isCancelled",
+            "type": "u8"
+          },
+          {
+            "name": "isPublic",
+            "docs": [
+              "Tickets are bought without permit, `uid` derived from buyer wallet"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "hasFeeConfig",
+            "docs": [
+              "Fee is split by the raffle [`FeeConfig`] instead of the global one"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdShards",
+            "docs": [
+              "Ticket shards created so far, tickets are sold once every shard exists"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
+          },
+          {
+            "name": "shardSales",
+            "docs": [
+              "Sales of every shard as of the last sync, maps winning index to shard"
+            ],
+            "type": {
+              "array": [
+                "u32",
+                16
+              ]
+            }
+          },
+          {
+            "name": "randomness",
+            "type": {
+              "defined": "RaffleRandomness"
+            }
+          },
+          {
+            "name": "prizes",
+            "type": {
+              "array": [
+                {
+                  "defined": "RafflePrize"
+                },
+                10
+              ]
+            }
+          },
+          {
+            "name": "crankRewardPercent",
+            "docs": [
+              "Crank reward snapshot at creation, in basis points of the proceeds"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "referralPercent",
+            "docs": [
+              "Referral slice snapshot at creation, in basis points of the fee"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeConfigVersion",
+            "docs": [
+              "Global [`FeeConfig`] version snapshot at creation, 0 when fee goes to vault"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u64",
+                7
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeConfig",
+      "docs": [
+        "Split of the platform fee, global or for a single raffle.",
+        "",
+        "Global splits are never rewritten, every change creates a new version",
+        "which only raffles created afterwards use"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "raffle",
+            "docs": [
+              "Raffle using this split, default key for the global split"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Version of the global split, 0 for a raffle split"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "totalRecipients",
+            "type": "u8"
+          },
+          {
+            "name": "recipients",
+            "type": {
+              "array": [
+                {
+                  "defined": "FeeRecipient"
+                },
+                5
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "compressedPrize",
+      "docs": [
+        "Leaf of a compressed nft prize held by raffle escrow, at `[PREFIX, raffle, PRIZE, slot]`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ticketShard",
+      "docs": [
+        "Ticket shard header, followed in account data by `total_tickets`",
+        "[`RaffleTicket`] entries which grow by realloc on every purchase"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "raffle",
+            "type": "publicKey"
+          },
+          {
+            "name": "capacity",
+            "type": "u32"
+          },
+          {
+            "name": "totalSales",
+            "type": "u32"
+          },
+          {
+            "name": "totalTickets",
+            "type": "u32"
+          },
+          {
+            "name": "shard",
+            "type": "u8"
+          },
+          {
+            "name": "escrowBump",
+            "docs": [
+              "Bump of the shard proceeds escrow `[PREFIX, raffle, ESCROW, shard]`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "fees",
+            "docs": [
+              "Fee held by the shard proceeds escrow until swept to its recipients.",
+              "Paying it on purchase would make every buyer pass the split and lock",
+              "its recipient accounts, so fee is only credited here"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u64",
+                3
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralAccount",
+      "docs": [
+        "Referral rewards of a referrer in a single payment mint, held by the",
+        "escrow at `[PREFIX, referral_account, ESCROW]` until claimed"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "referrer",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "accrued",
+            "docs": [
+              "Total rewards credited by ticket purchases"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimed",
+            "docs": [
+              "Total rewards paid out by `claim_referral`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u64",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "whitelistEntry",
+      "docs": [
+        "Whitelisted prize collection at `[PREFIX, WHITELIST, key]`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "docs": [
+              "Verified collection key, or verified first creator for legacy collections"
+            ],
+            "type": "publicKey"
           },
           {
-            "name": "reserved0",
-            "type": "u128"
+            "name": "addedBy",
+            "type": "publicKey"
           },
           {
-            "name": "reserved1",
-            "type": "u128"
+            "name": "addedAt",
+            "type": "i64"
           },
           {
-            "name": "reserved2",
-            "type": "u128"
+            "name": "isEnabled",
+            "type": "u8"
           },
           {
-            "name": "reserved3",
-            "type": "u128"
+            "name": "kind",
+            "docs": [
+              "[`WhitelistKind`] discriminant"
+            ],
+            "type": "u8"
           },
           {
-            "name": "tickets",
+            "name": "reserved",
             "type": {
-              "vec": {
-                "defined": "RaffleTicket"
-              }
+              "array": [
+                "u8",
+                30
+              ]
             }
           }
         ]
@@ -859,8 +9589,15 @@ export type SolanaRaffle = {
             "type": "u32"
           },
           {
-            "name": "reserved0",
-            "type": "u128"
+            "name": "deposited",
+            "type": "u64"
+          },
+          {
+            "name": "permitNonce",
+            "docs": [
+              "Nonce of the last purchase permit used"
+            ],
+            "type": "u64"
           },
           {
             "name": "reserved1",
@@ -880,748 +9617,951 @@ export type SolanaRaffle = {
   ],
   "types": [
     {
-      "name": "RaffleTicket",
+      "name": "Uses",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "uid",
+            "name": "useMethod",
             "type": {
-              "array": [
-                "u8",
-                12
-              ]
+              "defined": "UseMethod"
             }
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          },
+          {
+            "name": "total",
+            "type": "u64"
           }
         ]
       }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InvalidSigner",
-      "msg": "Invalid Signer"
-    },
-    {
-      "code": 6001,
-      "name": "InvalidWallet",
-      "msg": "Invalid Wallet"
-    },
-    {
-      "code": 6002,
-      "name": "InvalidRaffleIdx",
-      "msg": "Invalid raffle index"
-    },
-    {
-      "code": 6003,
-      "name": "InvalidUUID",
-      "msg": "Invalid UUID"
-    },
-    {
-      "code": 6004,
-      "name": "InvalidDate",
-      "msg": "Invalid date"
-    },
-    {
-      "code": 6005,
-      "name": "InsufficientBalance",
-      "msg": "Token balance not enough"
-    },
-    {
-      "code": 6006,
-      "name": "RaffleNotStarted",
-      "msg": "Raffle not started"
-    },
-    {
-      "code": 6007,
-      "name": "RaffleExpired",
-      "msg": "Raffle has been expired"
-    },
-    {
-      "code": 6008,
-      "name": "RaffleNotEnded",
-      "msg": "Raffle not ended"
-    },
-    {
-      "code": 6009,
-      "name": "RaffleAlreadyWithdrawn",
-      "msg": "Raffle already withdrawn"
-    },
-    {
-      "code": 6010,
-      "name": "PrizeNotDeposited",
-      "msg": "Prize not deposited"
-    },
-    {
-      "code": 6011,
-      "name": "PrizeAlreadyDeposited",
-      "msg": "Prize already deposited"
-    },
-    {
-      "code": 6012,
-      "name": "PrizeAlreadyClaimed",
-      "msg": "Prize already claimed"
-    },
-    {
-      "code": 6013,
-      "name": "WinnerNotRevealed",
-      "msg": "Winner not revealed"
-    },
-    {
-      "code": 6014,
-      "name": "WinnerNotMatched",
-      "msg": "Winner not matched"
-    },
-    {
-      "code": 6015,
-      "name": "SupplyLimitExceed",
-      "msg": "Totaly supply limit exceed"
     },
     {
-      "code": 6016,
-      "name": "WalletLimitExceed",
-      "msg": "Wallet supply limit exceed"
-    },
-    {
-      "code": 6017,
-      "name": "TotalSupplyLessTotalSales",
-      "msg": "Total supply should be greater than total sales"
-    },
-    {
-      "code": 6018,
-      "name": "InvalidAmount",
-      "msg": "Invalid amount"
+      "name": "Collection",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "key",
+            "type": "publicKey"
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "NumericOverflow",
-      "msg": "Numeric Overflow Error"
+      "name": "Creator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "share",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "InvalidMint",
-      "msg": "Invalid Mint"
+      "name": "MetadataArgs",
+      "docs": [
+        "Metadata of a compressed nft, layout must match Bubblegum to reproduce leaf hashes"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "primarySaleHappened",
+            "type": "bool"
+          },
+          {
+            "name": "isMutable",
+            "type": "bool"
+          },
+          {
+            "name": "editionNonce",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "tokenStandard",
+            "type": {
+              "option": {
+                "defined": "TokenStandard"
+              }
+            }
+          },
+          {
+            "name": "collection",
+            "type": {
+              "option": {
+                "defined": "Collection"
+              }
+            }
+          },
+          {
+            "name": "uses",
+            "type": {
+              "option": {
+                "defined": "Uses"
+              }
+            }
+          },
+          {
+            "name": "tokenProgramVersion",
+            "type": {
+              "defined": "TokenProgramVersion"
+            }
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "Creator"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "InvalidTokenAccount",
-      "msg": "Invalid Token Account"
+      "name": "BuyPermit",
+      "docs": [
+        "Purchase permit issued off-chain by the global permit signer"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxAmount",
+            "docs": [
+              "Max tickets allowed to buy with this permit"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the permit is rejected"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Must be greater than the last nonce used by the user account"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "InvalidPubkey",
-      "msg": "Invalid Pubkey"
+      "name": "FeeRecipient",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "share",
+            "docs": [
+              "Share of the fee in basis points"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "InvalidOwner",
-      "msg": "Invalid Owner"
+      "name": "RaffleRandomness",
+      "docs": [
+        "Randomness committed to by `request_randomness` and consumed by `settle_randomness`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slot",
+            "docs": [
+              "Slot whose hash is used, or slot of the oracle request"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "docs": [
+              "Unix timestamp when randomness was settled"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "request",
+            "docs": [
+              "Oracle request account, unused for slot hash source"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "value",
+            "docs": [
+              "Randomness revealed by the provider"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "seed",
+            "docs": [
+              "Seed of the winner draw, `keccak(value || raffle)`"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "source",
+            "docs": [
+              "[`RandomnessSource`] discriminant"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "attempt",
+            "docs": [
+              "Index of the request, bumped on every re-request, oracle seeds derive from it"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "UninitializedAccount",
-      "msg": "Uninitialized Account"
+      "name": "RafflePrize",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "winnerIdx",
+            "type": "u32"
+          },
+          {
+            "name": "kind",
+            "docs": [
+              "[`PrizeKind`] discriminant"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "isDeposited",
+            "type": "u8"
+          },
+          {
+            "name": "isClaimed",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6025,
-      "name": "InvalidCollection",
-      "msg": "Invalid Collection"
+      "name": "RaffleTicket",
+      "docs": [
+        "Purchase of `amount` consecutive tickets starting at `start_idx`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startIdx",
+            "type": "u32"
+          },
+          {
+            "name": "amount",
+            "type": "u32"
+          },
+          {
+            "name": "uid",
+            "type": {
+              "array": [
+                "u8",
+                12
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6026,
-      "name": "InvalidTokenStandard",
-      "msg": "Invalid TokenStandard"
+      "name": "TokenStandard",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NonFungible"
+          },
+          {
+            "name": "FungibleAsset"
+          },
+          {
+            "name": "Fungible"
+          },
+          {
+            "name": "NonFungibleEdition"
+          }
+        ]
+      }
     },
     {
-      "code": 6027,
-      "name": "BadMetadata",
-      "msg": "Bad Metadata"
+      "name": "UseMethod",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Burn"
+          },
+          {
+            "name": "Multiple"
+          },
+          {
+            "name": "Single"
+          }
+        ]
+      }
     },
     {
-      "code": 6028,
-      "name": "WhitelistFull",
-      "msg": "Whitelist is full"
-    }
-  ]
-};
-
-export const IDL: SolanaRaffle = {
-  "version": "0.1.0",
-  "name": "solana_raffle",
-  "instructions": [
-    {
-      "name": "initialize",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "vault",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "globalAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
+      "name": "TokenProgramVersion",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Original"
+          },
+          {
+            "name": "Token2022"
+          }
+        ]
+      }
     },
     {
-      "name": "updateSetting",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "newAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "vault",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "globalAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "CorePlugin",
+      "docs": [
+        "Owner managed plugins used to hold a deposited asset"
       ],
-      "args": []
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FreezeDelegate",
+            "fields": [
+              {
+                "name": "frozen",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "TransferDelegate"
+          }
+        ]
+      }
     },
     {
-      "name": "addWhitelist",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "globalAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "collection",
-          "type": "publicKey"
-        }
-      ]
+      "name": "RandomnessSource",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "None"
+          },
+          {
+            "name": "SlotHash"
+          },
+          {
+            "name": "Oracle"
+          }
+        ]
+      }
     },
     {
-      "name": "removeWhitelist",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "globalAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "collection",
-          "type": "publicKey"
-        }
-      ]
+      "name": "PrizeKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Nft"
+          },
+          {
+            "name": "Pnft"
+          },
+          {
+            "name": "Token"
+          },
+          {
+            "name": "Sol"
+          },
+          {
+            "name": "Nft2022"
+          },
+          {
+            "name": "Core"
+          },
+          {
+            "name": "Cnft"
+          }
+        ]
+      }
     },
     {
-      "name": "createRaffle",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
+      "name": "WhitelistKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Collection"
+          },
+          {
+            "name": "Creator"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "RaffleCreated",
+      "fields": [
         {
-          "name": "globalAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "splMint",
-          "isMut": false,
-          "isSigner": false
+          "name": "idx",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "raffleAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "splMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "idx",
-          "type": "u32"
+          "name": "totalSupply",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "totalSupply",
-          "type": "u32"
+          "name": "totalPrizes",
+          "type": "u8",
+          "index": false
         },
         {
           "name": "price",
-          "type": "u64"
+          "type": "u64",
+          "index": false
         },
         {
           "name": "startDate",
-          "type": "u64"
+          "type": "u64",
+          "index": false
         },
         {
           "name": "endDate",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "addPrize",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "globalAccount",
-          "isMut": false,
-          "isSigner": false
+          "name": "minSales",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "raffleAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "totalShards",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "isPublic",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ShardCreated",
+      "fields": [
         {
-          "name": "metadata",
-          "isMut": false,
-          "isSigner": false
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "edition",
-          "isMut": false,
-          "isSigner": false
+          "name": "idx",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "tokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "shard",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PrizeAdded",
+      "fields": [
         {
-          "name": "escrowAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "metadataProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "idx",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "slot",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "kind",
+          "type": {
+            "defined": "PrizeKind"
+          },
+          "index": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
         {
-          "name": "idx",
-          "type": "u32"
+          "name": "amount",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "addPrizePnft",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
+      "name": "TicketsBought",
+      "fields": [
         {
-          "name": "globalAccount",
-          "isMut": false,
-          "isSigner": false
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "raffleAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "idx",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "metadata",
-          "isMut": true,
-          "isSigner": false
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          },
+          "index": false
         },
         {
-          "name": "edition",
-          "isMut": false,
-          "isSigner": false
+          "name": "shard",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "tokenRecord",
-          "isMut": true,
-          "isSigner": false
+          "name": "startIdx",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "delegateRecord",
-          "isMut": true,
-          "isSigner": false
+          "name": "amount",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "tokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "paid",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "escrowAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "fee",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "authorizationRules",
-          "isMut": false,
-          "isSigner": false
+          "name": "referralFee",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "sysvarInstructions",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "referrer",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FeesSwept",
+      "fields": [
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "metadataProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "idx",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "authorizationRulesProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "shard",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "idx",
-          "type": "u32"
+          "name": "amount",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "buyTicket",
-      "accounts": [
+      "name": "RandomnessRequested",
+      "fields": [
         {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "buyer",
-          "isMut": true,
-          "isSigner": true
+          "name": "idx",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "globalAccount",
-          "isMut": false,
-          "isSigner": false
+          "name": "source",
+          "type": {
+            "defined": "RandomnessSource"
+          },
+          "index": false
         },
         {
-          "name": "raffleAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "request",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "userAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "slot",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "splMint",
-          "isMut": false,
-          "isSigner": false
+          "name": "totalSales",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "userTokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "requester",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "vaultTokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "crankReward",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WinnersRevealed",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "escrowAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "idx",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "source",
+          "type": {
+            "defined": "RandomnessSource"
+          },
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "request",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "slot",
+          "type": "u64",
+          "index": false
+        },
         {
-          "name": "idx",
-          "type": "u32"
+          "name": "value",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
+          },
+          "index": false
         },
         {
-          "name": "uid",
+          "name": "seed",
           "type": {
             "array": [
               "u8",
-              12
+              32
             ]
-          }
+          },
+          "index": false
         },
         {
-          "name": "amount",
-          "type": "u32"
+          "name": "timestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalSales",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "winners",
+          "type": {
+            "vec": "u32"
+          },
+          "index": false
         }
       ]
     },
     {
-      "name": "withdrawRaffle",
-      "accounts": [
+      "name": "PrizeClaimed",
+      "fields": [
         {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "raffleAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "idx",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "splMint",
-          "isMut": false,
-          "isSigner": false
+          "name": "slot",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "treasuryTokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "kind",
+          "type": {
+            "defined": "PrizeKind"
+          },
+          "index": false
         },
         {
-          "name": "escrowAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "amount",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "winner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "idx",
-          "type": "u32"
+          "name": "uid",
+          "type": {
+            "array": [
+              "u8",
+              12
+            ]
+          },
+          "index": false
         }
       ]
     },
     {
-      "name": "revealWinner",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "globalAccount",
-          "isMut": false,
-          "isSigner": false
-        },
+      "name": "PrizeReclaimed",
+      "fields": [
         {
-          "name": "raffleAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
         },
-        {
-          "name": "recentSlothashes",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
         {
           "name": "idx",
-          "type": "u32"
-        }
-      ]
-    },
-    {
-      "name": "claimPrize",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "raffleAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "slot",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "userAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "kind",
+          "type": {
+            "defined": "PrizeKind"
+          },
+          "index": false
         },
         {
           "name": "mint",
-          "isMut": false,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "metadata",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RaffleWithdrawn",
+      "fields": [
         {
-          "name": "edition",
-          "isMut": false,
-          "isSigner": false
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "creatorTokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "idx",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "winnerTokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "treasury",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "escrowAccount",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RaffleCancelled",
+      "fields": [
         {
-          "name": "metadataProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "idx",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TicketsRefunded",
+      "fields": [
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
         },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
         {
           "name": "idx",
-          "type": "u32"
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "uid",
@@ -1630,319 +10570,161 @@ export const IDL: SolanaRaffle = {
               "u8",
               12
             ]
-          }
+          },
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "claimPrizePnft",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
+      "name": "ReferralInitialized",
+      "fields": [
         {
-          "name": "raffleAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "userAccount",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "referral",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReferralClaimed",
+      "fields": [
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": false
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "metadata",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "edition",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "ownerTokenRecord",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "ownerTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WhitelistUpdated",
+      "fields": [
         {
-          "name": "destTokenRecord",
-          "isMut": true,
-          "isSigner": false
+          "name": "key",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "destTokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "kind",
+          "type": {
+            "defined": "WhitelistKind"
+          },
+          "index": false
         },
         {
-          "name": "escrowAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "isEnabled",
+          "type": "bool",
+          "index": false
         },
         {
-          "name": "authorizationRules",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProgramInitialized",
+      "fields": [
         {
-          "name": "sysvarInstructions",
-          "isMut": false,
-          "isSigner": false
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "vault",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "ataProgram",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "permitSigner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SettingsUpdated",
+      "fields": [
         {
-          "name": "metadataProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "authorizationRulesProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "vault",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "permitSigner",
+          "type": "publicKey",
+          "index": false
         }
-      ],
-      "args": [
+      ]
+    },
+    {
+      "name": "FeesUpdated",
+      "fields": [
         {
-          "name": "idx",
-          "type": "u32"
+          "name": "feePercent",
+          "type": "u16",
+          "index": false
         },
         {
-          "name": "uid",
-          "type": {
-            "array": [
-              "u8",
-              12
-            ]
-          }
+          "name": "maxTicketPercent",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "referralPercent",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "crankRewardPercent",
+          "type": "u16",
+          "index": false
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "globalAccount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "vault",
-            "type": "publicKey"
-          },
-          {
-            "name": "totalRaffles",
-            "type": "u32"
-          },
-          {
-            "name": "wlCollections",
-            "type": {
-              "array": [
-                "publicKey",
-                10
-              ]
-            }
-          },
-          {
-            "name": "reserved0",
-            "type": "u128"
-          },
-          {
-            "name": "reserved1",
-            "type": "u128"
-          },
-          {
-            "name": "reserved2",
-            "type": "u128"
-          },
-          {
-            "name": "reserved3",
-            "type": "u128"
-          }
-        ]
-      }
-    },
-    {
-      "name": "raffleAccount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "idx",
-            "type": "u32"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "splMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "mint",
-            "type": "publicKey"
-          },
-          {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "totalSupply",
-            "type": "u32"
-          },
-          {
-            "name": "totalSales",
-            "type": "u32"
-          },
-          {
-            "name": "winnerIdx",
-            "type": "u32"
-          },
-          {
-            "name": "startDate",
-            "type": "u64"
-          },
-          {
-            "name": "endDate",
-            "type": "u64"
-          },
-          {
-            "name": "isDeposited",
-            "type": "bool"
-          },
-          {
-            "name": "isClaimed",
-            "type": "bool"
-          },
-          {
-            "name": "isWithdrawn",
-            "type": "bool"
-          },
-          {
-            "name": "reserved0",
-            "type": "u128"
-          },
-          {
-            "name": "reserved1",
-            "type": "u128"
-          },
-          {
-            "name": "reserved2",
-            "type": "u128"
-          },
-          {
-            "name": "reserved3",
-            "type": "u128"
-          },
-          {
-            "name": "tickets",
-            "type": {
-              "vec": {
-                "defined": "RaffleTicket"
-              }
-            }
-          }
-        ]
-      }
     },
     {
-      "name": "userAccount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "raffleIdx",
-            "type": "u32"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "uid",
-            "type": {
-              "array": [
-                "u8",
-                12
-              ]
+      "name": "FeeConfigUpdated",
+      "fields": [
+        {
+          "name": "raffle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipients",
+          "type": {
+            "vec": {
+              "defined": "FeeRecipient"
             }
           },
-          {
-            "name": "amount",
-            "type": "u32"
-          },
-          {
-            "name": "reserved0",
-            "type": "u128"
-          },
-          {
-            "name": "reserved1",
-            "type": "u128"
-          },
-          {
-            "name": "reserved2",
-            "type": "u128"
-          },
-          {
-            "name": "reserved3",
-            "type": "u128"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "RaffleTicket",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "uid",
-            "type": {
-              "array": [
-                "u8",
-                12
-              ]
-            }
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
@@ -2090,6 +10872,136 @@ export const IDL: SolanaRaffle = {
       "code": 6028,
       "name": "WhitelistFull",
       "msg": "Whitelist is full"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidPrizeSlot",
+      "msg": "Invalid prize slot"
+    },
+    {
+      "code": 6030,
+      "name": "WinnerAlreadyRevealed",
+      "msg": "Winner already revealed"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidPrizeKind",
+      "msg": "Invalid prize kind"
+    },
+    {
+      "code": 6032,
+      "name": "RaffleHasSales",
+      "msg": "Raffle already has ticket sales"
+    },
+    {
+      "code": 6033,
+      "name": "RaffleCancelled",
+      "msg": "Raffle has been cancelled"
+    },
+    {
+      "code": 6034,
+      "name": "RaffleNotRefundable",
+      "msg": "Raffle not refundable"
+    },
+    {
+      "code": 6035,
+      "name": "MinSalesNotReached",
+      "msg": "Minimum sales not reached"
+    },
+    {
+      "code": 6036,
+      "name": "RandomnessAlreadyRequested",
+      "msg": "Randomness already requested"
+    },
+    {
+      "code": 6037,
+      "name": "RandomnessNotRequested",
+      "msg": "Randomness not requested"
+    },
+    {
+      "code": 6038,
+      "name": "RandomnessNotReady",
+      "msg": "Randomness not ready"
+    },
+    {
+      "code": 6039,
+      "name": "RandomnessExpired",
+      "msg": "Randomness expired"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidRandomnessAccount",
+      "msg": "Invalid randomness account"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidTicketShard",
+      "msg": "Invalid ticket shard"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidPermit",
+      "msg": "Invalid purchase permit"
+    },
+    {
+      "code": 6043,
+      "name": "PermitExpired",
+      "msg": "Purchase permit expired"
+    },
+    {
+      "code": 6044,
+      "name": "PermitAlreadyUsed",
+      "msg": "Purchase permit already used"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidFeeConfig",
+      "msg": "Invalid fee config"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidFeeRecipient",
+      "msg": "Invalid fee recipient"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidReferral",
+      "msg": "Invalid referral account"
+    },
+    {
+      "code": 6048,
+      "name": "SelfReferral",
+      "msg": "Self referral not allowed"
+    },
+    {
+      "code": 6049,
+      "name": "UnsupportedMintExtension",
+      "msg": "Unsupported mint extension"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidAsset",
+      "msg": "Invalid core asset"
+    },
+    {
+      "code": 6051,
+      "name": "EarlyRevealNotAllowed",
+      "msg": "Early reveal restricted to authority"
+    },
+    {
+      "code": 6052,
+      "name": "ShardsNotCreated",
+      "msg": "Ticket shards not created"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidShardEscrow",
+      "msg": "Invalid shard escrow"
+    },
+    {
+      "code": 6054,
+      "name": "FeesNotSwept",
+      "msg": "Shard fees not swept"
     }
   ]
 };
@@ -1,5 +1,8 @@
 import { Connection, PublicKey } from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Metaplex } from "@metaplex-foundation/js";
 import { RAFFLE_PROGRAM_ID } from "./constants";
+import { SolanaRaffle } from "./atlas3_raffle";
 import * as anchor from "@coral-xyz/anchor";
 import { MPL_TOKEN_METADATA_PROGRAM_ID } from "@metaplex-foundation/mpl-token-metadata";
 import { MPL_TOKEN_AUTH_RULES_PROGRAM_ID } from "@metaplex-foundation/mpl-token-auth-rules";
@@ -11,6 +14,10 @@ const PREFIX = "solana-raffle";
 const ESCROW_SEED = "escrow";
 const RAFFLE_SEED = "raffle";
 const USER_SEED = "user";
+const PRIZE_SEED = "prize";
+const FEE_SEED = "fee";
+const REFERRAL_SEED = "referral";
+const WHITELIST_SEED = "wl";
 
 export const shortenPublicKey = (publicKey: string | undefined) => {
   return `${publicKey?.slice(0, 4)}...${publicKey?.slice(-4)}`;
@@ -99,6 +106,113 @@ export const findRafflePda = (idx: anchor.BN): PublicKey => {
   return pda;
 };
 
+// Signer of the self-invocations logging events emitted through CPI
+export const findEventAuthorityPda = (): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from("__event_authority"),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
+export const findShardPda = (raffleIdx: number, shard: number): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    Buffer.from(RAFFLE_SEED),
+    new anchor.BN(raffleIdx).toArrayLike(Buffer, 'be', 4),
+    Buffer.from([shard]),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
+// Every ticket shard in order, passed where the program needs final sales totals
+export const shardAccounts = (raffleIdx: number, totalShards: number) => {
+  return [...Array(totalShards).keys()].map(shard => ({
+    pubkey: findShardPda(raffleIdx, shard),
+    isSigner: false,
+    isWritable: false,
+  }));
+};
+
+// Every ticket shard in order followed by their escrows, passed where the program pays out of ticket proceeds
+export const shardEscrowAccounts = (raffleIdx: number, totalShards: number) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  return [
+    ...shardAccounts(raffleIdx, totalShards),
+    ...[...Array(totalShards).keys()].map(shard => ({
+      pubkey: findShardEscrowPda(rafflePda, shard),
+      isSigner: false,
+      isWritable: true,
+    })),
+  ];
+};
+
+// Fee split of a single raffle
+export const findFeeConfigPda = (rafflePda: PublicKey): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    rafflePda.toBuffer(),
+    Buffer.from(FEE_SEED),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
+// Global fee split, each change is a new version starting from 1
+export const findGlobalFeeConfigPda = (version: number): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    Buffer.from(FEE_SEED),
+    new anchor.BN(version).toArrayLike(Buffer, 'be', 4),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
+export const findWhitelistPda = (key: PublicKey): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    Buffer.from(WHITELIST_SEED),
+    key.toBuffer(),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
+// Whitelist entry of the verified nft collection, falling back to verified first creator
+export const findPrizeWhitelistPda = async (connection: Connection, mint: PublicKey): Promise<PublicKey> => {
+  const nft = await new Metaplex(connection).nfts().findByMint({ mintAddress: mint });
+  if (nft.collection?.verified) {
+    return findWhitelistPda(nft.collection.address);
+  }
+
+  const creator = nft.creators[0];
+  return findWhitelistPda(creator?.verified ? creator.address : PublicKey.default);
+};
+
+export const findReferralPda = (referrer: PublicKey, mint: PublicKey): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    Buffer.from(REFERRAL_SEED),
+    referrer.toBuffer(),
+    mint.toBuffer(),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
+export const findPrizeEscrowPda = (rafflePda: PublicKey, slot: number): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    rafflePda.toBuffer(),
+    Buffer.from(PRIZE_SEED),
+    Buffer.from([slot]),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
 export const findEscrowPda = (rafflePda: PublicKey): PublicKey => {
   const [pda] = PublicKey.findProgramAddressSync([
     Buffer.from(PREFIX),
@@ -109,6 +223,18 @@ export const findEscrowPda = (rafflePda: PublicKey): PublicKey => {
   return pda;
 };
 
+// Escrow of ticket proceeds and unswept fees of a shard
+export const findShardEscrowPda = (rafflePda: PublicKey, shard: number): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    rafflePda.toBuffer(),
+    Buffer.from(ESCROW_SEED),
+    Buffer.from([shard]),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
 export const findUserPda = (uid: string, rafflePda: PublicKey): PublicKey => {
   const [pda] = PublicKey.findProgramAddressSync([
     Buffer.from(USER_SEED),
@@ -118,3 +244,71 @@ export const findUserPda = (uid: string, rafflePda: PublicKey): PublicKey => {
 
   return pda;
 };
+
+// Purchase permit message, must match `BuyPermit::message` of the program
+export const buyPermitMessage = (
+  raffleIdx: number,
+  uid: string,
+  buyer: PublicKey,
+  permit: { maxAmount: number, expiry: anchor.BN, nonce: anchor.BN },
+) => {
+  return Buffer.concat([
+    Buffer.from(PREFIX),
+    new anchor.BN(raffleIdx).toArrayLike(Buffer, 'le', 4),
+    Buffer.from(hexStringToByteArray(uid)),
+    buyer.toBuffer(),
+    new anchor.BN(permit.maxAmount).toArrayLike(Buffer, 'le', 4),
+    permit.expiry.toArrayLike(Buffer, 'le', 8),
+    permit.nonce.toArrayLike(Buffer, 'le', 8),
+  ]);
+}
+
+// User id of a wallet in public raffles, must match `wallet_uid` of the program
+export const walletUid = (wallet: PublicKey): string => {
+  return byteArrayToHexString([...wallet.toBuffer().subarray(0, 12)]);
+}
+
+// Token program owning the mint, classic SPL or Token-2022
+export const findTokenProgramId = async (connection: Connection, mint: PublicKey): Promise<PublicKey> => {
+  const mintInfo = await connection.getAccountInfo(mint);
+  return mintInfo?.owner.equals(TOKEN_2022_PROGRAM_ID) ? TOKEN_2022_PROGRAM_ID : TOKEN_PROGRAM_ID;
+}
+
+// Map global ticket index to its shard, using shard sales snapshot of the raffle
+export const locateTicket = (raffleAccount: any, ticketIdx: number) => {
+  let offset = 0;
+  for (let shard = 0; shard < raffleAccount.totalShards; shard++) {
+    const sales = raffleAccount.shardSales[shard];
+    if (ticketIdx < offset + sales) {
+      return { shard, shardIdx: ticketIdx - offset };
+    }
+    offset += sales;
+  }
+  return null;
+}
+
+const TICKET_SIZE = 4 + 4 + 12;
+
+// Uid owning a ticket, ranges are stored after the shard header outside of the IDL layout
+export const findTicketUid = async (program: anchor.Program<SolanaRaffle>, raffleIdx: number, ticketIdx: number) => {
+  const raffleAccount = await program.account.raffleAccountV2.fetch(findRafflePda(new anchor.BN(raffleIdx)));
+  const location = locateTicket(raffleAccount, ticketIdx);
+  if (!location) {
+    return null;
+  }
+
+  const shardPda = findShardPda(raffleIdx, location.shard);
+  const ticketShard = await program.account.ticketShard.fetch(shardPda);
+  const accountInfo = await program.provider.connection.getAccountInfo(shardPda);
+  const offset = program.account.ticketShard.size;
+
+  for (let i = 0; i < ticketShard.totalTickets; i++) {
+    const data = accountInfo!.data.subarray(offset + i * TICKET_SIZE, offset + (i + 1) * TICKET_SIZE);
+    const startIdx = data.readUInt32LE(0);
+    const amount = data.readUInt32LE(4);
+    if (location.shardIdx >= startIdx && location.shardIdx < startIdx + amount) {
+      return { shard: location.shard, uid: byteArrayToHexString([...data.subarray(8)]) };
+    }
+  }
+  return null;
+}
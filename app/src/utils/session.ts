@@ -0,0 +1,48 @@
+import { createHmac, timingSafeEqual } from "crypto";
+import type { NextApiRequest } from "next";
+
+const SESSION_COOKIE = "session";
+
+// Session issued by the platform login, `uid` is the hex user id the permits are bound to
+export type Session = {
+  uid: string;
+  wallet?: string;
+  exp: number;
+};
+
+// Session of the request, from the bearer token or the session cookie.
+// Tokens are `base64url(payload).base64url(hmac-sha256(payload))` keyed by `SESSION_SECRET`.
+export const getSession = (req: NextApiRequest): Session | null => {
+  const secret = process.env.SESSION_SECRET;
+  if (!secret) {
+    throw new Error("SESSION_SECRET is not configured");
+  }
+
+  const header = req.headers.authorization;
+  const token = header?.startsWith("Bearer ") ? header.slice(7) : req.cookies[SESSION_COOKIE];
+  if (!token) {
+    return null;
+  }
+
+  const [payload, signature] = token.split(".");
+  if (!payload || !signature) {
+    return null;
+  }
+
+  const expected = createHmac("sha256", secret).update(payload).digest();
+  const actual = Buffer.from(signature, "base64url");
+  if (actual.length != expected.length || !timingSafeEqual(actual, expected)) {
+    return null;
+  }
+
+  try {
+    const session = JSON.parse(Buffer.from(payload, "base64url").toString()) as Session;
+    if (typeof session.uid != "string" || !(session.exp > Date.now() / 1000)) {
+      return null;
+    }
+    return session;
+  }
+  catch {
+    return null;
+  }
+}
@@ -7,6 +7,7 @@ dotenv.config();
 export const RPC_URL = process.env.RPC_URL ?? "";
 export const SIGNER = Keypair.fromSecretKey(bs58.decode(process.env.WALLET_PRIVKEY ?? ""));
 export const RAFFLE_PROGRAM_ID = new PublicKey(process.env.RAFFLE_PROGRAM_ID ?? "");
+export const PERMIT_SIGNER = new PublicKey(process.env.PERMIT_SIGNER ?? "");
 
 export const ID_LENGTH = 12;
 export const FEE_PERCENTAGE = 100;
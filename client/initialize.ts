@@ -2,9 +2,9 @@ import * as anchor from "@coral-xyz/anchor";
 import { AnchorProvider, Program, Wallet } from "@coral-xyz/anchor";
 import { Connection, PublicKey } from "@solana/web3.js";
 
-import { RAFFLE_PROGRAM_ID, RPC_URL, SIGNER } from ".";
+import { PERMIT_SIGNER, RAFFLE_PROGRAM_ID, RPC_URL, SIGNER } from ".";
 import { SolanaRaffle, IDL } from "../target/types/solana_raffle";
-import { findGlobalPda } from "./utils";
+import { findEventAuthorityPda, findGlobalPda } from "./utils";
 
 const main = async () => {
 
@@ -23,8 +23,11 @@ const main = async () => {
             authority: SIGNER.publicKey,
             globalAccount: globalPda,
             vault: new PublicKey("HK5Tf2wDXjvX1veuESXbcehaAjECcZdfnPCDfrcE7dAt"),
+            permitSigner: PERMIT_SIGNER,
             systemProgram: anchor.web3.SystemProgram.programId,
-            rent: anchor.web3.SYSVAR_RENT_PUBKEY
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+            eventAuthority: findEventAuthorityPda(),
+            program: program.programId,
         })
         .rpc();
     console.log(tx);
@@ -0,0 +1,40 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorProvider, Program, Wallet } from "@coral-xyz/anchor";
+import { Connection, PublicKey } from "@solana/web3.js";
+
+import { RAFFLE_PROGRAM_ID, RPC_URL, SIGNER } from ".";
+import { SolanaRaffle, IDL } from "../target/types/solana_raffle";
+import { findGlobalPda, findWhitelistPda } from "./utils";
+
+const main = async () => {
+
+    const connection = new Connection(RPC_URL);
+    const wallet = new Wallet(SIGNER);
+    const provider = new AnchorProvider(connection, wallet, {
+        commitment: 'confirmed'
+    });
+
+    const program = new Program<SolanaRaffle>(IDL, RAFFLE_PROGRAM_ID, provider);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+
+    // Move every legacy whitelist slot into its own entry account
+    for (const collection of globalAccount.wlCollections) {
+        if (collection.equals(PublicKey.default)) {
+            continue;
+        }
+
+        const tx = await program.methods.migrateWhitelist(collection)
+            .accounts({
+                authority: SIGNER.publicKey,
+                globalAccount: globalPda,
+                whitelistEntry: findWhitelistPda(collection),
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .rpc();
+        console.log(collection.toBase58(), tx);
+    }
+}
+
+main();
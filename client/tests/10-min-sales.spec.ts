@@ -0,0 +1,143 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import AUTHORITY_WALLET from './keypairs/authority.json';
+import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
+import PAYER_WALLET from './keypairs/payer.json';
+import { addPrize, addWhitelist, buyTicket, createRaffle, delay, findEventAuthorityPda, findGlobalPda, findRafflePda, findShardEscrowPda, findShardPda, mintNft, reclaimPrize, refundTicket, revealWinner, safeAirdrop, shardEscrowAccounts, transferNft } from "../utils";
+import { SolanaRaffle } from "../../target/types/solana_raffle";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { assert } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { TokenStandard } from "@metaplex-foundation/mpl-token-metadata";
+
+
+describe("minimum sales", () => {
+
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
+
+  const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
+  const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
+  const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
+
+  const totalUsers = 2;
+  let userIds: string[] = [];
+  let userSigners: Keypair[] = [];
+
+  let collection;
+  before(async () => {
+    collection = await mintNft(provider.connection, authority, true);
+    await addWhitelist(program, authority, collection);
+
+    for (let i = 1; i <= totalUsers; i++) {
+      userIds.push(`63efe02f54d5460081a36a${i.toString().padEnd(2, '0')}`);
+
+      const signer = Keypair.generate();
+      await safeAirdrop(provider.connection, signer.publicKey, 1);
+      userSigners.push(signer);
+    }
+  })
+
+  it('Failed create raffle as minimum sales above supply', async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+
+    try {
+      await createRaffle(program, globalAccount.totalRaffles, payer, NATIVE_MINT, 10, new anchor.BN(1_000_000), new anchor.BN(now), new anchor.BN(now + 10), 1, 11);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("InvalidAmount"), "Transaction should be reverted with InvalidAmount.");
+    }
+  });
+
+  it('Success refund when minimum sales not reached', async () => {
+
+    const totalSupply = 10;
+    const minSales = 5;
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 8);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+    const escrowPda = findShardEscrowPda(rafflePda, 0);
+
+    const splMint = NATIVE_MINT;
+    await createRaffle(program, raffleIdx, payer, splMint, totalSupply, price, startDate, endDate, 1, minSales);
+
+    const nft = await mintNft(provider.connection, payer, false, collection, authority);
+    await addPrize(program, raffleIdx, payer, nft);
+
+    await delay(2)
+    for (let i = 0; i < totalUsers; i++) {
+      await buyTicket(program, userIds[i], 2, raffleIdx, userSigners[i], permitSigner, splMint);
+    }
+
+    // Refund is not available while raffle is live
+    try {
+      await refundTicket(program, raffleIdx, userIds[0], userSigners[0].publicKey, splMint, payer);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RaffleNotRefundable"), "Transaction should be reverted with RaffleNotRefundable.");
+    }
+
+    await delay(7);
+
+    try {
+      await revealWinner(program, authority, raffleIdx);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("MinSalesNotReached"), "Transaction should be reverted with MinSalesNotReached.");
+    }
+
+    try {
+      await program.methods.withdrawRaffle(raffleIdx)
+        .accounts({
+          authority: payer.publicKey,
+          raffleAccount: rafflePda,
+          splMint,
+          treasuryTokenAccount: payer.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          eventAuthority: findEventAuthorityPda(),
+          program: program.programId,
+        })
+        .remainingAccounts(shardEscrowAccounts(raffleIdx))
+        .signers([payer])
+        .rpc();
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("MinSalesNotReached"), "Transaction should be reverted with MinSalesNotReached.");
+    }
+
+    // Anyone can push refunds to buyers
+    for (let i = 0; i < totalUsers; i++) {
+      const balanceBefore = await provider.connection.getBalance(userSigners[i].publicKey);
+      await refundTicket(program, raffleIdx, userIds[i], userSigners[i].publicKey, splMint, payer);
+      const balanceAfter = await provider.connection.getBalance(userSigners[i].publicKey);
+      assert(balanceAfter > balanceBefore, "Refund not received");
+    }
+    const ticketShard = await program.account.ticketShard.fetch(findShardPda(raffleIdx, 0));
+    assert(await provider.connection.getBalance(escrowPda) == ticketShard.fees.toNumber(), "Escrow should only hold fee");
+
+    // Creator gets the prize released
+    await reclaimPrize(program, raffleIdx, payer);
+    await transferNft(provider.connection, payer, authority.publicKey, nft, TokenStandard.NonFungible);
+  });
+
+});
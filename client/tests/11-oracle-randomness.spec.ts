@@ -0,0 +1,140 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import AUTHORITY_WALLET from './keypairs/authority.json';
+import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
+import PAYER_WALLET from './keypairs/payer.json';
+import { addPrize, addWhitelist, buyTicket, claimPrize, createRaffle, delay, findGlobalPda, findRafflePda, findOracleSeed, findTicketUid, fulfillOracle, mintNft, requestOracle, requestRandomness, safeAirdrop, settleRandomness, transferNft, waitForRaffleEnd } from "../utils";
+import { SolanaRaffle } from "../../target/types/solana_raffle";
+import { MockOracle } from "../../target/types/mock_oracle";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { assert } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { TokenStandard } from "@metaplex-foundation/mpl-token-metadata";
+import { keccak_256 } from "@noble/hashes/sha3";
+
+
+describe("oracle randomness", () => {
+
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
+  const oracle = anchor.workspace.MockOracle as anchor.Program<MockOracle>;
+
+  const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
+  const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
+  const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
+
+  const totalUsers = 3;
+  let userIds: string[] = [];
+  let userSigners: Keypair[] = [];
+
+  let collection;
+  before(async () => {
+    collection = await mintNft(provider.connection, authority, true);
+    await addWhitelist(program, authority, collection);
+
+    for (let i = 1; i <= totalUsers; i++) {
+      userIds.push(`63efe02f54d5460081a36b${i.toString().padEnd(2, '0')}`);
+
+      const signer = Keypair.generate();
+      await safeAirdrop(provider.connection, signer.publicKey, 1);
+      userSigners.push(signer);
+    }
+  })
+
+  it('Success draw winner from oracle randomness', async () => {
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 10);
+    const price = new anchor.BN(1_000_000);
+
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    const splMint = NATIVE_MINT;
+    await createRaffle(program, raffleIdx, payer, splMint, 10, price, startDate, endDate, 1, 0);
+
+    const nft = await mintNft(provider.connection, payer, false, collection, authority);
+    await addPrize(program, raffleIdx, payer, nft);
+
+    await delay(2)
+    for (let i = 0; i < totalUsers; i++) {
+      await buyTicket(program, userIds[i], 1, raffleIdx, userSigners[i], permitSigner, splMint);
+    }
+
+    await waitForRaffleEnd(program, raffleIdx);
+
+    // Request opened with a seed not bound to the raffle is rejected
+    const unboundRequest = await requestOracle(oracle, payer, Buffer.from(Keypair.generate().publicKey.toBytes()));
+    try {
+      await requestRandomness(program, authority, raffleIdx, unboundRequest);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("InvalidRandomnessAccount"), "Transaction should be reverted with InvalidRandomnessAccount.");
+    }
+
+    // Non randomness accounts of the oracle program are rejected
+    try {
+      await requestRandomness(program, authority, raffleIdx, oracle.programId);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("InvalidOwner") || ex.toString().includes("InvalidRandomnessAccount"), "Transaction should be reverted.");
+    }
+
+    const seed = findOracleSeed(rafflePda);
+    const oracleRequest = await requestOracle(oracle, payer, seed);
+    await requestRandomness(program, authority, raffleIdx, oracleRequest);
+
+    // Pending oracle request is replaced only after it timed out
+    try {
+      await requestRandomness(program, authority, raffleIdx);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RandomnessAlreadyRequested"), "Transaction should be reverted with RandomnessAlreadyRequested.");
+    }
+
+    // Sales are closed once randomness is requested
+    try {
+      await buyTicket(program, userIds[0], 1, raffleIdx, userSigners[0], permitSigner, splMint);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RaffleExpired") || ex.toString().includes("RandomnessAlreadyRequested"), "Transaction should be reverted.");
+    }
+
+    try {
+      await settleRandomness(program, payer, raffleIdx, oracleRequest);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RandomnessNotReady"), "Transaction should be reverted with RandomnessNotReady.");
+    }
+
+    const randomness = Buffer.alloc(64, 7);
+    await fulfillOracle(oracle, payer, seed, randomness);
+    await settleRandomness(program, payer, raffleIdx, oracleRequest);
+
+    // Anyone can recompute the draw seed from stored randomness
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    assert(raffleAccount.isRevealed, "Winner not revealed");
+    assert(Buffer.from(raffleAccount.randomness.value).equals(randomness), "Randomness not stored");
+
+    const drawSeed = keccak_256(Buffer.concat([randomness, rafflePda.toBuffer()]));
+    assert(Buffer.from(raffleAccount.randomness.seed).equals(Buffer.from(drawSeed)), "Seed mismatch");
+
+    const winnerIdx = raffleAccount.prizes[0].winnerIdx;
+    const winner = userIds.indexOf(await findTicketUid(program, raffleIdx, winnerIdx - 1));
+    await claimPrize(program, raffleIdx, userIds[winner], userSigners[winner]);
+    await transferNft(provider.connection, userSigners[winner], authority.publicKey, nft, TokenStandard.NonFungible);
+  });
+
+});
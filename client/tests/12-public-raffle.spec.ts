@@ -0,0 +1,125 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import AUTHORITY_WALLET from './keypairs/authority.json';
+import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
+import PAYER_WALLET from './keypairs/payer.json';
+import { addPrizeSol, buyTicket, claimPrizeSol, createRaffle, delay, findGlobalPda, findPrizeEscrowPda, findRafflePda, findTicketUid, findUserPda, revealWinner, safeAirdrop, walletUid } from "../utils";
+import { SolanaRaffle } from "../../target/types/solana_raffle";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { assert } from "chai";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+
+
+describe("public raffle", () => {
+
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
+
+  const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
+  const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
+  const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
+
+  const totalUsers = 3;
+  let userSigners: Keypair[] = [];
+
+  const getUserSigner = (uid: string): Keypair => {
+    return userSigners.find(signer => walletUid(signer.publicKey) == uid);
+  }
+
+  before(async () => {
+    await safeAirdrop(provider.connection, payer.publicKey, 2);
+
+    for (let i = 0; i < totalUsers; i++) {
+      const signer = Keypair.generate();
+      await safeAirdrop(provider.connection, signer.publicKey, 1);
+      userSigners.push(signer);
+    }
+  })
+
+  it('Buy tickets by wallet without permit', async () => {
+
+    const totalSupply = 10;
+    const prizeAmount = new anchor.BN(LAMPORTS_PER_SOL / 10);
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 10);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    const splMint = NATIVE_MINT;
+    await createRaffle(program, raffleIdx, payer, splMint, totalSupply, price, startDate, endDate, 1, 0, 1, true);
+    await addPrizeSol(program, raffleIdx, payer, prizeAmount);
+
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    assert(raffleAccount.isPublic == 1, "Raffle should be public");
+
+    await delay(3)
+
+    // User id must be derived from buyer wallet
+    try {
+      await buyTicket(program, walletUid(userSigners[1].publicKey), 1, raffleIdx, userSigners[0], null, splMint);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("InvalidUUID"), "Transaction should be reverted with InvalidUUID.");
+    }
+
+    for (let i = 0; i < totalUsers; i++) {
+      await buyTicket(program, walletUid(userSigners[i].publicKey), 2, raffleIdx, userSigners[i], null, splMint);
+    }
+
+    const userAccount = await program.account.userAccount.fetch(findUserPda(walletUid(userSigners[0].publicKey), rafflePda));
+    assert(userAccount.authority.equals(userSigners[0].publicKey), "User account not keyed by wallet");
+    assert(userAccount.amount == 2, "Ticket amount not matched");
+
+    await revealWinner(program, authority, raffleIdx);
+
+    const revealedAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    const winnerUid = await findTicketUid(program, raffleIdx, revealedAccount.prizes[0].winnerIdx - 1);
+    const winner = getUserSigner(winnerUid);
+
+    await claimPrizeSol(program, raffleIdx, winnerUid, winner);
+    assert(await provider.connection.getBalance(findPrizeEscrowPda(rafflePda, 0)) == 0, "Prize vault not empty");
+  });
+
+  it('Uid gated raffle still requires permit', async () => {
+
+    const totalSupply = 10;
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 10);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+
+    const splMint = NATIVE_MINT;
+    await createRaffle(program, raffleIdx, payer, splMint, totalSupply, price, startDate, endDate);
+    await addPrizeSol(program, raffleIdx, payer, new anchor.BN(LAMPORTS_PER_SOL / 10));
+
+    await delay(2)
+
+    const buyer = userSigners[0];
+    try {
+      await buyTicket(program, walletUid(buyer.publicKey), 1, raffleIdx, buyer, null, splMint);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("InvalidPermit"), "Transaction should be reverted with InvalidPermit.");
+    }
+
+    await buyTicket(program, walletUid(buyer.publicKey), 1, raffleIdx, buyer, permitSigner, splMint);
+  });
+});
@@ -0,0 +1,160 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import AUTHORITY_WALLET from './keypairs/authority.json';
+import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
+import PAYER_WALLET from './keypairs/payer.json';
+import { addPrizeSol, buyTicket, createRaffle, delay, findGlobalPda, safeAirdrop, setFeeConfig, setRaffleFeeConfig, sweepFees } from "../utils";
+import { SolanaRaffle } from "../../target/types/solana_raffle";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { assert } from "chai";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { BASIS_POINTS, FEE_PERCENTAGE } from "..";
+
+
+describe("fee splits", () => {
+
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
+
+  const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
+  const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
+  const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
+
+  const buyerUid = "63efe02f54d5460081a36d01";
+  const buyer = Keypair.generate();
+  const partner = Keypair.generate();
+  const referrer = Keypair.generate();
+
+  const price = new anchor.BN(LAMPORTS_PER_SOL / 100);
+
+  const newRaffle = async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+    const raffleIdx = globalAccount.totalRaffles;
+
+    await createRaffle(program, raffleIdx, payer, NATIVE_MINT, 10, price, new anchor.BN(now), new anchor.BN(now + 20));
+    await addPrizeSol(program, raffleIdx, payer, new anchor.BN(LAMPORTS_PER_SOL / 10));
+    return raffleIdx;
+  }
+
+  before(async () => {
+    await safeAirdrop(provider.connection, payer.publicKey, 2);
+    await safeAirdrop(provider.connection, buyer.publicKey, 1);
+
+    // Native recipients must stay rent exempt
+    await safeAirdrop(provider.connection, partner.publicKey, 1);
+    await safeAirdrop(provider.connection, referrer.publicKey, 1);
+  })
+
+  it('Validate fee split shares', async () => {
+    const raffleIdx = await newRaffle();
+
+    try {
+      await setRaffleFeeConfig(program, authority, raffleIdx, [
+        { wallet: vault.publicKey, share: 5000 },
+        { wallet: partner.publicKey, share: 4000 },
+      ]);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("InvalidFeeConfig"), "Transaction should be reverted with InvalidFeeConfig.");
+    }
+
+    try {
+      await setRaffleFeeConfig(program, payer, raffleIdx, [
+        { wallet: payer.publicKey, share: BASIS_POINTS },
+      ]);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("ConstraintHasOne"), "Transaction should be reverted with ConstraintHasOne.");
+    }
+  });
+
+  it('Split fee by raffle fee config', async () => {
+    const raffleIdx = await newRaffle();
+
+    await setRaffleFeeConfig(program, authority, raffleIdx, [
+      { wallet: vault.publicKey, share: 5000 },
+      { wallet: partner.publicKey, share: 3000 },
+      { wallet: referrer.publicKey, share: 2000 },
+    ]);
+
+    await delay(2);
+
+    const vaultBefore = await provider.connection.getBalance(vault.publicKey);
+    const partnerBefore = await provider.connection.getBalance(partner.publicKey);
+    const referrerBefore = await provider.connection.getBalance(referrer.publicKey);
+
+    await buyTicket(program, buyerUid, 2, raffleIdx, buyer, permitSigner, NATIVE_MINT);
+    await sweepFees(program, buyer, raffleIdx);
+
+    const feeAmount = price.muln(2).muln(FEE_PERCENTAGE).divn(BASIS_POINTS).toNumber();
+    assert(await provider.connection.getBalance(vault.publicKey) - vaultBefore == feeAmount * 0.5, "Vault share not matched");
+    assert(await provider.connection.getBalance(partner.publicKey) - partnerBefore == feeAmount * 0.3, "Partner share not matched");
+    assert(await provider.connection.getBalance(referrer.publicKey) - referrerBefore == feeAmount * 0.2, "Referrer share not matched");
+  });
+
+  it('Keep raffle fee config while fees are held', async () => {
+    const raffleIdx = await newRaffle();
+
+    await setRaffleFeeConfig(program, authority, raffleIdx, [
+      { wallet: partner.publicKey, share: BASIS_POINTS },
+    ]);
+
+    await delay(2);
+    await buyTicket(program, buyerUid, 1, raffleIdx, buyer, permitSigner, NATIVE_MINT);
+
+    try {
+      await setRaffleFeeConfig(program, authority, raffleIdx, [
+        { wallet: referrer.publicKey, share: BASIS_POINTS },
+      ]);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("FeesNotSwept"), "Transaction should be reverted with FeesNotSwept.");
+    }
+
+    // Split can change once held fees are swept
+    await sweepFees(program, buyer, raffleIdx);
+    await setRaffleFeeConfig(program, authority, raffleIdx, [
+      { wallet: referrer.publicKey, share: BASIS_POINTS },
+    ]);
+  });
+
+  it('Split fee by global fee config', async () => {
+    await setFeeConfig(program, authority, [
+      { wallet: vault.publicKey, share: 6000 },
+      { wallet: partner.publicKey, share: 4000 },
+    ]);
+
+    try {
+      const raffleIdx = await newRaffle();
+      await delay(2);
+
+      const partnerBefore = await provider.connection.getBalance(partner.publicKey);
+      await buyTicket(program, buyerUid, 1, raffleIdx, buyer, permitSigner, NATIVE_MINT);
+
+      // Raffle keeps the split in effect at its creation
+      await setFeeConfig(program, authority, [
+        { wallet: vault.publicKey, share: BASIS_POINTS },
+      ]);
+      await sweepFees(program, buyer, raffleIdx);
+
+      const feeAmount = price.muln(FEE_PERCENTAGE).divn(BASIS_POINTS).toNumber();
+      assert(await provider.connection.getBalance(partner.publicKey) - partnerBefore == feeAmount * 0.4, "Partner share not matched");
+    }
+    finally {
+      // Whole fee goes back to vault
+      await setFeeConfig(program, authority, []);
+    }
+
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+    assert(globalAccount.hasFeeConfig == 0, "Global fee config not cleared");
+  });
+});
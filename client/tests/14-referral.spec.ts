@@ -0,0 +1,97 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import AUTHORITY_WALLET from './keypairs/authority.json';
+import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
+import PAYER_WALLET from './keypairs/payer.json';
+import { addPrizeSol, buyTicket, claimReferral, createRaffle, delay, findGlobalPda, findReferralPda, initReferral, newBuyPermit, safeAirdrop, sweepFees, updateFees } from "../utils";
+import { SolanaRaffle } from "../../target/types/solana_raffle";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { assert } from "chai";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { BASIS_POINTS, FEE_PERCENTAGE, MAX_TICKET_PERCENT } from "..";
+
+
+describe("referral", () => {
+
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
+
+  const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
+  const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
+  const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
+
+  const buyerUid = "63efe02f54d5460081a36d02";
+  const buyer = Keypair.generate();
+  const referrer = Keypair.generate();
+
+  const price = new anchor.BN(LAMPORTS_PER_SOL / 100);
+  const referralPercent = 2500;
+
+  before(async () => {
+    await safeAirdrop(provider.connection, payer.publicKey, 2);
+    await safeAirdrop(provider.connection, buyer.publicKey, 1);
+    await safeAirdrop(provider.connection, referrer.publicKey, 1);
+  })
+
+  it('Credit and claim referral rewards', async () => {
+    await updateFees(program, authority, FEE_PERCENTAGE, MAX_TICKET_PERCENT, referralPercent);
+
+    try {
+      const now = Math.floor(Date.now() / 1000);
+      const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+      const raffleIdx = globalAccount.totalRaffles;
+
+      await createRaffle(program, raffleIdx, payer, NATIVE_MINT, 10, price, new anchor.BN(now), new anchor.BN(now + 20));
+      await addPrizeSol(program, raffleIdx, payer, new anchor.BN(LAMPORTS_PER_SOL / 10));
+      await initReferral(program, referrer, NATIVE_MINT);
+      await initReferral(program, buyer, NATIVE_MINT);
+
+      await delay(2);
+
+      // Buyers can not refer themselves
+      try {
+        await buyTicket(program, buyerUid, 1, raffleIdx, buyer, permitSigner, NATIVE_MINT, 0, newBuyPermit(1), buyer.publicKey);
+        assert(false, "Transaction should be reverted");
+      }
+      catch (ex) {
+        assert(ex.toString().includes("SelfReferral"), "Transaction should be reverted with SelfReferral.");
+      }
+
+      const vaultBefore = await provider.connection.getBalance(vault.publicKey);
+      await buyTicket(program, buyerUid, 2, raffleIdx, buyer, permitSigner, NATIVE_MINT, 0, newBuyPermit(2), referrer.publicKey);
+      await sweepFees(program, buyer, raffleIdx);
+
+      const feeAmount = price.muln(2).muln(FEE_PERCENTAGE).divn(BASIS_POINTS).toNumber();
+      const referralAmount = Math.floor(feeAmount * referralPercent / BASIS_POINTS);
+      assert(await provider.connection.getBalance(vault.publicKey) - vaultBefore == feeAmount - referralAmount, "Vault share not matched");
+
+      const referralPda = findReferralPda(referrer.publicKey, NATIVE_MINT);
+      let referralAccount = await program.account.referralAccount.fetch(referralPda);
+      assert(referralAccount.accrued.toNumber() == referralAmount, "Accrued referral not matched");
+
+      const referrerBefore = await provider.connection.getBalance(referrer.publicKey);
+      await claimReferral(program, referrer, NATIVE_MINT);
+
+      referralAccount = await program.account.referralAccount.fetch(referralPda);
+      assert(referralAccount.claimed.eq(referralAccount.accrued), "Referral not fully claimed");
+      assert(await provider.connection.getBalance(referrer.publicKey) - referrerBefore == referralAmount, "Claimed referral not matched");
+
+      // Nothing left to claim
+      try {
+        await claimReferral(program, referrer, NATIVE_MINT);
+        assert(false, "Transaction should be reverted");
+      }
+      catch (ex) {
+        assert(ex.toString().includes("InvalidAmount"), "Transaction should be reverted with InvalidAmount.");
+      }
+    }
+    finally {
+      await updateFees(program, authority, FEE_PERCENTAGE, MAX_TICKET_PERCENT);
+    }
+  });
+});
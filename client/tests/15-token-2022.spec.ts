@@ -0,0 +1,101 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
+import PAYER_WALLET from './keypairs/payer.json';
+import { addPrizeSol, buyTicket, createRaffle, delay, findGlobalPda, findRafflePda, findShardEscrowPda, findShardPda, findUserPda, safeAirdrop, withdrawRaffle } from "../utils";
+import { SolanaRaffle } from "../../target/types/solana_raffle";
+import { ExtensionType, TOKEN_2022_PROGRAM_ID, createInitializeMintInstruction, createInitializeTransferFeeConfigInstruction, getAccount, getMintLen, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
+import { assert } from "chai";
+import { Keypair, LAMPORTS_PER_SOL, SystemProgram, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { BASIS_POINTS, FEE_PERCENTAGE } from "..";
+
+
+describe("token-2022 payments", () => {
+
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
+
+  const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
+  const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
+
+  const buyerUid = "63efe02f54d5460081a36d03";
+  const buyer = Keypair.generate();
+  const splMint = Keypair.generate();
+
+  const decimals = 6;
+  const transferFeeBasisPoints = 100;
+
+  // Withheld fee of the mint's transfer fee extension
+  const transferFee = (amount: number) => Math.ceil(amount * transferFeeBasisPoints / BASIS_POINTS);
+
+  before(async () => {
+    await safeAirdrop(provider.connection, payer.publicKey, 2);
+    await safeAirdrop(provider.connection, buyer.publicKey, 1);
+
+    // Token-2022 mint with 1% transfer fee
+    const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    const lamports = await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+    const tx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: payer.publicKey,
+        newAccountPubkey: splMint.publicKey,
+        space: mintLen,
+        lamports,
+        programId: TOKEN_2022_PROGRAM_ID,
+      }),
+      createInitializeTransferFeeConfigInstruction(splMint.publicKey, payer.publicKey, payer.publicKey, transferFeeBasisPoints, BigInt(LAMPORTS_PER_SOL), TOKEN_2022_PROGRAM_ID),
+      createInitializeMintInstruction(splMint.publicKey, decimals, payer.publicKey, null, TOKEN_2022_PROGRAM_ID),
+    );
+    await sendAndConfirmTransaction(provider.connection, tx, [payer, splMint]);
+
+    const buyerAta = await getOrCreateAssociatedTokenAccount(provider.connection, payer, splMint.publicKey, buyer.publicKey, false, undefined, undefined, TOKEN_2022_PROGRAM_ID);
+    await mintTo(provider.connection, payer, splMint.publicKey, buyerAta.address, payer, 1_000_000_000, [], undefined, TOKEN_2022_PROGRAM_ID);
+    await getOrCreateAssociatedTokenAccount(provider.connection, payer, splMint.publicKey, vault.publicKey, false, undefined, undefined, TOKEN_2022_PROGRAM_ID);
+  })
+
+  it('Buy tickets and withdraw with transfer fee mint', async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const price = new anchor.BN(1_000_000);
+
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    await createRaffle(program, raffleIdx, payer, splMint.publicKey, 10, price, new anchor.BN(now), new anchor.BN(now + 10));
+    await addPrizeSol(program, raffleIdx, payer, new anchor.BN(LAMPORTS_PER_SOL / 10));
+
+    await delay(2);
+
+    await buyTicket(program, buyerUid, 2, raffleIdx, buyer, permitSigner, splMint.publicKey);
+
+    // Deposit only counts what escrow actually received, fee is held next to it
+    const totalAmount = price.muln(2).toNumber();
+    const feeAmount = Math.floor(totalAmount * FEE_PERCENTAGE / BASIS_POINTS);
+    const remainAmount = totalAmount - feeAmount;
+    const depositAmount = remainAmount - transferFee(remainAmount);
+    const escrowAmount = totalAmount - transferFee(totalAmount);
+
+    const escrowPda = findShardEscrowPda(rafflePda, 0);
+    const escrowInfo = await getAccount(provider.connection, escrowPda, undefined, TOKEN_2022_PROGRAM_ID);
+    assert(Number(escrowInfo.amount) == escrowAmount, "Escrow balance not matched");
+
+    const userAccount = await program.account.userAccount.fetch(findUserPda(buyerUid, rafflePda));
+    assert(userAccount.deposited.toNumber() == depositAmount, "Deposited amount not matched");
+
+    const ticketShard = await program.account.ticketShard.fetch(findShardPda(raffleIdx, 0));
+    assert(ticketShard.fees.toNumber() == escrowAmount - depositAmount, "Held fee not matched");
+
+    await delay(9);
+
+    const treasuryAta = await getOrCreateAssociatedTokenAccount(provider.connection, payer, splMint.publicKey, payer.publicKey, false, undefined, undefined, TOKEN_2022_PROGRAM_ID);
+    await withdrawRaffle(program, raffleIdx, payer);
+
+    const treasuryInfo = await getAccount(provider.connection, treasuryAta.address, undefined, TOKEN_2022_PROGRAM_ID);
+    assert(Number(treasuryInfo.amount) == depositAmount - transferFee(depositAmount), "Treasury balance not matched");
+  });
+});
@@ -0,0 +1,144 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import AUTHORITY_WALLET from './keypairs/authority.json';
+import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
+import { MPL_CORE_PROGRAM_ID, PrizeKind, addPrize2022, addPrizeCore, addWhitelist, buyTicket, claimPrize2022, claimPrizeCore, createCoreCollection, createRaffle, delay, findGlobalPda, findPrizeEscrowPda, findRafflePda, findTicketUid, mintCoreAsset, mintNft2022, reclaimPrizeCore, revealWinner, safeAirdrop } from "../utils";
+import { SolanaRaffle } from "../../target/types/solana_raffle";
+import { NATIVE_MINT, TOKEN_2022_PROGRAM_ID, getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { assert } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+
+describe("token-2022 and core prizes", () => {
+
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
+
+  const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
+  const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
+
+  // Fresh creator, whitelisted by creator address for token-2022 nft
+  const creator = Keypair.generate();
+
+  const totalUsers = 3;
+  let userIds: string[] = [];
+  let userSigners: Keypair[] = [];
+
+  const getUserSigner = (uid: string): Keypair => {
+    for (let i = 0; i < totalUsers; i++) {
+      if (userIds[i] == uid) {
+        return userSigners[i];
+      }
+    }
+  }
+
+  const newRaffle = async (): Promise<number> => {
+    const now = Math.floor(Date.now() / 1000);
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+    const raffleIdx = globalAccount.totalRaffles;
+
+    await createRaffle(program, raffleIdx, creator, NATIVE_MINT, 10, new anchor.BN(1_000_000), new anchor.BN(now), new anchor.BN(now + 10));
+    return raffleIdx;
+  }
+
+  const drawWinner = async (raffleIdx: number): Promise<[string, Keypair]> => {
+    await delay(3);
+    for (let i = 0; i < totalUsers; i++) {
+      await buyTicket(program, userIds[i], 2, raffleIdx, userSigners[i], permitSigner, NATIVE_MINT);
+    }
+    await revealWinner(program, authority, raffleIdx);
+
+    const raffleAccount = await program.account.raffleAccountV2.fetch(findRafflePda(new anchor.BN(raffleIdx)));
+    const winnerUid = await findTicketUid(program, raffleIdx, raffleAccount.prizes[0].winnerIdx - 1);
+    return [winnerUid, getUserSigner(winnerUid)];
+  }
+
+  before(async () => {
+    await safeAirdrop(provider.connection, creator.publicKey, 2);
+    await addWhitelist(program, authority, creator.publicKey, true);
+
+    for (let i = 1; i <= totalUsers; i++) {
+      userIds.push(`63efe02f54d5460081a368${i.toString().padEnd(2, '0')}`);
+
+      const signer = Keypair.generate();
+      await safeAirdrop(provider.connection, signer.publicKey, 1);
+      userSigners.push(signer);
+    }
+  })
+
+  it('Deposit token-2022 nft into prize escrow and claim', async () => {
+    const raffleIdx = await newRaffle();
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    const nft = await mintNft2022(provider.connection, creator);
+    await addPrize2022(program, raffleIdx, creator, nft);
+
+    // Nft leaves creator wallet for the prize escrow
+    const prizeEscrow = findPrizeEscrowPda(rafflePda, 0);
+    const prizeEscrowAcc = await getAccount(provider.connection, prizeEscrow, undefined, TOKEN_2022_PROGRAM_ID);
+    assert(prizeEscrowAcc.amount == BigInt(1), "Prize not deposited");
+
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    assert(raffleAccount.prizes[0].kind == PrizeKind.Nft2022, "Prize kind not matched");
+
+    const [winnerUid, winner] = await drawWinner(raffleIdx);
+    await claimPrize2022(program, raffleIdx, winnerUid, winner);
+
+    const winnerAta = getAssociatedTokenAddressSync(nft, winner.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const winnerAtaAcc = await getAccount(provider.connection, winnerAta, undefined, TOKEN_2022_PROGRAM_ID);
+    assert(winnerAtaAcc.amount == BigInt(1), "Prize not claimed");
+
+    const prizeEscrowInfo = await provider.connection.getAccountInfo(prizeEscrow);
+    assert(prizeEscrowInfo == null, "Prize escrow not closed");
+  });
+
+  describe("core assets", () => {
+    let collection: PublicKey;
+
+    before(async () => {
+      // Core program is loaded from local-deploy/mpl_core.so, see `yarn programs:dump`
+      const coreProgram = await provider.connection.getAccountInfo(MPL_CORE_PROGRAM_ID);
+      assert(coreProgram?.executable, "MPL Core program not deployed");
+
+      collection = await createCoreCollection(provider.connection, authority);
+      await addWhitelist(program, authority, collection);
+    })
+
+    it('Freeze core asset in place and claim', async () => {
+      const raffleIdx = await newRaffle();
+      const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+      const asset = await mintCoreAsset(provider.connection, authority, creator.publicKey, collection);
+      await addPrizeCore(program, raffleIdx, creator, asset, collection);
+
+      const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+      assert(raffleAccount.prizes[0].kind == PrizeKind.Core, "Prize kind not matched");
+      assert(raffleAccount.prizes[0].mint.equals(asset), "Prize asset not matched");
+
+      const [winnerUid, winner] = await drawWinner(raffleIdx);
+      await claimPrizeCore(program, raffleIdx, winnerUid, winner, collection);
+
+      // Owner lives at bytes 1..33 of AssetV1
+      const assetInfo = await provider.connection.getAccountInfo(asset);
+      assert(new PublicKey(assetInfo.data.subarray(1, 33)).equals(winner.publicKey), "Prize not claimed");
+    });
+
+    it('Reclaim core asset of raffle without sales', async () => {
+      const raffleIdx = await newRaffle();
+
+      const asset = await mintCoreAsset(provider.connection, authority, creator.publicKey, collection);
+      await addPrizeCore(program, raffleIdx, creator, asset, collection);
+
+      await delay(12);
+      await reclaimPrizeCore(program, raffleIdx, creator, collection);
+
+      const assetInfo = await provider.connection.getAccountInfo(asset);
+      assert(new PublicKey(assetInfo.data.subarray(1, 33)).equals(creator.publicKey), "Asset owner changed");
+    });
+  });
+});
@@ -0,0 +1,191 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import AUTHORITY_WALLET from './keypairs/authority.json';
+import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
+import { BUBBLEGUM_PROGRAM_ID, CnftLeaf, PrizeKind, addPrizeCnft, addWhitelist, buyTicket, claimPrizeCnft, createRaffle, delay, findEscrowPda, findGlobalPda, findRafflePda, findTicketUid, findTreeAuthorityPda, reclaimPrizeCnft, revealWinner, safeAirdrop } from "../utils";
+import { SolanaRaffle } from "../../target/types/solana_raffle";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { assert } from "chai";
+import { Keypair, PublicKey, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { MetadataArgs, TokenProgramVersion, TokenStandard, computeCompressedNFTHash, createCreateTreeInstruction, createMintV1Instruction, getLeafAssetId } from "@metaplex-foundation/mpl-bubblegum";
+import { ConcurrentMerkleTreeAccount, MerkleTree, SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, SPL_NOOP_PROGRAM_ID, createAllocTreeIx } from "@solana/spl-account-compression";
+
+
+describe("compressed nft prize", () => {
+
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
+
+  const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
+  const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
+
+  // Fresh creator, whitelisted by verified creator address
+  const creator = Keypair.generate();
+  const merkleTree = Keypair.generate();
+  const maxDepth = 3;
+
+  const totalUsers = 3;
+  let userIds: string[] = [];
+  let userSigners: Keypair[] = [];
+
+  // Local copy of the tree, mirrors every leaf change made on chain
+  let tree: MerkleTree;
+  let totalMinted = 0;
+
+  const getUserSigner = (uid: string): Keypair => {
+    for (let i = 0; i < totalUsers; i++) {
+      if (userIds[i] == uid) {
+        return userSigners[i];
+      }
+    }
+  }
+
+  const metadata: MetadataArgs = {
+    name: "Test",
+    symbol: "TEST",
+    uri: "",
+    sellerFeeBasisPoints: 0,
+    primarySaleHappened: false,
+    isMutable: true,
+    editionNonce: null,
+    tokenStandard: TokenStandard.NonFungible,
+    collection: null,
+    uses: null,
+    tokenProgramVersion: TokenProgramVersion.Original,
+    creators: [{ address: creator.publicKey, verified: true, share: 100 }],
+  };
+
+  // Same metadata in the shape of the raffle program IDL
+  const metadataArgs = {
+    ...metadata,
+    tokenStandard: { nonFungible: {} },
+    tokenProgramVersion: { original: {} },
+  };
+
+  const currentLeaf = async (index: number): Promise<CnftLeaf> => {
+    const treeAccount = await ConcurrentMerkleTreeAccount.fromAccountAddress(provider.connection, merkleTree.publicKey);
+    const { proof } = tree.getProof(index);
+    return {
+      merkleTree: merkleTree.publicKey,
+      root: Array.from(treeAccount.getCurrentRoot()),
+      proof: proof.map(node => new PublicKey(node)),
+      nonce: index,
+      index,
+    };
+  }
+
+  const setLeafOwner = (index: number, owner: PublicKey) => {
+    const assetId = getLeafAssetId(merkleTree.publicKey, new anchor.BN(index));
+    tree.updateLeaf(index, computeCompressedNFTHash(assetId, owner, owner, new anchor.BN(index), metadata));
+  }
+
+  const mintCnft = async (): Promise<number> => {
+    const tx = new Transaction().add(createMintV1Instruction({
+      treeAuthority: findTreeAuthorityPda(merkleTree.publicKey),
+      leafOwner: creator.publicKey,
+      leafDelegate: creator.publicKey,
+      merkleTree: merkleTree.publicKey,
+      payer: creator.publicKey,
+      treeDelegate: creator.publicKey,
+      logWrapper: SPL_NOOP_PROGRAM_ID,
+      compressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+    }, { message: metadata }));
+    await sendAndConfirmTransaction(provider.connection, tx, [creator]);
+
+    const index = totalMinted++;
+    setLeafOwner(index, creator.publicKey);
+    return index;
+  }
+
+  const newRaffle = async (): Promise<number> => {
+    const now = Math.floor(Date.now() / 1000);
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+    const raffleIdx = globalAccount.totalRaffles;
+
+    await createRaffle(program, raffleIdx, creator, NATIVE_MINT, 10, new anchor.BN(1_000_000), new anchor.BN(now), new anchor.BN(now + 10));
+    return raffleIdx;
+  }
+
+  before(async () => {
+    // Bubblegum and compression programs are loaded from local-deploy, see `yarn programs:dump`
+    const bubblegum = await provider.connection.getAccountInfo(BUBBLEGUM_PROGRAM_ID);
+    assert(bubblegum?.executable, "Bubblegum program not deployed");
+
+    await safeAirdrop(provider.connection, creator.publicKey, 2);
+    await addWhitelist(program, authority, creator.publicKey, true);
+
+    const tx = new Transaction().add(
+      await createAllocTreeIx(provider.connection, merkleTree.publicKey, creator.publicKey, { maxDepth, maxBufferSize: 8 }, 0),
+      createCreateTreeInstruction({
+        treeAuthority: findTreeAuthorityPda(merkleTree.publicKey),
+        merkleTree: merkleTree.publicKey,
+        payer: creator.publicKey,
+        treeCreator: creator.publicKey,
+        logWrapper: SPL_NOOP_PROGRAM_ID,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+      }, { maxDepth, maxBufferSize: 8, public: false }),
+    );
+    await sendAndConfirmTransaction(provider.connection, tx, [creator, merkleTree]);
+    tree = MerkleTree.sparseMerkleTreeFromLeaves([], maxDepth);
+
+    for (let i = 1; i <= totalUsers; i++) {
+      userIds.push(`63efe02f54d5460081a369${i.toString().padEnd(2, '0')}`);
+
+      const signer = Keypair.generate();
+      await safeAirdrop(provider.connection, signer.publicKey, 1);
+      userSigners.push(signer);
+    }
+  })
+
+  it('Escrow compressed nft and claim by winner', async () => {
+    const raffleIdx = await newRaffle();
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+    const escrowPda = findEscrowPda(rafflePda);
+
+    const index = await mintCnft();
+    await addPrizeCnft(program, raffleIdx, creator, await currentLeaf(index), metadataArgs, creator.publicKey);
+    setLeafOwner(index, escrowPda);
+
+    let raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    assert(raffleAccount.prizes[0].kind == PrizeKind.Cnft, "Prize kind not matched");
+    assert(raffleAccount.prizes[0].mint.equals(getLeafAssetId(merkleTree.publicKey, new anchor.BN(index))), "Asset id not matched");
+
+    await delay(3);
+    for (let i = 0; i < totalUsers; i++) {
+      await buyTicket(program, userIds[i], 2, raffleIdx, userSigners[i], permitSigner, NATIVE_MINT);
+    }
+    await revealWinner(program, authority, raffleIdx);
+
+    raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    const winnerUid = await findTicketUid(program, raffleIdx, raffleAccount.prizes[0].winnerIdx - 1);
+    const winner = getUserSigner(winnerUid);
+
+    await claimPrizeCnft(program, raffleIdx, winnerUid, winner, await currentLeaf(index));
+    setLeafOwner(index, winner.publicKey);
+
+    // Root only matches when the leaf is owned by the winner
+    const treeAccount = await ConcurrentMerkleTreeAccount.fromAccountAddress(provider.connection, merkleTree.publicKey);
+    assert(Buffer.from(treeAccount.getCurrentRoot()).equals(tree.root), "Leaf not transferred to winner");
+  });
+
+  it('Reclaim compressed nft of raffle without sales', async () => {
+    const raffleIdx = await newRaffle();
+    const escrowPda = findEscrowPda(findRafflePda(new anchor.BN(raffleIdx)));
+
+    const index = await mintCnft();
+    await addPrizeCnft(program, raffleIdx, creator, await currentLeaf(index), metadataArgs, creator.publicKey);
+    setLeafOwner(index, escrowPda);
+
+    await delay(12);
+    await reclaimPrizeCnft(program, raffleIdx, creator, await currentLeaf(index));
+    setLeafOwner(index, creator.publicKey);
+
+    const treeAccount = await ConcurrentMerkleTreeAccount.fromAccountAddress(provider.connection, merkleTree.publicKey);
+    assert(Buffer.from(treeAccount.getCurrentRoot()).equals(tree.root), "Leaf not returned to creator");
+  });
+});
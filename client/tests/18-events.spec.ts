@@ -0,0 +1,82 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import AUTHORITY_WALLET from './keypairs/authority.json';
+import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
+import PAYER_WALLET from './keypairs/payer.json';
+import { PrizeKind, addPrizeSol, buyTicket, claimPrizeSol, createRaffle, delay, fetchEvents, findGlobalPda, findRafflePda, findTicketUid, hexStringToNumArray, revealWinner, safeAirdrop } from "../utils";
+import { SolanaRaffle } from "../../target/types/solana_raffle";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { assert } from "chai";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { BASIS_POINTS, FEE_PERCENTAGE } from "..";
+
+
+describe("events", () => {
+
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
+
+  const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
+  const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
+  const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
+
+  const buyerUid = "63efe02f54d5460081a37001";
+  const buyer = Keypair.generate();
+
+  before(async () => {
+    await safeAirdrop(provider.connection, payer.publicKey, 2);
+    await safeAirdrop(provider.connection, buyer.publicKey, 1);
+  })
+
+  it('Emit events through raffle lifecycle', async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const price = new anchor.BN(1_000_000);
+    const prizeAmount = new anchor.BN(LAMPORTS_PER_SOL / 10);
+
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    await createRaffle(program, raffleIdx, payer, NATIVE_MINT, 10, price, new anchor.BN(now), new anchor.BN(now + 10));
+
+    // Events are emitted through self CPI, out of reach of log truncation
+    let events = await fetchEvents(program, await addPrizeSol(program, raffleIdx, payer, prizeAmount));
+    assert(events.length == 1 && events[0].name == "PrizeAdded", "PrizeAdded not emitted");
+    assert(events[0].data.raffle.equals(rafflePda), "Prize raffle not matched");
+    assert(events[0].data.kind.sol !== undefined, "Prize kind not matched");
+    assert((events[0].data.amount as anchor.BN).eq(prizeAmount), "Prize amount not matched");
+
+    await delay(2);
+
+    events = await fetchEvents(program, await buyTicket(program, buyerUid, 3, raffleIdx, buyer, permitSigner, NATIVE_MINT));
+    const bought = events.find(event => event.name == "TicketsBought");
+    const paid = price.muln(3);
+    assert(bought, "TicketsBought not emitted");
+    assert(bought.data.buyer.equals(buyer.publicKey), "Buyer not matched");
+    assert(Buffer.from(bought.data.uid as number[]).equals(Buffer.from(hexStringToNumArray(buyerUid))), "Uid not matched");
+    assert(bought.data.startIdx == 0 && bought.data.amount == 3, "Ticket range not matched");
+    assert((bought.data.paid as anchor.BN).eq(paid), "Paid amount not matched");
+    assert((bought.data.fee as anchor.BN).eq(paid.muln(FEE_PERCENTAGE).divn(BASIS_POINTS)), "Fee not matched");
+    assert(bought.data.referrer == null, "Referrer should be empty");
+
+    events = await fetchEvents(program, await revealWinner(program, authority, raffleIdx));
+    const revealed = events.find(event => event.name == "WinnersRevealed");
+    assert(revealed, "WinnersRevealed not emitted");
+    assert((revealed.data.winners as number[])[0] > 0, "Winner index not set");
+
+    const winnerUid = await findTicketUid(program, raffleIdx, (revealed.data.winners as number[])[0] - 1);
+    events = await fetchEvents(program, await claimPrizeSol(program, raffleIdx, winnerUid, buyer));
+    const claimed = events.find(event => event.name == "PrizeClaimed");
+    assert(claimed, "PrizeClaimed not emitted");
+    assert(claimed.data.winner.equals(buyer.publicKey), "Winner not matched");
+    assert(claimed.data.kind.sol !== undefined, "Claimed kind not matched");
+
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    assert(raffleAccount.prizes[0].kind == PrizeKind.Sol, "Prize kind not matched");
+  });
+});
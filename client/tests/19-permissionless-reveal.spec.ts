@@ -0,0 +1,229 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import AUTHORITY_WALLET from './keypairs/authority.json';
+import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
+import PAYER_WALLET from './keypairs/payer.json';
+import { addPrizeSol, buyTicket, cancelRaffle, createRaffle, delay, findGlobalPda, findRafflePda, findShardEscrowPda, findShardPda, pushPrizeSol, pushProceeds, refundTicket, requestRandomness, safeAirdrop, settleRandomness, updateFees, waitForRaffleEnd } from "../utils";
+import { SolanaRaffle } from "../../target/types/solana_raffle";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { assert } from "chai";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { BASIS_POINTS, FEE_PERCENTAGE, MAX_TICKET_PERCENT } from "..";
+
+
+describe("permissionless reveal", () => {
+
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
+
+  const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
+  const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
+  const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
+
+  const buyer = Keypair.generate();
+  const cranker = Keypair.generate();
+
+  const price = new anchor.BN(LAMPORTS_PER_SOL / 100);
+  const crankRewardPercent = 50;
+
+  before(async () => {
+    await safeAirdrop(provider.connection, payer.publicKey, 2);
+    await safeAirdrop(provider.connection, buyer.publicKey, 1);
+    await safeAirdrop(provider.connection, cranker.publicKey, 1);
+    await updateFees(program, authority, FEE_PERCENTAGE, MAX_TICKET_PERCENT, 0, crankRewardPercent);
+  })
+
+  after(async () => {
+    await updateFees(program, authority, FEE_PERCENTAGE, MAX_TICKET_PERCENT);
+  })
+
+  const settle = async (raffleIdx: number) => {
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    while (await provider.connection.getSlot() <= raffleAccount.randomness.slot.toNumber()) {
+      await delay(0.4);
+    }
+    await settleRandomness(program, cranker, raffleIdx);
+    return program.account.raffleAccountV2.fetch(rafflePda);
+  }
+
+  it('Anyone reveals ended raffle and earns crank reward', async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+    const escrowPda = findShardEscrowPda(rafflePda, 0);
+
+    await createRaffle(program, raffleIdx, payer, NATIVE_MINT, 10, price, new anchor.BN(now), new anchor.BN(now + 10));
+    await addPrizeSol(program, raffleIdx, payer, new anchor.BN(LAMPORTS_PER_SOL / 10));
+
+    await delay(2);
+    await buyTicket(program, "63efe02f54d5460081a37101", 3, raffleIdx, buyer, permitSigner, NATIVE_MINT);
+
+    // Nobody reveals before end date while tickets are left
+    for (const requester of [cranker, authority]) {
+      try {
+        await requestRandomness(program, requester, raffleIdx);
+        assert(false, "Transaction should be reverted");
+      }
+      catch (ex) {
+        assert(ex.toString().includes("RaffleNotEnded"), "Transaction should be reverted with RaffleNotEnded.");
+      }
+    }
+
+    await waitForRaffleEnd(program, raffleIdx);
+
+    // Reward rate is snapshot at creation, later setting changes don't apply
+    await updateFees(program, authority, FEE_PERCENTAGE, MAX_TICKET_PERCENT, 0, 0);
+    const escrowBefore = await provider.connection.getBalance(escrowPda);
+    await requestRandomness(program, cranker, raffleIdx);
+    await updateFees(program, authority, FEE_PERCENTAGE, MAX_TICKET_PERCENT, 0, crankRewardPercent);
+
+    // Reward is paid out of proceeds, fee held for the vault is left aside
+    const ticketShard = await program.account.ticketShard.fetch(findShardPda(raffleIdx, 0));
+    const crankReward = Math.floor((escrowBefore - ticketShard.fees.toNumber()) * crankRewardPercent / BASIS_POINTS);
+    assert(escrowBefore - await provider.connection.getBalance(escrowPda) == crankReward, "Crank reward not matched");
+
+    const raffleAccount = await settle(raffleIdx);
+    assert(raffleAccount.isRevealed, "Winner not revealed");
+    assert(raffleAccount.prizes[0].winnerIdx > 0, "Winner not drawn");
+  });
+
+  it('Can not cancel nor refund after crank request', async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+    const escrowPda = findShardEscrowPda(rafflePda, 0);
+
+    const uid = "63efe02f54d5460081a37103";
+    await createRaffle(program, raffleIdx, payer, NATIVE_MINT, 10, price, new anchor.BN(now), new anchor.BN(now + 10));
+    await addPrizeSol(program, raffleIdx, payer, new anchor.BN(LAMPORTS_PER_SOL / 10));
+
+    await delay(2);
+    await buyTicket(program, uid, 3, raffleIdx, buyer, permitSigner, NATIVE_MINT);
+
+    await waitForRaffleEnd(program, raffleIdx);
+    await requestRandomness(program, cranker, raffleIdx);
+    const escrowAfterCrank = await provider.connection.getBalance(escrowPda);
+
+    // Crank reward already left escrow, deposits can not be refunded in full
+    try {
+      await cancelRaffle(program, authority, raffleIdx);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RandomnessAlreadyRequested"), "Transaction should be reverted with RandomnessAlreadyRequested.");
+    }
+
+    try {
+      await refundTicket(program, raffleIdx, uid, buyer.publicKey, NATIVE_MINT, buyer);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RaffleNotRefundable"), "Transaction should be reverted with RaffleNotRefundable.");
+    }
+    assert(await provider.connection.getBalance(escrowPda) == escrowAfterCrank, "Escrow should be untouched");
+
+    const raffleAccount = await settle(raffleIdx);
+    assert(raffleAccount.isRevealed, "Winner not revealed");
+  });
+
+  it('Anyone pushes proceeds to creator once revealed', async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+    const escrowPda = findShardEscrowPda(rafflePda, 0);
+
+    await createRaffle(program, raffleIdx, payer, NATIVE_MINT, 10, price, new anchor.BN(now), new anchor.BN(now + 10));
+    await addPrizeSol(program, raffleIdx, payer, new anchor.BN(LAMPORTS_PER_SOL / 10));
+
+    await delay(2);
+    await buyTicket(program, "63efe02f54d5460081a37104", 3, raffleIdx, buyer, permitSigner, NATIVE_MINT);
+
+    await waitForRaffleEnd(program, raffleIdx);
+    await requestRandomness(program, cranker, raffleIdx);
+    await settle(raffleIdx);
+
+    const ticketShard = await program.account.ticketShard.fetch(findShardPda(raffleIdx, 0));
+    const proceeds = await provider.connection.getBalance(escrowPda) - ticketShard.fees.toNumber();
+    const creatorBefore = await provider.connection.getBalance(payer.publicKey);
+    await pushProceeds(program, raffleIdx, cranker);
+
+    assert(await provider.connection.getBalance(payer.publicKey) - creatorBefore == proceeds, "Proceeds not pushed to creator");
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    assert(raffleAccount.isWithdrawn, "Raffle not withdrawn");
+  });
+
+  it('Anyone pushes prize to winner once revealed', async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    const uid = "63efe02f54d5460081a37105";
+    const prize = LAMPORTS_PER_SOL / 10;
+    await createRaffle(program, raffleIdx, payer, NATIVE_MINT, 10, price, new anchor.BN(now), new anchor.BN(now + 10));
+    await addPrizeSol(program, raffleIdx, payer, new anchor.BN(prize));
+
+    await delay(2);
+    await buyTicket(program, uid, 3, raffleIdx, buyer, permitSigner, NATIVE_MINT);
+
+    await waitForRaffleEnd(program, raffleIdx);
+    await requestRandomness(program, cranker, raffleIdx);
+    await settle(raffleIdx);
+
+    // Prize goes to the ticket owner whoever pays the transaction
+    try {
+      await pushPrizeSol(program, raffleIdx, uid, cranker.publicKey, cranker);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("ConstraintHasOne"), "Transaction should be reverted with ConstraintHasOne.");
+    }
+
+    const winnerBefore = await provider.connection.getBalance(buyer.publicKey);
+    await pushPrizeSol(program, raffleIdx, uid, buyer.publicKey, cranker);
+
+    assert(await provider.connection.getBalance(buyer.publicKey) - winnerBefore >= prize, "Prize not pushed to winner");
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    assert(raffleAccount.prizes[0].isClaimed, "Prize not claimed");
+  });
+
+  it('Only authority reveals sold out raffle early, without reward', async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+    const escrowPda = findShardEscrowPda(rafflePda, 0);
+
+    const totalSupply = 4;
+    await createRaffle(program, raffleIdx, payer, NATIVE_MINT, totalSupply, price, new anchor.BN(now), new anchor.BN(now + 100), 1, 0, 1, false, BASIS_POINTS);
+    await addPrizeSol(program, raffleIdx, payer, new anchor.BN(LAMPORTS_PER_SOL / 10));
+
+    await delay(2);
+    await buyTicket(program, "63efe02f54d5460081a37102", totalSupply, raffleIdx, buyer, permitSigner, NATIVE_MINT);
+
+    try {
+      await requestRandomness(program, cranker, raffleIdx);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("EarlyRevealNotAllowed"), "Transaction should be reverted with EarlyRevealNotAllowed.");
+    }
+
+    const escrowBefore = await provider.connection.getBalance(escrowPda);
+    await requestRandomness(program, authority, raffleIdx);
+    assert(await provider.connection.getBalance(escrowPda) == escrowBefore, "Early reveal should not pay crank reward");
+
+    const raffleAccount = await settle(raffleIdx);
+    assert(raffleAccount.isRevealed, "Winner not revealed");
+  });
+
+});
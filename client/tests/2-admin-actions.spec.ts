@@ -2,14 +2,15 @@ import * as anchor from "@coral-xyz/anchor";
 
 import AUTHORITY_WALLET from './keypairs/authority.json';
 import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
 import PAYER_WALLET from './keypairs/payer.json';
 import USER1_WALLET from './keypairs/user1.json';
 import USER2_WALLET from './keypairs/user2.json';
-import { addWhitelist, findGlobalPda, removeWhitelist } from "../utils";
+import { addWhitelist, findEventAuthorityPda, findGlobalPda, findWhitelistPda, migrateWhitelist, removeWhitelist, updateFees } from "../utils";
 import { SolanaRaffle } from "../../target/types/solana_raffle";
 import { assert } from "chai";
-import { Keypair, PublicKey } from "@solana/web3.js";
-import { MAX_COLLECTIONS } from "..";
+import { Keypair } from "@solana/web3.js";
+import { FEE_PERCENTAGE, MAX_COLLECTIONS, MAX_TICKET_PERCENT } from "..";
 
 describe("admin actions", () => {
 
@@ -20,6 +21,7 @@ describe("admin actions", () => {
   const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
 
   const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
   const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
   const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
   const user1 = anchor.web3.Keypair.fromSecretKey(Buffer.from(USER1_WALLET));
@@ -33,9 +35,12 @@ describe("admin actions", () => {
         .accounts({
           authority: user1.publicKey,
           vault: vault.publicKey,
+          permitSigner: permitSigner.publicKey,
           globalAccount: globalPda,
           systemProgram: anchor.web3.SystemProgram.programId,
-          rent: anchor.web3.SYSVAR_RENT_PUBKEY
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          eventAuthority: findEventAuthorityPda(),
+          program: program.programId,
         })
         .signers([user1])
         .rpc();
@@ -53,24 +58,67 @@ describe("admin actions", () => {
         authority: user1.publicKey,
         newAuthority: authority.publicKey,
         vault: vault.publicKey,
+        permitSigner: permitSigner.publicKey,
         globalAccount: globalPda,
         systemProgram: anchor.web3.SystemProgram.programId,
+        eventAuthority: findEventAuthorityPda(),
+        program: program.programId,
       })
       .signers([user1])
       .rpc();
 
     const globalAccount = await program.account.globalAccount.fetch(globalPda);
     assert.equal(globalAccount.authority.toBase58(), authority.publicKey.toBase58(), "Authority not updated");
+    assert.equal(globalAccount.permitSigner.toBase58(), permitSigner.publicKey.toBase58(), "Permit signer not updated");
   });
 
-  it('Add/remove whitelist collection', async () => {
+  it('Update platform fees', async () => {
     const globalPda = findGlobalPda();
 
+    let globalAccount = await program.account.globalAccount.fetch(globalPda);
+    assert(globalAccount.feePercent == FEE_PERCENTAGE, "Default fee not set");
+    assert(globalAccount.maxTicketPercent == MAX_TICKET_PERCENT, "Default wallet cap not set");
+
+    try {
+      await updateFees(program, authority, 1001, MAX_TICKET_PERCENT);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("InvalidFeeConfig"), "Transaction should be reverted with InvalidFeeConfig.");
+    }
+
+    try {
+      await updateFees(program, authority, FEE_PERCENTAGE, 10001);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("InvalidFeeConfig"), "Transaction should be reverted with InvalidFeeConfig.");
+    }
+
+    try {
+      await updateFees(program, authority, FEE_PERCENTAGE, MAX_TICKET_PERCENT, 0, 101);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("InvalidFeeConfig"), "Transaction should be reverted with InvalidFeeConfig.");
+    }
+
+    await updateFees(program, authority, 250, 5000);
+    globalAccount = await program.account.globalAccount.fetch(globalPda);
+    assert(globalAccount.feePercent == 250 && globalAccount.maxTicketPercent == 5000, "Fees not updated");
+
+    await updateFees(program, authority, FEE_PERCENTAGE, MAX_TICKET_PERCENT);
+  });
+
+  it('Add/remove whitelist collection', async () => {
     const collection1 = Keypair.generate();
     await addWhitelist(program, authority, collection1.publicKey);
 
-    let globalAccount = await program.account.globalAccount.fetch(globalPda);
-    assert.equal(globalAccount.wlCollections[0].toBase58(), collection1.publicKey.toBase58(), "WL not added");
+    const whitelistPda = findWhitelistPda(collection1.publicKey);
+    let whitelistEntry = await program.account.whitelistEntry.fetch(whitelistPda);
+    assert.equal(whitelistEntry.key.toBase58(), collection1.publicKey.toBase58(), "WL not added");
+    assert.equal(whitelistEntry.addedBy.toBase58(), authority.publicKey.toBase58(), "WL added by not matched");
+    assert(whitelistEntry.isEnabled == 1, "WL not enabled");
 
     try {
       await addWhitelist(program, authority, collection1.publicKey);
@@ -86,34 +134,36 @@ describe("admin actions", () => {
       assert(false, "Transaction should be reverted");
     }
     catch (ex) {
-      assert(ex.toString().includes("InvalidCollection"), "Transaction should be reverted with InvalidCollection.");
+      assert(ex.toString().includes("AccountNotInitialized"), "Transaction should be reverted with AccountNotInitialized.");
     }
 
     await removeWhitelist(program, authority, collection1.publicKey);
 
-    globalAccount = await program.account.globalAccount.fetch(globalPda);
-    assert.equal(globalAccount.wlCollections[0].toBase58(), PublicKey.default.toBase58(), "WL not removed");
-  });
+    whitelistEntry = await program.account.whitelistEntry.fetch(whitelistPda);
+    assert(whitelistEntry.isEnabled == 0, "WL not removed");
 
-  /*
-  it('Max whitelist collection', async () => {
-    const globalPda = findGlobalPda();
+    // Removed collection can be whitelisted again
+    await addWhitelist(program, authority, collection1.publicKey);
+    whitelistEntry = await program.account.whitelistEntry.fetch(whitelistPda);
+    assert(whitelistEntry.isEnabled == 1, "WL not enabled again");
+  });
 
-    let collection = Keypair.generate();
-    for (let i = 0; i < MAX_COLLECTIONS; i++) {
-      collection = Keypair.generate();
+  it('Whitelist more collections than legacy limit', async () => {
+    for (let i = 0; i <= MAX_COLLECTIONS; i++) {
+      const collection = Keypair.generate();
       await addWhitelist(program, authority, collection.publicKey);
     }
+  });
 
+  it('Migrate only legacy whitelist collections', async () => {
     try {
       const collection = Keypair.generate();
-      await addWhitelist(program, authority, collection.publicKey);
+      await migrateWhitelist(program, authority, collection.publicKey);
       assert(false, "Transaction should be reverted");
     }
     catch (ex) {
-      assert(ex.toString().includes("WhitelistFull"), "Transaction should be reverted with WhitelistFull.");
+      assert(ex.toString().includes("InvalidCollection"), "Transaction should be reverted with InvalidCollection.");
     }
   });
-  */
 
 });
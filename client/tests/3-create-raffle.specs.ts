@@ -156,4 +156,27 @@ describe("create raffle", () => {
     await addPrize(program, 2, user1, nft);
   });
 
+  it('Add prize of legacy collection by verified creator', async () => {
+    const totalSupply = 10;
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 10);
+    const price = new anchor.BN(1_000_000_000);
+    await createRaffle(program, 3, user1, usdcMint.publicKey, totalSupply, price, startDate, endDate);
+
+    // Uncollected nft with user1 as verified first creator
+    const nft = await mintNft(provider.connection, user1, false);
+
+    try {
+      await addPrize(program, 3, user1, nft);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("InvalidCollection"), "Transaction should be reverted with InvalidCollection.");
+    }
+
+    await addWhitelist(program, authority, user1.publicKey, true);
+    await addPrize(program, 3, user1, nft);
+  });
+
 });
@@ -2,14 +2,15 @@ import * as anchor from "@coral-xyz/anchor";
 
 import AUTHORITY_WALLET from './keypairs/authority.json';
 import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
 import USER1_WALLET from './keypairs/user1.json';
 import USER2_WALLET from './keypairs/user2.json';
 import PAYER_WALLET from './keypairs/payer.json';
 import USDC_MINT from './keypairs/usdc-mint.json';
-import { addPrize, addWhitelist, buyTicket, byteArrayToHexString, delay, findEscrowPda, findGlobalPda, findRafflePda, findUserPda, mintNft, safeAirdrop } from "../utils";
+import { addPrize, addWhitelist, buyTicket, createRaffle, createShards, delay, fetchTickets, findEventAuthorityPda, findGlobalPda, findRafflePda, findShardEscrowPda, findShardPda, findTicketUid, findUserPda, mintNft, newBuyPermit, safeAirdrop, shardEscrowAccounts, sweepFees, updateFees, withdrawRaffle } from "../utils";
 import { SolanaRaffle } from "../../target/types/solana_raffle";
 import { assert } from "chai";
-import { BASIS_POINTS, FEE_PERCENTAGE } from "..";
+import { BASIS_POINTS, FEE_PERCENTAGE, MAX_TICKET_PERCENT } from "..";
 import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { NATIVE_MINT, mintToChecked, getOrCreateAssociatedTokenAccount, getAssociatedTokenAddressSync, getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 
@@ -23,6 +24,7 @@ describe("buy ticket", () => {
   const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
 
   const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
   const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
   const usdcMint = anchor.web3.Keypair.fromSecretKey(Buffer.from(USDC_MINT)).publicKey;
   const user1 = anchor.web3.Keypair.fromSecretKey(Buffer.from(USER1_WALLET));
@@ -56,23 +58,26 @@ describe("buy ticket", () => {
 
     // Register raffle with user1
     const splMint = NATIVE_MINT;
-    await program.methods.createRaffle(raffleIdx, totalSupply, price, startDate, endDate)
+    await program.methods.createRaffle(raffleIdx, totalSupply, 1, price, startDate, endDate, 0, 1, false, null)
       .accounts({
         authority: user1.publicKey,
         globalAccount: globalPda,
         raffleAccount: rafflePda,
         splMint: splMint,
         systemProgram: anchor.web3.SystemProgram.programId,
-        rent: anchor.web3.SYSVAR_RENT_PUBKEY
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        eventAuthority: findEventAuthorityPda(),
+        program: program.programId,
       })
       .signers([user1])
       .rpc();
+    await createShards(program, raffleIdx, user1);
 
     // Buy raffle with user2
     const userPda = findUserPda(user2_uid, rafflePda);
 
     try {
-      await buyTicket(program, user2_uid, 1, raffleIdx, user2, authority, vault.publicKey, splMint);
+      await buyTicket(program, user2_uid, 1, raffleIdx, user2, permitSigner, splMint);
       assert(false, "Transaction should be reverted");
     }
     catch (ex) {
@@ -83,7 +88,7 @@ describe("buy ticket", () => {
 
     // Try to buy ticket before add prize
     try {
-      await buyTicket(program, user2_uid, 1, raffleIdx, user2, authority, vault.publicKey, splMint);
+      await buyTicket(program, user2_uid, 1, raffleIdx, user2, permitSigner, splMint);
       assert(false, "Transaction should be reverted");
     }
     catch (ex) {
@@ -95,7 +100,7 @@ describe("buy ticket", () => {
 
     // Try to buy ticket more than max supply
     try {
-      await buyTicket(program, user2_uid, 100, raffleIdx, user2, authority, vault.publicKey, splMint);
+      await buyTicket(program, user2_uid, 100, raffleIdx, user2, permitSigner, splMint);
       assert(false, "Transaction should be reverted");
     }
     catch (ex) {
@@ -103,41 +108,44 @@ describe("buy ticket", () => {
     }
 
     for (let i = 0; i < 2; i++) {
-      await buyTicket(program, user2_uid, 1, raffleIdx, user2, authority, vault.publicKey, splMint);
+      await buyTicket(program, user2_uid, 1, raffleIdx, user2, permitSigner, splMint);
     }
 
     try {
-      await buyTicket(program, user2_uid, 1, raffleIdx, user1, authority, vault.publicKey, splMint);
+      await buyTicket(program, user2_uid, 1, raffleIdx, user1, permitSigner, splMint);
       assert(false, "Transaction should be reverted");
     }
     catch (ex) {
       assert(ex.toString().includes("InvalidWallet"), "Transaction should be reverted with InvalidWallet.");
     }
 
-    await buyTicket(program, user2_uid, 1, raffleIdx, user2, authority, vault.publicKey, splMint);
+    await buyTicket(program, user2_uid, 1, raffleIdx, user2, permitSigner, splMint);
 
     // Check user account
     const userAccount = await program.account.userAccount.fetch(userPda);
     assert(userAccount.amount == 3, "User amount not matched");
 
-    // Check raffle account
-    const raffleAccount = await program.account.raffleAccount.fetch(rafflePda);
-    assert(raffleAccount.totalSales == 3, "Raffle sales not matched");
+    // Check ticket shard, raffle sales are only synced on withdraw
+    const ticketShard = await program.account.ticketShard.fetch(findShardPda(raffleIdx, 0));
+    assert(ticketShard.totalSales == 3, "Raffle sales not matched");
 
-    // Check fee wallet
-    const feeBalance = await provider.connection.getBalance(vault.publicKey);
+    // Fee is held by the shard escrow until swept
     const totalAmount = price.muln(3);
     const expectedFeeAmount = totalAmount.muln(FEE_PERCENTAGE).divn(BASIS_POINTS);
-    assert(feeBalance > expectedFeeAmount.toNumber(), "Fee balance not matched");
+    assert(ticketShard.fees.eq(expectedFeeAmount), "Held fee not matched");
+
+    // Check fee wallet
+    const feeBalanceBefore = await provider.connection.getBalance(vault.publicKey);
+    await sweepFees(program, payer, raffleIdx);
+    const feeBalance = await provider.connection.getBalance(vault.publicKey);
+    assert(feeBalance - feeBalanceBefore == expectedFeeAmount.toNumber(), "Fee balance not matched");
 
     // Check escrow wallet
-    const escrowPda = findEscrowPda(rafflePda);
-    const escrowBalance = await provider.connection.getBalance(escrowPda);
+    const escrowAccount = findShardEscrowPda(rafflePda, 0);
+    const escrowBalance = await provider.connection.getBalance(escrowAccount);
     const expectedEscrowBalance = totalAmount.sub(expectedFeeAmount).toNumber();
     assert(escrowBalance == expectedEscrowBalance, "Escrow balance not matched");
 
-    const escrowAccount = findEscrowPda(rafflePda);
-
     // Try to withdraw funds before raffle ends
     try {
       await program.methods.withdrawRaffle(raffleIdx)
@@ -146,11 +154,13 @@ describe("buy ticket", () => {
           raffleAccount: rafflePda,
           splMint,
           treasuryTokenAccount: user1.publicKey,
-          escrowAccount: escrowAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: anchor.web3.SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          eventAuthority: findEventAuthorityPda(),
+          program: program.programId,
         })
+        .remainingAccounts(shardEscrowAccounts(raffleIdx))
         .signers([user1])
         .rpc();
       assert(false, "Transaction should be reverted");
@@ -166,7 +176,7 @@ describe("buy ticket", () => {
     await safeAirdrop(provider.connection, user.publicKey, 1);
 
     try {
-      await buyTicket(program, user1_uid, 1, raffleIdx, user, authority, vault.publicKey, splMint);
+      await buyTicket(program, user1_uid, 1, raffleIdx, user, permitSigner, splMint);
       assert(false, "Transaction should be reverted");
     }
     catch (ex) {
@@ -181,10 +191,13 @@ describe("buy ticket", () => {
         raffleAccount: rafflePda,
         splMint,
         treasuryTokenAccount: user1.publicKey,
-        escrowAccount: escrowAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: anchor.web3.SystemProgram.programId,
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        eventAuthority: findEventAuthorityPda(),
+        program: program.programId,
       })
+      .remainingAccounts(shardEscrowAccounts(raffleIdx))
       .signers([user1])
       .rpc();
 
@@ -213,17 +226,20 @@ describe("buy ticket", () => {
 
     // Register raffle with user1
     const splMint = usdcMint;
-    await program.methods.createRaffle(raffleIdx, totalSupply, price, startDate, endDate)
+    await program.methods.createRaffle(raffleIdx, totalSupply, 1, price, startDate, endDate, 0, 1, false, null)
       .accounts({
         authority: user1.publicKey,
         globalAccount: globalPda,
         raffleAccount: rafflePda,
         splMint: splMint,
         systemProgram: anchor.web3.SystemProgram.programId,
-        rent: anchor.web3.SYSVAR_RENT_PUBKEY
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        eventAuthority: findEventAuthorityPda(),
+        program: program.programId,
       })
       .signers([user1])
       .rpc();
+    await createShards(program, raffleIdx, user1);
 
     const nft = await mintNft(provider.connection, user1, false, collection, authority);
     await addPrize(program, raffleIdx, user1, nft);
@@ -238,24 +254,25 @@ describe("buy ticket", () => {
     await mintToChecked(provider.connection, authority, splMint, userAta, authority, LAMPORTS_PER_SOL, 9);
 
     for (let i = 0; i < 2; i++) {
-      await buyTicket(program, user2_uid, 1, raffleIdx, user2, authority, vault.publicKey, splMint);
+      await buyTicket(program, user2_uid, 1, raffleIdx, user2, permitSigner, splMint);
     }
 
-    await buyTicket(program, user2_uid, 1, raffleIdx, user2, authority, vault.publicKey, splMint);
+    await buyTicket(program, user2_uid, 1, raffleIdx, user2, permitSigner, splMint);
 
     // Check user account
     const userAccount = await program.account.userAccount.fetch(userPda);
     assert(userAccount.amount == 3, "User amount not matched");
 
-    // Check raffle account
-    const raffleAccount = await program.account.raffleAccount.fetch(rafflePda);
-    assert(raffleAccount.totalSales == 3, "Raffle sales not matched");
+    // Check ticket shard, raffle sales are only synced on withdraw
+    const ticketShard = await program.account.ticketShard.fetch(findShardPda(raffleIdx, 0));
+    assert(ticketShard.totalSales == 3, "Raffle sales not matched");
 
     // Check user USDC balance
     const userInfo = await getAccount(provider.connection, getAssociatedTokenAddressSync(splMint, user2.publicKey));
 
     // Check escrow USDC balance
-    const escrowPda = findEscrowPda(rafflePda);
+    const escrowPda = findShardEscrowPda(rafflePda, 0);
+    await sweepFees(program, payer, raffleIdx);
     let escrowInfo = await getAccount(provider.connection, escrowPda);
 
     // Check vault USDC balance
@@ -273,13 +290,15 @@ describe("buy ticket", () => {
       .accounts({
         authority: user1.publicKey,
         raffleAccount: rafflePda,
-        escrowAccount: escrowPda,
         splMint,
         treasuryTokenAccount: treasuryAta,
         systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        eventAuthority: findEventAuthorityPda(),
+        program: program.programId,
       })
+      .remainingAccounts(shardEscrowAccounts(raffleIdx))
       .signers([user1])
       .rpc();
 
@@ -307,43 +326,290 @@ describe("buy ticket", () => {
     const raffleIdx = globalAccount.totalRaffles;
 
     const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
-    const escrowPda = findEscrowPda(rafflePda);
 
     // Register raffle with user1
     const splMint = NATIVE_MINT;
-    await program.methods.createRaffle(raffleIdx, totalSupply, price, startDate, endDate)
+    await program.methods.createRaffle(raffleIdx, totalSupply, 1, price, startDate, endDate, 0, 1, false, null)
       .accounts({
         authority: user1.publicKey,
         globalAccount: globalPda,
         raffleAccount: rafflePda,
         splMint,
         systemProgram: anchor.web3.SystemProgram.programId,
-        rent: anchor.web3.SYSVAR_RENT_PUBKEY
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        eventAuthority: findEventAuthorityPda(),
+        program: program.programId,
       })
       .signers([user1])
       .rpc();
+    await createShards(program, raffleIdx, user1);
 
     const nft = await mintNft(provider.connection, user1, false, collection, authority);
     await addPrize(program, raffleIdx, user1, nft);
 
     await delay(3)
-    await buyTicket(program, user1_uid, 4, raffleIdx, user1, authority, vault.publicKey, splMint);
-    await buyTicket(program, user2_uid, 4, raffleIdx, user2, authority, vault.publicKey, splMint);
+    await buyTicket(program, user1_uid, 4, raffleIdx, user1, permitSigner, splMint);
+    await buyTicket(program, user2_uid, 4, raffleIdx, user2, permitSigner, splMint);
 
     try {
-      await buyTicket(program, user3_uid, 3, raffleIdx, payer, authority, vault.publicKey, splMint);
+      await buyTicket(program, user3_uid, 3, raffleIdx, payer, permitSigner, splMint);
       assert(false, "Transaction should be reverted");
     }
     catch (ex) {
       assert(ex.toString().includes("SupplyLimitExceed"), "Transaction should be reverted with SupplyLimitExceed.");
     }
 
-    await buyTicket(program, user3_uid, 2, raffleIdx, payer, authority, vault.publicKey, splMint);
+    await buyTicket(program, user3_uid, 2, raffleIdx, payer, permitSigner, splMint);
 
     // Check user id stored in pda
-    const raffleAccount = await program.account.raffleAccount.fetch(rafflePda);
-    let ticketUid = byteArrayToHexString(raffleAccount.tickets[9].uid);
+    let ticketUid = await findTicketUid(program, raffleIdx, 9);
     assert(user3_uid == ticketUid, "Ticket uid not matched")
+
+    // Purchase is logged as a single ticket range
+    const tickets = await fetchTickets(program, raffleIdx, 0);
+    const lastTicket = tickets[tickets.length - 1];
+    assert(lastTicket.startIdx == 8 && lastTicket.amount == 2, "Ticket range not matched")
+  });
+
+  it('Snapshot fee and wallet cap at raffle creation', async () => {
+
+    const totalSupply = 10;
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 30);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    // Wallet cap raised to 50% for this raffle only
+    const splMint = NATIVE_MINT;
+    await createRaffle(program, raffleIdx, user1, splMint, totalSupply, price, startDate, endDate, 1, 0, 1, false, 5000);
+
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    assert(raffleAccount.feePercent == FEE_PERCENTAGE, "Fee not snapshot");
+    assert(raffleAccount.maxTicketPercent == 5000, "Wallet cap not overridden");
+
+    const nft = await mintNft(provider.connection, user1, false, collection, authority);
+    await addPrize(program, raffleIdx, user1, nft);
+
+    // Fee change applies to new raffles only
+    await updateFees(program, authority, FEE_PERCENTAGE * 3, MAX_TICKET_PERCENT);
+
+    try {
+      await delay(2);
+      const vaultBalanceBefore = await provider.connection.getBalance(vault.publicKey);
+      await buyTicket(program, user2_uid, 5, raffleIdx, user2, permitSigner, splMint);
+      await sweepFees(program, payer, raffleIdx);
+      const vaultBalanceAfter = await provider.connection.getBalance(vault.publicKey);
+
+      const expectedFeeAmount = price.muln(5).muln(FEE_PERCENTAGE).divn(BASIS_POINTS);
+      assert(vaultBalanceAfter - vaultBalanceBefore == expectedFeeAmount.toNumber(), "Fee amount not matched");
+    }
+    finally {
+      await updateFees(program, authority, FEE_PERCENTAGE, MAX_TICKET_PERCENT);
+    }
+  });
+
+  it('Skip wallet cap when set to zero', async () => {
+
+    const totalSupply = 10;
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 30);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+
+    // Zero cap, as read on deployments upgraded before the wallet cap existed
+    const splMint = NATIVE_MINT;
+    await createRaffle(program, raffleIdx, user1, splMint, totalSupply, price, startDate, endDate, 1, 0, 1, false, 0);
+
+    const nft = await mintNft(provider.connection, user1, false, collection, authority);
+    await addPrize(program, raffleIdx, user1, nft);
+
+    await delay(2);
+    await buyTicket(program, user2_uid, totalSupply, raffleIdx, user2, permitSigner, splMint);
+
+    const userAccount = await program.account.userAccount.fetch(findUserPda(user2_uid, findRafflePda(new anchor.BN(raffleIdx))));
+    assert(userAccount.amount == totalSupply, "Wallet cap not skipped");
+  });
+
+  it('Validate purchase permit', async () => {
+
+    const totalSupply = 10;
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 30);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+
+    const splMint = NATIVE_MINT;
+    await createRaffle(program, raffleIdx, user1, splMint, totalSupply, price, startDate, endDate);
+
+    const nft = await mintNft(provider.connection, user1, false, collection, authority);
+    await addPrize(program, raffleIdx, user1, nft);
+
+    await delay(2);
+
+    // Admin authority no longer signs purchases
+    try {
+      await buyTicket(program, user2_uid, 1, raffleIdx, user2, authority, splMint);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("InvalidPermit"), "Transaction should be reverted with InvalidPermit.");
+    }
+
+    try {
+      const permit = { ...newBuyPermit(1), expiry: new anchor.BN(now - 1) };
+      await buyTicket(program, user2_uid, 1, raffleIdx, user2, permitSigner, splMint, 0, permit);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("PermitExpired"), "Transaction should be reverted with PermitExpired.");
+    }
+
+    try {
+      await buyTicket(program, user2_uid, 2, raffleIdx, user2, permitSigner, splMint, 0, newBuyPermit(1));
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("InvalidAmount"), "Transaction should be reverted with InvalidAmount.");
+    }
+
+    const permit = newBuyPermit(1);
+    await buyTicket(program, user2_uid, 1, raffleIdx, user2, permitSigner, splMint, 0, permit);
+
+    const userAccount = await program.account.userAccount.fetch(findUserPda(user2_uid, findRafflePda(new anchor.BN(raffleIdx))));
+    assert(userAccount.permitNonce.eq(permit.nonce), "Permit nonce not stored");
+
+    try {
+      await buyTicket(program, user2_uid, 1, raffleIdx, user2, permitSigner, splMint, 0, permit);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("PermitAlreadyUsed"), "Transaction should be reverted with PermitAlreadyUsed.");
+    }
+  });
+
+  it('Spread purchases across ticket shards', async () => {
+
+    const totalSupply = 10;
+    const totalShards = 3;
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 10);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    // Shard capacities are 4, 3, 3
+    const splMint = NATIVE_MINT;
+    await createRaffle(program, raffleIdx, user1, splMint, totalSupply, price, startDate, endDate, 1, 0, totalShards);
+
+    const nft = await mintNft(provider.connection, user1, false, collection, authority);
+    await addPrize(program, raffleIdx, user1, nft);
+
+    await delay(3)
+    await buyTicket(program, user1_uid, 4, raffleIdx, user1, permitSigner, splMint, 0);
+    await buyTicket(program, user2_uid, 3, raffleIdx, user2, permitSigner, splMint, 1);
+
+    try {
+      await buyTicket(program, user3_uid, 4, raffleIdx, payer, permitSigner, splMint, 2);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("SupplyLimitExceed"), "Transaction should be reverted with SupplyLimitExceed.");
+    }
+
+    await buyTicket(program, user3_uid, 2, raffleIdx, payer, permitSigner, splMint, 2);
+
+    // Shard sales are only reflected in the raffle after a sync
+    await delay(8);
+    await withdrawRaffle(program, raffleIdx, user1);
+
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    assert(raffleAccount.totalSales == 9, "Total sales not matched");
+    assert(raffleAccount.shardSales.slice(0, totalShards).join() == "4,3,2", "Shard sales not matched");
+
+    // Global ticket indexes follow shard order
+    assert(await findTicketUid(program, raffleIdx, 3) == user1_uid, "Ticket uid not matched");
+    assert(await findTicketUid(program, raffleIdx, 4) == user2_uid, "Ticket uid not matched");
+    assert(await findTicketUid(program, raffleIdx, 8) == user3_uid, "Ticket uid not matched");
+  });
+
+  it('Block purchases until every ticket shard exists', async () => {
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 10);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    // Register raffle with two shards, create only the first one
+    const splMint = NATIVE_MINT;
+    await program.methods.createRaffle(raffleIdx, 10, 1, price, startDate, endDate, 0, 2, false, null)
+      .accounts({
+        authority: user1.publicKey,
+        globalAccount: globalPda,
+        raffleAccount: rafflePda,
+        splMint: splMint,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        eventAuthority: findEventAuthorityPda(),
+        program: program.programId,
+      })
+      .signers([user1])
+      .rpc();
+    await createShards(program, raffleIdx, user1, 1);
+
+    const nft = await mintNft(provider.connection, user1, false, collection, authority);
+    await addPrize(program, raffleIdx, user1, nft);
+
+    await delay(2)
+    try {
+      await buyTicket(program, user2_uid, 1, raffleIdx, user2, permitSigner, splMint, 0);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("ShardsNotCreated"), "Transaction should be reverted with ShardsNotCreated.");
+    }
+
+    await program.methods.createShard(raffleIdx, 1)
+      .accounts({
+        authority: user1.publicKey,
+        raffleAccount: rafflePda,
+        ticketShard: findShardPda(raffleIdx, 1),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        eventAuthority: findEventAuthorityPda(),
+        program: program.programId,
+      })
+      .signers([user1])
+      .rpc();
+    await buyTicket(program, user2_uid, 1, raffleIdx, user2, permitSigner, splMint, 0);
+
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    assert(raffleAccount.createdShards == 2, "Created shards not counted");
   });
 
 });
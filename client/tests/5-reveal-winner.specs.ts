@@ -2,14 +2,16 @@ import * as anchor from "@coral-xyz/anchor";
 
 import AUTHORITY_WALLET from './keypairs/authority.json';
 import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
 import PAYER_WALLET from './keypairs/payer.json';
 import USDC_MINT from './keypairs/usdc-mint.json';
-import { addPrize, addWhitelist, buyTicket, byteArrayToHexString, claimPrize, createRaffle, delay, findEscrowPda, findGlobalPda, findRafflePda, findUserPda, hexStringToNumArray, mintNft, revealWinner, safeAirdrop, transferNft } from "../utils";
+import { addPrize, addWhitelist, buyTicket, claimPrize, createRaffle, delay, findEscrowPda, findGlobalPda, findRafflePda, findTicketUid, findUserPda, hexStringToNumArray, mintNft, revealWinner, safeAirdrop, transferNft } from "../utils";
 import { SolanaRaffle } from "../../target/types/solana_raffle";
 import { NATIVE_MINT, getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { assert } from "chai";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { TokenStandard } from "@metaplex-foundation/mpl-token-metadata";
+import { keccak_256 } from "@noble/hashes/sha3";
 
 
 describe("reveal winner", () => {
@@ -21,6 +23,7 @@ describe("reveal winner", () => {
   const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
 
   const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
   const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
   const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
   const usdcMint = anchor.web3.Keypair.fromSecretKey(Buffer.from(USDC_MINT)).publicKey;
@@ -87,33 +90,122 @@ describe("reveal winner", () => {
 
     await delay(3)
     for (let i = 0; i < 3; i++) {
-      await buyTicket(program, userIds[i], 3, raffleIdx, userSigners[i], authority, vault.publicKey, splMint);
+      await buyTicket(program, userIds[i], 3, raffleIdx, userSigners[i], permitSigner, splMint);
     }
 
     // Reveal winner
     await revealWinner(program, authority, raffleIdx);
 
     // Check winner
-    const raffleAccount = await program.account.raffleAccount.fetch(rafflePda);
-    assert(raffleAccount.winnerIdx > 0, "Winner not revealed");
-    console.log('Winner idx:', raffleAccount.winnerIdx);
-
-    const winnerUid = byteArrayToHexString(raffleAccount.tickets[raffleAccount.winnerIdx - 1].uid);
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    const winnerIdx = raffleAccount.prizes[0].winnerIdx;
+    assert(winnerIdx > 0, "Winner not revealed");
+    console.log('Winner idx:', winnerIdx);
+
+    // Draw proof is stored with the raffle
+    const { randomness } = raffleAccount;
+    const drawSeed = keccak_256(Buffer.concat([Buffer.from(randomness.value), rafflePda.toBuffer()]));
+    assert(Buffer.from(randomness.seed).equals(Buffer.from(drawSeed)), "Seed mismatch");
+    assert(randomness.timestamp.toNumber() > 0, "Timestamp not stored");
+
+    const winnerUid = await findTicketUid(program, raffleIdx, winnerIdx - 1);
     const winner = getUserSigner(winnerUid);
 
     // Claim prize
     await claimPrize(program, raffleIdx, winnerUid, winner);
 
     // Check creator ata
-    const creatorAta = getAssociatedTokenAddressSync(raffleAccount.mint, raffleAccount.authority);
+    const creatorAta = getAssociatedTokenAddressSync(raffleAccount.prizes[0].mint, raffleAccount.authority);
     const creatorAtaAcc = await getAccount(provider.connection, creatorAta);
     assert(creatorAtaAcc.amount == 0n, "Nft not transferred");
 
     // Check winner ata
-    const winnerAta = getAssociatedTokenAddressSync(raffleAccount.mint, winner.publicKey);
+    const winnerAta = getAssociatedTokenAddressSync(raffleAccount.prizes[0].mint, winner.publicKey);
     const winnerAtaAcc = await getAccount(provider.connection, winnerAta);
     assert(winnerAtaAcc.amount == 1n, "Nft not claimed");
 
   });
 
+  it('Success reveal multiple winners / claim each prize slot', async () => {
+
+    const totalSupply = 10;
+    const totalPrizes = 3;
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 10);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    // Register raffle with several prize slots
+    const splMint = NATIVE_MINT;
+    await createRaffle(program, raffleIdx, payer, splMint, totalSupply, price, startDate, endDate, totalPrizes);
+
+    // Fill every prize slot
+    for (let slot = 0; slot < totalPrizes; slot++) {
+      const nft = await mintNft(provider.connection, payer, false, collection, authority);
+
+      // Try to buy ticket before all slots are filled
+      if (slot == 1) {
+        try {
+          await buyTicket(program, userIds[0], 1, raffleIdx, userSigners[0], permitSigner, splMint);
+          assert(false, "Transaction should be reverted");
+        }
+        catch (ex) {
+          assert(ex.toString().includes("PrizeNotDeposited"), "Transaction should be reverted with PrizeNotDeposited.");
+        }
+      }
+
+      await addPrize(program, raffleIdx, payer, nft, slot);
+    }
+
+    await delay(3)
+    for (let i = 0; i < 3; i++) {
+      await buyTicket(program, userIds[i], 3, raffleIdx, userSigners[i], permitSigner, splMint);
+    }
+
+    // Reveal winners
+    await revealWinner(program, authority, raffleIdx);
+
+    // Winners should be distinct tickets
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    const winnerIdxs = raffleAccount.prizes.slice(0, raffleAccount.totalPrizes).map(prize => prize.winnerIdx);
+    assert(winnerIdxs.every(winnerIdx => winnerIdx > 0), "Winner not revealed");
+    assert(new Set(winnerIdxs).size == totalPrizes, "Winner tickets not distinct");
+
+    // Try to reveal again
+    try {
+      await revealWinner(program, authority, raffleIdx);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("WinnerAlreadyRevealed"), "Transaction should be reverted with WinnerAlreadyRevealed.");
+    }
+
+    for (let slot = 0; slot < totalPrizes; slot++) {
+      const winnerUid = await findTicketUid(program, raffleIdx, winnerIdxs[slot] - 1);
+      const winner = getUserSigner(winnerUid);
+
+      await claimPrize(program, raffleIdx, winnerUid, winner, slot);
+
+      const winnerAta = getAssociatedTokenAddressSync(raffleAccount.prizes[slot].mint, winner.publicKey);
+      const winnerAtaAcc = await getAccount(provider.connection, winnerAta);
+      assert(winnerAtaAcc.amount == 1n, "Nft not claimed");
+
+      // Try to claim same slot again
+      try {
+        await claimPrize(program, raffleIdx, winnerUid, winner, slot);
+        assert(false, "Transaction should be reverted");
+      }
+      catch (ex) {
+        assert(ex.toString().includes("PrizeAlreadyClaimed"), "Transaction should be reverted with PrizeAlreadyClaimed.");
+      }
+    }
+  });
+
 });
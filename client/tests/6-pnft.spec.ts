@@ -2,9 +2,10 @@ import * as anchor from "@coral-xyz/anchor";
 
 import AUTHORITY_WALLET from './keypairs/authority.json';
 import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
 import PAYER_WALLET from './keypairs/payer.json';
 import USDC_MINT from './keypairs/usdc-mint.json';
-import { addPrizePnft, addWhitelist, buyTicket, byteArrayToHexString, claimPrize, claimPrizePnft, createRaffle, delay, findEscrowPda, findGlobalPda, findRafflePda, findUserPda, hexStringToNumArray, mintNft, revealWinner, safeAirdrop, transferNft } from "../utils";
+import { addPrizePnft, addWhitelist, buyTicket, claimPrize, claimPrizePnft, createRaffle, delay, findEscrowPda, findGlobalPda, findRafflePda, findTicketUid, findUserPda, hexStringToNumArray, mintNft, revealWinner, safeAirdrop, transferNft } from "../utils";
 import { SolanaRaffle } from "../../target/types/solana_raffle";
 import { NATIVE_MINT, getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { assert } from "chai";
@@ -22,6 +23,7 @@ describe("support pnft", () => {
   const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
 
   const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
   const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
   const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
   const usdcMint = anchor.web3.Keypair.fromSecretKey(Buffer.from(USDC_MINT)).publicKey;
@@ -88,18 +90,19 @@ describe("support pnft", () => {
 
     await delay(3)
     for (let i = 0; i < 3; i++) {
-      await buyTicket(program, userIds[i], 3, raffleIdx, userSigners[i], authority, vault.publicKey, splMint);
+      await buyTicket(program, userIds[i], 3, raffleIdx, userSigners[i], permitSigner, splMint);
     }
 
     // Reveal winner
     await revealWinner(program, authority, raffleIdx);
 
     // Check winner
-    const raffleAccount = await program.account.raffleAccount.fetch(rafflePda);
-    assert(raffleAccount.winnerIdx > 0, "Winner not revealed");
-    console.log('Winner idx:', raffleAccount.winnerIdx);
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    const winnerIdx = raffleAccount.prizes[0].winnerIdx;
+    assert(winnerIdx > 0, "Winner not revealed");
+    console.log('Winner idx:', winnerIdx);
 
-    const winnerUid = byteArrayToHexString(raffleAccount.tickets[raffleAccount.winnerIdx - 1].uid);
+    const winnerUid = await findTicketUid(program, raffleIdx, winnerIdx - 1);
     const winner = getUserSigner(winnerUid);
 
     // Claim prize
@@ -111,12 +114,12 @@ describe("support pnft", () => {
     }
 
     // Check creator ata
-    const creatorAta = getAssociatedTokenAddressSync(raffleAccount.mint, raffleAccount.authority);
+    const creatorAta = getAssociatedTokenAddressSync(raffleAccount.prizes[0].mint, raffleAccount.authority);
     const creatorAtaAcc = await getAccount(provider.connection, creatorAta);
     assert(creatorAtaAcc.amount == 0n, "Nft not transferred");
 
     // Check winner ata
-    const winnerAta = getAssociatedTokenAddressSync(raffleAccount.mint, winner.publicKey);
+    const winnerAta = getAssociatedTokenAddressSync(raffleAccount.prizes[0].mint, winner.publicKey);
     const winnerAtaAcc = await getAccount(provider.connection, winnerAta);
     assert(winnerAtaAcc.amount == 1n, "Nft not claimed");
 
@@ -0,0 +1,116 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import AUTHORITY_WALLET from './keypairs/authority.json';
+import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
+import PAYER_WALLET from './keypairs/payer.json';
+import USDC_MINT from './keypairs/usdc-mint.json';
+import { addPrizeToken, buyTicket, claimPrize, claimPrizeToken, createRaffle, delay, findGlobalPda, findPrizeEscrowPda, findRafflePda, findTicketUid, PrizeKind, revealWinner, safeAirdrop } from "../utils";
+import { SolanaRaffle } from "../../target/types/solana_raffle";
+import { NATIVE_MINT, getAccount, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount, mintToChecked } from "@solana/spl-token";
+import { assert } from "chai";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+
+
+describe("token prize", () => {
+
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
+
+  const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
+  const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
+  const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
+  const usdcMint = anchor.web3.Keypair.fromSecretKey(Buffer.from(USDC_MINT)).publicKey;
+
+  const totalUsers = 3;
+  let userIds: string[] = [];
+  let userSigners: Keypair[] = [];
+
+  const getUserSigner = (uid: string): Keypair => {
+    for (let i = 0; i < totalUsers; i++) {
+      if (userIds[i] == uid) {
+        return userSigners[i];
+      }
+    }
+  }
+
+  before(async () => {
+    for (let i = 1; i <= totalUsers; i++) {
+      userIds.push(`63efe02f54d5460081a367${i.toString().padEnd(2, '0')}`);
+
+      const signer = Keypair.generate();
+      await safeAirdrop(provider.connection, signer.publicKey, 1);
+      userSigners.push(signer);
+    }
+
+    // Mint some USDC for creator
+    const payerAta = (await getOrCreateAssociatedTokenAccount(provider.connection, authority, usdcMint, payer.publicKey)).address;
+    await mintToChecked(provider.connection, authority, usdcMint, payerAta, authority, LAMPORTS_PER_SOL, 9);
+  })
+
+  it('Success deposit / claim token prize pool', async () => {
+
+    const totalSupply = 10;
+    const prizeAmount = new anchor.BN(500_000_000);
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 10);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    const splMint = NATIVE_MINT;
+    await createRaffle(program, raffleIdx, payer, splMint, totalSupply, price, startDate, endDate);
+    await addPrizeToken(program, raffleIdx, payer, usdcMint, prizeAmount);
+
+    // Check prize escrow holds the pool
+    const prizeEscrow = findPrizeEscrowPda(rafflePda, 0);
+    const prizeEscrowAcc = await getAccount(provider.connection, prizeEscrow);
+    assert(prizeEscrowAcc.amount == BigInt(prizeAmount.toString()), "Prize not deposited");
+
+    let raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    assert(raffleAccount.prizes[0].kind == PrizeKind.Token, "Prize kind not matched");
+    assert(raffleAccount.prizes[0].amount.eq(prizeAmount), "Prize amount not matched");
+
+    await delay(3)
+    for (let i = 0; i < totalUsers; i++) {
+      await buyTicket(program, userIds[i], 2, raffleIdx, userSigners[i], permitSigner, splMint);
+    }
+
+    await revealWinner(program, authority, raffleIdx);
+
+    raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    const winnerIdx = raffleAccount.prizes[0].winnerIdx;
+    const winnerUid = await findTicketUid(program, raffleIdx, winnerIdx - 1);
+    const winner = getUserSigner(winnerUid);
+
+    // Try to claim with nft claim path
+    try {
+      await claimPrize(program, raffleIdx, winnerUid, winner);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      // console.log(ex)
+    }
+
+    await claimPrizeToken(program, raffleIdx, winnerUid, winner);
+
+    // Check winner ata
+    const winnerAta = getAssociatedTokenAddressSync(usdcMint, winner.publicKey);
+    const winnerAtaAcc = await getAccount(provider.connection, winnerAta);
+    assert(winnerAtaAcc.amount == BigInt(prizeAmount.toString()), "Prize not claimed");
+
+    // Prize escrow should be closed
+    const prizeEscrowInfo = await provider.connection.getAccountInfo(prizeEscrow);
+    assert(prizeEscrowInfo == null, "Prize escrow not closed");
+  });
+
+});
@@ -0,0 +1,136 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import AUTHORITY_WALLET from './keypairs/authority.json';
+import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
+import PAYER_WALLET from './keypairs/payer.json';
+import { addPrizeSol, buyTicket, claimPrizeSol, createRaffle, delay, findGlobalPda, findPrizeEscrowPda, findRafflePda, findTicketUid, reclaimPrizeSol, revealWinner, safeAirdrop } from "../utils";
+import { SolanaRaffle } from "../../target/types/solana_raffle";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { assert } from "chai";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+
+
+describe("sol prize", () => {
+
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
+
+  const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
+  const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
+  const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
+
+  const totalUsers = 3;
+  let userIds: string[] = [];
+  let userSigners: Keypair[] = [];
+
+  const getUserSigner = (uid: string): Keypair => {
+    for (let i = 0; i < totalUsers; i++) {
+      if (userIds[i] == uid) {
+        return userSigners[i];
+      }
+    }
+  }
+
+  before(async () => {
+    await safeAirdrop(provider.connection, payer.publicKey, 2);
+
+    for (let i = 1; i <= totalUsers; i++) {
+      userIds.push(`63efe02f54d5460081a368${i.toString().padEnd(2, '0')}`);
+
+      const signer = Keypair.generate();
+      await safeAirdrop(provider.connection, signer.publicKey, 1);
+      userSigners.push(signer);
+    }
+  })
+
+  it('Success deposit / claim sol prize', async () => {
+
+    const totalSupply = 10;
+    const prizeAmount = new anchor.BN(LAMPORTS_PER_SOL / 2);
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 10);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    const splMint = NATIVE_MINT;
+    await createRaffle(program, raffleIdx, payer, splMint, totalSupply, price, startDate, endDate);
+    await addPrizeSol(program, raffleIdx, payer, prizeAmount);
+
+    const prizeVault = findPrizeEscrowPda(rafflePda, 0);
+    assert(await provider.connection.getBalance(prizeVault) == prizeAmount.toNumber(), "Prize not deposited");
+
+    await delay(3)
+    for (let i = 0; i < totalUsers; i++) {
+      await buyTicket(program, userIds[i], 2, raffleIdx, userSigners[i], permitSigner, splMint);
+    }
+
+    await revealWinner(program, authority, raffleIdx);
+
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    const winnerIdx = raffleAccount.prizes[0].winnerIdx;
+    const winnerUid = await findTicketUid(program, raffleIdx, winnerIdx - 1);
+    const winner = getUserSigner(winnerUid);
+
+    // Creator can not take prize back once tickets are sold
+    try {
+      await reclaimPrizeSol(program, raffleIdx, payer);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RaffleHasSales"), "Transaction should be reverted with RaffleHasSales.");
+    }
+
+    const winnerBalanceBefore = await provider.connection.getBalance(winner.publicKey);
+    await claimPrizeSol(program, raffleIdx, winnerUid, winner);
+    const winnerBalanceAfter = await provider.connection.getBalance(winner.publicKey);
+
+    assert(winnerBalanceAfter > winnerBalanceBefore, "Prize not claimed");
+    assert(await provider.connection.getBalance(prizeVault) == 0, "Prize vault not empty");
+  });
+
+  it('Success reclaim sol prize without sales', async () => {
+
+    const totalSupply = 10;
+    const prizeAmount = new anchor.BN(LAMPORTS_PER_SOL / 10);
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 3);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    await createRaffle(program, raffleIdx, payer, NATIVE_MINT, totalSupply, price, startDate, endDate);
+    await addPrizeSol(program, raffleIdx, payer, prizeAmount);
+
+    // Try to reclaim before raffle ends
+    try {
+      await reclaimPrizeSol(program, raffleIdx, payer);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RaffleNotEnded"), "Transaction should be reverted with RaffleNotEnded.");
+    }
+
+    await delay(4);
+    await reclaimPrizeSol(program, raffleIdx, payer);
+
+    const prizeVault = findPrizeEscrowPda(rafflePda, 0);
+    assert(await provider.connection.getBalance(prizeVault) == 0, "Prize vault not empty");
+  });
+
+});
@@ -0,0 +1,196 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import AUTHORITY_WALLET from './keypairs/authority.json';
+import VAULT_WALLET from './keypairs/vault.json';
+import PERMIT_SIGNER_WALLET from './keypairs/permit-signer.json';
+import PAYER_WALLET from './keypairs/payer.json';
+import { addPrize, addWhitelist, buyTicket, cancelRaffle, createRaffle, delay, findGlobalPda, findRafflePda, findShardEscrowPda, findShardPda, findUserPda, mintNft, reclaimPrize, refundTicket, requestRandomness, revealWinner, safeAirdrop, transferNft, waitForRaffleEnd } from "../utils";
+import { SolanaRaffle } from "../../target/types/solana_raffle";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { assert } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { TokenStandard } from "@metaplex-foundation/mpl-token-metadata";
+
+
+describe("cancel raffle", () => {
+
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SolanaRaffle as anchor.Program<SolanaRaffle>;
+
+  const vault = anchor.web3.Keypair.fromSecretKey(Buffer.from(VAULT_WALLET));
+  const permitSigner = anchor.web3.Keypair.fromSecretKey(Buffer.from(PERMIT_SIGNER_WALLET));
+  const authority = anchor.web3.Keypair.fromSecretKey(Buffer.from(AUTHORITY_WALLET));
+  const payer = anchor.web3.Keypair.fromSecretKey(Buffer.from(PAYER_WALLET));
+
+  const totalUsers = 2;
+  let userIds: string[] = [];
+  let userSigners: Keypair[] = [];
+
+  let collection;
+  before(async () => {
+    collection = await mintNft(provider.connection, authority, true);
+    await addWhitelist(program, authority, collection);
+
+    for (let i = 1; i <= totalUsers; i++) {
+      userIds.push(`63efe02f54d5460081a369${i.toString().padEnd(2, '0')}`);
+
+      const signer = Keypair.generate();
+      await safeAirdrop(provider.connection, signer.publicKey, 1);
+      userSigners.push(signer);
+    }
+  })
+
+  it('Success cancel raffle / refund tickets / reclaim prize', async () => {
+
+    const totalSupply = 10;
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 30);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+    const escrowPda = findShardEscrowPda(rafflePda, 0);
+
+    const splMint = NATIVE_MINT;
+    await createRaffle(program, raffleIdx, payer, splMint, totalSupply, price, startDate, endDate);
+
+    const nft = await mintNft(provider.connection, payer, false, collection, authority);
+    await addPrize(program, raffleIdx, payer, nft);
+
+    await delay(3)
+    for (let i = 0; i < totalUsers; i++) {
+      await buyTicket(program, userIds[i], 2, raffleIdx, userSigners[i], permitSigner, splMint);
+    }
+
+    // Try to reclaim prize while raffle is live
+    try {
+      await reclaimPrize(program, raffleIdx, payer);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RaffleNotEnded"), "Transaction should be reverted with RaffleNotEnded.");
+    }
+
+    // Creator can not cancel after first sale
+    try {
+      await cancelRaffle(program, payer, raffleIdx);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RaffleHasSales"), "Transaction should be reverted with RaffleHasSales.");
+    }
+
+    // Try to refund before cancel
+    try {
+      await refundTicket(program, raffleIdx, userIds[0], userSigners[0].publicKey, splMint, userSigners[0]);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RaffleNotRefundable"), "Transaction should be reverted with RaffleNotRefundable.");
+    }
+
+    await cancelRaffle(program, authority, raffleIdx);
+
+    // Buy / reveal is not allowed after cancel
+    try {
+      await buyTicket(program, userIds[0], 1, raffleIdx, userSigners[0], permitSigner, splMint);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RaffleCancelled"), "Transaction should be reverted with RaffleCancelled.");
+    }
+
+    try {
+      await revealWinner(program, authority, raffleIdx);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RaffleCancelled"), "Transaction should be reverted with RaffleCancelled.");
+    }
+
+    // Refund each buyer, platform fee stays held for the vault
+    for (let i = 0; i < totalUsers; i++) {
+      const userPda = findUserPda(userIds[i], rafflePda);
+      const userAccount = await program.account.userAccount.fetch(userPda);
+      const escrowBalanceBefore = await provider.connection.getBalance(escrowPda);
+
+      await refundTicket(program, raffleIdx, userIds[i], userSigners[i].publicKey, splMint, userSigners[i]);
+
+      const escrowBalanceAfter = await provider.connection.getBalance(escrowPda);
+      assert(escrowBalanceBefore - escrowBalanceAfter == userAccount.deposited.toNumber(), "Refund amount not matched");
+    }
+    const ticketShard = await program.account.ticketShard.fetch(findShardPda(raffleIdx, 0));
+    assert(await provider.connection.getBalance(escrowPda) == ticketShard.fees.toNumber(), "Escrow should only hold fee");
+
+    // Creator gets the prize released
+    await reclaimPrize(program, raffleIdx, payer);
+    await transferNft(provider.connection, payer, authority.publicKey, nft, TokenStandard.NonFungible);
+  });
+
+  it('Success cancel raffle by creator before first sale', async () => {
+
+    const totalSupply = 10;
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 30);
+    const price = new anchor.BN(1_000_000);
+
+    const globalPda = findGlobalPda();
+    const globalAccount = await program.account.globalAccount.fetch(globalPda);
+    const raffleIdx = globalAccount.totalRaffles;
+    const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+    await createRaffle(program, raffleIdx, payer, NATIVE_MINT, totalSupply, price, startDate, endDate);
+
+    const nft = await mintNft(provider.connection, payer, false, collection, authority);
+    await addPrize(program, raffleIdx, payer, nft);
+
+    await cancelRaffle(program, payer, raffleIdx);
+
+    const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+    assert(raffleAccount.isCancelled, "Raffle not cancelled");
+
+    await reclaimPrize(program, raffleIdx, payer);
+  });
+
+  it('Can not cancel raffle once randomness is requested', async () => {
+
+    const now = Math.floor(Date.now() / 1000);
+    const startDate = new anchor.BN(now);
+    const endDate = new anchor.BN(now + 10);
+    const price = new anchor.BN(1_000_000);
+
+    const globalAccount = await program.account.globalAccount.fetch(findGlobalPda());
+    const raffleIdx = globalAccount.totalRaffles;
+
+    const splMint = NATIVE_MINT;
+    await createRaffle(program, raffleIdx, payer, splMint, 10, price, startDate, endDate);
+
+    const nft = await mintNft(provider.connection, payer, false, collection, authority);
+    await addPrize(program, raffleIdx, payer, nft);
+
+    await delay(2)
+    await buyTicket(program, userIds[0], 1, raffleIdx, userSigners[0], permitSigner, splMint);
+
+    await waitForRaffleEnd(program, raffleIdx);
+    await requestRandomness(program, authority, raffleIdx);
+
+    try {
+      await cancelRaffle(program, authority, raffleIdx);
+      assert(false, "Transaction should be reverted");
+    }
+    catch (ex) {
+      assert(ex.toString().includes("RandomnessAlreadyRequested"), "Transaction should be reverted with RandomnessAlreadyRequested.");
+    }
+  });
+
+});
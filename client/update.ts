@@ -2,9 +2,9 @@ import * as anchor from "@coral-xyz/anchor";
 import { AnchorProvider, Program, Wallet } from "@coral-xyz/anchor";
 import { Connection, PublicKey } from "@solana/web3.js";
 
-import { RAFFLE_PROGRAM_ID, RPC_URL, SIGNER } from ".";
+import { PERMIT_SIGNER, RAFFLE_PROGRAM_ID, RPC_URL, SIGNER } from ".";
 import { SolanaRaffle, IDL } from "../target/types/solana_raffle";
-import { findGlobalPda } from "./utils";
+import { findEventAuthorityPda, findGlobalPda } from "./utils";
 
 const main = async () => {
 
@@ -24,7 +24,10 @@ const main = async () => {
             newAuthority: new PublicKey("74DWajGYmLZFiQuU8PACtKW24LxdJdNMyQBVKyw81vXq"),
             globalAccount: globalPda,
             vault: new PublicKey("HK5Tf2wDXjvX1veuESXbcehaAjECcZdfnPCDfrcE7dAt"),
+            permitSigner: PERMIT_SIGNER,
             systemProgram: anchor.web3.SystemProgram.programId,
+            eventAuthority: findEventAuthorityPda(),
+            program: program.programId,
         })
         .rpc();
     console.log(tx);
@@ -1,22 +1,32 @@
 
-import { Connection, Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { Connection, Ed25519Program, Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
 import * as anchor from "@coral-xyz/anchor";
 import BN from "bn.js";
-import { ASSOCIATED_TOKEN_PROGRAM_ID, NATIVE_MINT, TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, NATIVE_MINT, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
 
 import { RAFFLE_PROGRAM_ID } from ".";
 import { SolanaRaffle } from "../target/types/solana_raffle";
+import { MockOracle } from "../target/types/mock_oracle";
 import { Metaplex, keypairIdentity } from "@metaplex-foundation/js";
 import { MPL_TOKEN_METADATA_PROGRAM_ID, TokenStandard } from "@metaplex-foundation/mpl-token-metadata";
 import { MPL_TOKEN_AUTH_RULES_PROGRAM_ID } from "@metaplex-foundation/mpl-token-auth-rules";
+import { keccak_256 } from "@noble/hashes/sha3";
 
 const METADATA_PROGRAM_ID = new PublicKey(MPL_TOKEN_METADATA_PROGRAM_ID);
 const AUTHRULE_PROGRAM_ID = new PublicKey(MPL_TOKEN_AUTH_RULES_PROGRAM_ID);
+export const MPL_CORE_PROGRAM_ID = new PublicKey("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+export const BUBBLEGUM_PROGRAM_ID = new PublicKey("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+const COMPRESSION_PROGRAM_ID = new PublicKey("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+const NOOP_PROGRAM_ID = new PublicKey("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
 
 const PREFIX = "solana-raffle";
 const ESCROW_SEED = "escrow";
 const RAFFLE_SEED = "raffle";
 const USER_SEED = "user";
+const PRIZE_SEED = "prize";
+const FEE_SEED = "fee";
+const REFERRAL_SEED = "referral";
+const WHITELIST_SEED = "wl";
 
 export const delay = sec => new Promise(resolve => setTimeout(resolve, sec * 1000));
 
@@ -60,6 +70,59 @@ export function byteArrayToHexString(bytes: number[]): string {
   return uid;
 }
 
+export const PrizeKind = { Nft: 0, Pnft: 1, Token: 2, Sol: 3, Nft2022: 4, Core: 5, Cnft: 6 };
+
+const TICKET_SIZE = 4 + 4 + 12;
+
+// Ticket ranges are stored after the shard header, outside of the IDL layout
+export const fetchTickets = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  shard: number,
+) => {
+  const shardPda = findShardPda(raffleIdx, shard);
+  const ticketShard = await program.account.ticketShard.fetch(shardPda);
+  const accountInfo = await program.provider.connection.getAccountInfo(shardPda);
+  const offset = program.account.ticketShard.size;
+
+  const tickets = [];
+  for (let i = 0; i < ticketShard.totalTickets; i++) {
+    const data = accountInfo.data.subarray(offset + i * TICKET_SIZE, offset + (i + 1) * TICKET_SIZE);
+    tickets.push({
+      startIdx: data.readUInt32LE(0),
+      amount: data.readUInt32LE(4),
+      uid: [...data.subarray(8)],
+    });
+  }
+  return tickets;
+}
+
+// Map global ticket index to its shard, using shard sales snapshot of the raffle
+export function locateTicket(raffleAccount, ticketIdx: number) {
+  let offset = 0;
+  for (let shard = 0; shard < raffleAccount.totalShards; shard++) {
+    const sales = raffleAccount.shardSales[shard];
+    if (ticketIdx < offset + sales) {
+      return { shard, shardIdx: ticketIdx - offset };
+    }
+    offset += sales;
+  }
+  return null;
+}
+
+export const findTicketUid = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  ticketIdx: number,
+): Promise<string> => {
+  const raffleAccount = await program.account.raffleAccountV2.fetch(findRafflePda(new anchor.BN(raffleIdx)));
+  const { shard, shardIdx } = locateTicket(raffleAccount, ticketIdx);
+
+  const tickets = await fetchTickets(program, raffleIdx, shard);
+  const ticket = tickets.find(t => t.startIdx <= shardIdx && shardIdx < t.startIdx + t.amount);
+  return ticket ? byteArrayToHexString(ticket.uid) : null;
+}
+
 export const findMetadataPda = (mint: PublicKey): PublicKey => {
   const [pda] = PublicKey.findProgramAddressSync([
     Buffer.from('metadata'),
@@ -101,6 +164,15 @@ export const findGlobalPda = (): PublicKey => {
   return pda;
 };
 
+// Signer of the self-invocations logging events emitted through CPI
+export const findEventAuthorityPda = (): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from("__event_authority"),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
 export const findRafflePda = (idx: BN): PublicKey => {
   const [pda] = PublicKey.findProgramAddressSync([
     Buffer.from(PREFIX),
@@ -111,6 +183,108 @@ export const findRafflePda = (idx: BN): PublicKey => {
   return pda;
 };
 
+export const findShardPda = (raffleIdx: number, shard: number): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    Buffer.from(RAFFLE_SEED),
+    new anchor.BN(raffleIdx).toBuffer('be', 4),
+    Buffer.from([shard]),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
+// Every ticket shard in order, passed where the program needs final sales totals
+export const shardAccounts = (raffleIdx: number, totalShards: number = 1) => {
+  return [...Array(totalShards).keys()].map(shard => ({
+    pubkey: findShardPda(raffleIdx, shard),
+    isSigner: false,
+    isWritable: false,
+  }));
+};
+
+const fetchShardAccounts = async (program: anchor.Program<SolanaRaffle>, raffleIdx: number) => {
+  const raffleAccount = await program.account.raffleAccountV2.fetch(findRafflePda(new anchor.BN(raffleIdx)));
+  return shardAccounts(raffleIdx, raffleAccount.totalShards);
+};
+
+// Every ticket shard in order followed by their escrows, passed where the program pays out of ticket proceeds
+export const shardEscrowAccounts = (raffleIdx: number, totalShards: number = 1) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  return [
+    ...shardAccounts(raffleIdx, totalShards),
+    ...[...Array(totalShards).keys()].map(shard => ({
+      pubkey: findShardEscrowPda(rafflePda, shard),
+      isSigner: false,
+      isWritable: true,
+    })),
+  ];
+};
+
+const fetchShardEscrowAccounts = async (program: anchor.Program<SolanaRaffle>, raffleIdx: number) => {
+  const raffleAccount = await program.account.raffleAccountV2.fetch(findRafflePda(new anchor.BN(raffleIdx)));
+  return shardEscrowAccounts(raffleIdx, raffleAccount.totalShards);
+};
+
+// Fee split of a single raffle
+export const findFeeConfigPda = (rafflePda: PublicKey): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    rafflePda.toBuffer(),
+    Buffer.from(FEE_SEED),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
+// Global fee split, each change is a new version starting from 1
+export const findGlobalFeeConfigPda = (version: number): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    Buffer.from(FEE_SEED),
+    new anchor.BN(version).toBuffer('be', 4),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
+export const findWhitelistPda = (collection: PublicKey): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    Buffer.from(WHITELIST_SEED),
+    collection.toBuffer(),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
+// Whitelist entry of the verified nft collection, falling back to verified first creator
+const findPrizeWhitelistPda = async (connection: Connection, mint: PublicKey): Promise<PublicKey> => {
+  const nft = await new Metaplex(connection).nfts().findByMint({ mintAddress: mint });
+  if (nft.collection?.verified) {
+    return findWhitelistPda(nft.collection.address);
+  }
+
+  const creator = nft.creators[0];
+  return findWhitelistPda(creator?.verified ? creator.address : PublicKey.default);
+};
+
+export const findTreeAuthorityPda = (merkleTree: PublicKey): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([merkleTree.toBuffer()], BUBBLEGUM_PROGRAM_ID);
+  return pda;
+};
+
+export const findReferralPda = (referrer: PublicKey, mint: PublicKey): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    Buffer.from(REFERRAL_SEED),
+    referrer.toBuffer(),
+    mint.toBuffer(),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
 export const findEscrowPda = (rafflePda: PublicKey): PublicKey => {
   const [pda] = PublicKey.findProgramAddressSync([
     Buffer.from(PREFIX),
@@ -121,6 +295,29 @@ export const findEscrowPda = (rafflePda: PublicKey): PublicKey => {
   return pda;
 };
 
+// Escrow of ticket proceeds and unswept fees of a shard
+export const findShardEscrowPda = (rafflePda: PublicKey, shard: number): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    rafflePda.toBuffer(),
+    Buffer.from(ESCROW_SEED),
+    Buffer.from([shard]),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
+export const findPrizeEscrowPda = (rafflePda: PublicKey, slot: number): PublicKey => {
+  const [pda] = PublicKey.findProgramAddressSync([
+    Buffer.from(PREFIX),
+    rafflePda.toBuffer(),
+    Buffer.from(PRIZE_SEED),
+    Buffer.from([slot]),
+  ], RAFFLE_PROGRAM_ID);
+
+  return pda;
+};
+
 export const findUserPda = (uid: string, rafflePda: PublicKey): PublicKey => {
   const [pda] = PublicKey.findProgramAddressSync([
     Buffer.from(USER_SEED),
@@ -131,42 +328,211 @@ export const findUserPda = (uid: string, rafflePda: PublicKey): PublicKey => {
   return pda;
 };
 
+// Purchase permit message, must match `BuyPermit::message` of the program
+export const buyPermitMessage = (
+  raffleIdx: number,
+  uid: string,
+  buyer: PublicKey,
+  permit: { maxAmount: number, expiry: BN, nonce: BN },
+) => {
+  return Buffer.concat([
+    Buffer.from(PREFIX),
+    new anchor.BN(raffleIdx).toArrayLike(Buffer, 'le', 4),
+    Buffer.from(hexStringToNumArray(uid)),
+    buyer.toBuffer(),
+    new anchor.BN(permit.maxAmount).toArrayLike(Buffer, 'le', 4),
+    permit.expiry.toArrayLike(Buffer, 'le', 8),
+    permit.nonce.toArrayLike(Buffer, 'le', 8),
+  ]);
+}
+
+// User id of a wallet in public raffles, must match `wallet_uid` of the program
+export const walletUid = (wallet: PublicKey): string => {
+  return byteArrayToHexString([...wallet.toBuffer().subarray(0, 12)]);
+}
+
+// Permit valid for a minute, allowing exactly `amount` tickets
+export const newBuyPermit = (amount: number) => ({
+  maxAmount: amount,
+  expiry: new anchor.BN(Math.floor(Date.now() / 1000) + 60),
+  nonce: new anchor.BN(Date.now()),
+});
+
+// Token program owning the mint, classic SPL or Token-2022
+export const findTokenProgramId = async (connection: Connection, mint: PublicKey): Promise<PublicKey> => {
+  const mintInfo = await connection.getAccountInfo(mint);
+  return mintInfo?.owner.equals(TOKEN_2022_PROGRAM_ID) ? TOKEN_2022_PROGRAM_ID : TOKEN_PROGRAM_ID;
+};
+
 export const buyTicket = async (
   program: anchor.Program<SolanaRaffle>,
   uid: string,
   amount: number,
   raffleIdx: number,
   buyer: Keypair,
-  authority: Keypair,
-  vault: PublicKey,
+  permitSigner: Keypair | null,
   splMint: PublicKey,
+  shard: number = 0,
+  permit = newBuyPermit(amount),
+  referrer: PublicKey | null = null,
 ) => {
   const globalPda = findGlobalPda();
   const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
   const userPda = findUserPda(uid, rafflePda);
-  const escrowPda = findEscrowPda(rafflePda);
 
   const isNative = splMint == NATIVE_MINT;
-  if (!isNative) {
-    const userAta = getAssociatedTokenAddressSync(splMint, buyer.publicKey);
-  }
+  const tokenProgram = await findTokenProgramId(program.provider.connection, splMint);
+
+  // Permit signature is verified by the ed25519 program right before purchase,
+  // public raffles are bought without permit
+  const preInstructions = permitSigner ? [Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: permitSigner.secretKey,
+    message: buyPermitMessage(raffleIdx, uid, buyer.publicKey, permit),
+  })] : [];
 
-  return program.methods.buyTicket(raffleIdx, hexStringToNumArray(uid), amount)
+  return program.methods.buyTicket(raffleIdx, hexStringToNumArray(uid), amount, shard, permitSigner ? permit : null)
     .accounts({
-      authority: authority.publicKey,
       buyer: buyer.publicKey,
       globalAccount: globalPda,
       raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
       userAccount: userPda,
       splMint,
-      userTokenAccount: isNative ? buyer.publicKey : getAssociatedTokenAddressSync(splMint, buyer.publicKey),
-      escrowAccount: escrowPda,
-      vaultTokenAccount: isNative ? vault : getAssociatedTokenAddressSync(splMint, vault),
+      userTokenAccount: isNative ? buyer.publicKey : getAssociatedTokenAddressSync(splMint, buyer.publicKey, false, tokenProgram),
+      escrowAccount: findShardEscrowPda(rafflePda, shard),
+      referralAccount: referrer ? findReferralPda(referrer, splMint) : null,
+      referralEscrow: referrer ? findEscrowPda(findReferralPda(referrer, splMint)) : null,
+      sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
       systemProgram: anchor.web3.SystemProgram.programId,
-      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgram,
+      rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .preInstructions(preInstructions)
+    .signers([buyer])
+    .rpc();
+}
+
+// Sweep fees held by a shard escrow to the fee split in effect, or to the vault
+export const sweepFees = async (
+  program: anchor.Program<SolanaRaffle>,
+  payer: Keypair,
+  raffleIdx: number,
+  shard: number = 0,
+) => {
+  const globalPda = findGlobalPda();
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const globalAccount = await program.account.globalAccount.fetch(globalPda);
+  const splMint = raffleAccount.splMint;
+  const isNative = splMint.equals(NATIVE_MINT);
+  const tokenProgram = await findTokenProgramId(program.provider.connection, splMint);
+
+  // Fee recipients of the split in effect, passed as remaining accounts
+  const raffleFeeConfig = raffleAccount.hasFeeConfig ? findFeeConfigPda(rafflePda) : null;
+  const globalFeeConfig = !raffleAccount.hasFeeConfig && raffleAccount.feeConfigVersion ? findGlobalFeeConfigPda(raffleAccount.feeConfigVersion) : null;
+  const feeConfig = raffleFeeConfig ?? globalFeeConfig;
+  const feeConfigAccount = feeConfig ? await program.account.feeConfig.fetch(feeConfig) : null;
+  const feeRecipients = feeConfigAccount ? feeConfigAccount.recipients.slice(0, feeConfigAccount.totalRecipients) : [];
+  const feeAccounts = feeRecipients.map(r => ({
+    pubkey: isNative ? r.wallet : getAssociatedTokenAddressSync(splMint, r.wallet, false, tokenProgram),
+    isSigner: false,
+    isWritable: true,
+  }));
+
+  return program.methods.sweepFees(raffleIdx, shard)
+    .accounts({
+      payer: payer.publicKey,
+      globalAccount: globalPda,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      splMint,
+      escrowAccount: findShardEscrowPda(rafflePda, shard),
+      vaultTokenAccount: isNative ? globalAccount.vault : getAssociatedTokenAddressSync(splMint, globalAccount.vault, false, tokenProgram),
+      raffleFeeConfig,
+      globalFeeConfig,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .remainingAccounts(feeAccounts)
+    .signers([payer])
+    .rpc();
+}
+
+// Fees of every shard are swept first, proceeds are withdrawn once none are held
+export const withdrawRaffle = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  authority: Keypair,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  for (let shard = 0; shard < raffleAccount.totalShards; shard++) {
+    const ticketShard = await program.account.ticketShard.fetch(findShardPda(raffleIdx, shard));
+    if (!ticketShard.fees.isZero()) {
+      await sweepFees(program, authority, raffleIdx, shard);
+    }
+  }
+
+  const splMint = raffleAccount.splMint;
+  const isNative = splMint.equals(NATIVE_MINT);
+  const tokenProgram = await findTokenProgramId(program.provider.connection, splMint);
+
+  return program.methods.withdrawRaffle(raffleIdx)
+    .accounts({
+      authority: authority.publicKey,
+      raffleAccount: rafflePda,
+      splMint,
+      treasuryTokenAccount: isNative ? authority.publicKey : getAssociatedTokenAddressSync(splMint, authority.publicKey, false, tokenProgram),
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram,
       rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .remainingAccounts(shardEscrowAccounts(raffleIdx, raffleAccount.totalShards))
+    .signers([authority])
+    .rpc();
+}
+
+// Push proceeds of an ended raffle to its creator, anyone can pay the transaction
+export const pushProceeds = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  payer: Keypair,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  for (let shard = 0; shard < raffleAccount.totalShards; shard++) {
+    const ticketShard = await program.account.ticketShard.fetch(findShardPda(raffleIdx, shard));
+    if (!ticketShard.fees.isZero()) {
+      await sweepFees(program, payer, raffleIdx, shard);
+    }
+  }
+
+  const authority = raffleAccount.authority;
+  const splMint = raffleAccount.splMint;
+  const isNative = splMint.equals(NATIVE_MINT);
+  const tokenProgram = await findTokenProgramId(program.provider.connection, splMint);
+
+  return program.methods.pushProceeds(raffleIdx)
+    .accounts({
+      payer: payer.publicKey,
+      authority,
+      raffleAccount: rafflePda,
+      splMint,
+      treasuryTokenAccount: isNative ? authority : getAssociatedTokenAddressSync(splMint, authority, false, tokenProgram),
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
     })
-    .signers([buyer, authority])
+    .remainingAccounts(shardEscrowAccounts(raffleIdx, raffleAccount.totalShards))
+    .signers([payer])
     .rpc();
 }
 
@@ -179,21 +545,54 @@ export const createRaffle = async (
   price: BN,
   startDate: BN,
   endDate: BN,
+  totalPrizes: number = 1,
+  minSales: number = 0,
+  totalShards: number = 1,
+  isPublic: boolean = false,
+  maxTicketPercent: number | null = null,
 ) => {
   const globalPda = findGlobalPda();
   const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
 
-  return program.methods.createRaffle(raffleIdx, totalSupply, price, startDate, endDate)
+  await program.methods.createRaffle(raffleIdx, totalSupply, totalPrizes, price, startDate, endDate, minSales, totalShards, isPublic, maxTicketPercent)
     .accounts({
       authority: creator.publicKey,
       globalAccount: globalPda,
       raffleAccount: rafflePda,
       splMint,
       systemProgram: anchor.web3.SystemProgram.programId,
-      rent: anchor.web3.SYSVAR_RENT_PUBKEY
+      rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
     })
     .signers([creator])
     .rpc();
+
+  return createShards(program, raffleIdx, creator, totalShards);
+}
+
+export const createShards = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  creator: Keypair,
+  totalShards: number = 1,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+  const instructions = await Promise.all([...Array(totalShards).keys()].map(shard =>
+    program.methods.createShard(raffleIdx, shard)
+      .accounts({
+        authority: creator.publicKey,
+        raffleAccount: rafflePda,
+        ticketShard: findShardPda(raffleIdx, shard),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        eventAuthority: findEventAuthorityPda(),
+        program: program.programId,
+      })
+      .instruction()
+  ));
+
+  return program.provider.sendAndConfirm(new Transaction().add(...instructions), [creator]);
 }
 
 export const addPrize = async (
@@ -201,19 +600,19 @@ export const addPrize = async (
   raffleIdx: number,
   creator: Keypair,
   mint: PublicKey,
+  slot: number = 0,
 ) => {
-  const globalPda = findGlobalPda();
   const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
   const escrowPda = findEscrowPda(rafflePda);
   const tokenAccount = getAssociatedTokenAddressSync(mint, creator.publicKey);
   const metadata = findMetadataPda(mint);
   const edition = findEditionPda(mint);
 
-  return program.methods.addPrize(raffleIdx)
+  return program.methods.addPrize(raffleIdx, slot)
     .accounts({
       authority: creator.publicKey,
-      globalAccount: globalPda,
       raffleAccount: rafflePda,
+      whitelistEntry: await findPrizeWhitelistPda(program.provider.connection, mint),
       escrowAccount: escrowPda,
       mint,
       metadata,
@@ -223,6 +622,8 @@ export const addPrize = async (
       systemProgram: anchor.web3.SystemProgram.programId,
       tokenProgram: TOKEN_PROGRAM_ID,
       rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
     })
     .signers([creator])
     .rpc();
@@ -233,8 +634,8 @@ export const addPrizePnft = async (
   raffleIdx: number,
   creator: Keypair,
   mint: PublicKey,
+  slot: number = 0,
 ) => {
-  const globalPda = findGlobalPda();
   const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
   const escrowPda = findEscrowPda(rafflePda);
   const tokenAccount = getAssociatedTokenAddressSync(mint, creator.publicKey);
@@ -243,11 +644,11 @@ export const addPrizePnft = async (
   const tokenRecord = findTokenRecordPda(mint, tokenAccount);
   const delegateRecord = findTokenRecordPda(mint, escrowPda);
 
-  return program.methods.addPrizePnft(raffleIdx)
+  return program.methods.addPrizePnft(raffleIdx, slot)
     .accounts({
       authority: creator.publicKey,
-      globalAccount: globalPda,
       raffleAccount: rafflePda,
+      whitelistEntry: await findPrizeWhitelistPda(program.provider.connection, mint),
       escrowAccount: escrowPda,
       mint,
       metadata,
@@ -261,99 +662,879 @@ export const addPrizePnft = async (
       sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
       authorizationRules: RAFFLE_PROGRAM_ID,
       authorizationRulesProgram: AUTHRULE_PROGRAM_ID,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
     })
     .signers([creator])
     .rpc();
 }
 
-export const revealWinner = async (
+export const addPrizeToken = async (
   program: anchor.Program<SolanaRaffle>,
-  authority: Keypair,
   raffleIdx: number,
+  creator: Keypair,
+  mint: PublicKey,
+  amount: BN,
+  slot: number = 0,
 ) => {
-  const globalPda = findGlobalPda();
   const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const prizeEscrow = findPrizeEscrowPda(rafflePda, slot);
+  const tokenAccount = getAssociatedTokenAddressSync(mint, creator.publicKey);
 
-  return program.methods.revealWinner(raffleIdx)
+  return program.methods.addPrizeToken(raffleIdx, slot, amount)
     .accounts({
-      authority: authority.publicKey,
-      globalAccount: globalPda,
+      authority: creator.publicKey,
       raffleAccount: rafflePda,
-      recentSlothashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+      mint,
+      tokenAccount,
+      prizeEscrow,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
     })
-    .signers([authority])
+    .signers([creator])
     .rpc();
 }
 
-export const claimPrize = async (
+export const addPrizeSol = async (
   program: anchor.Program<SolanaRaffle>,
   raffleIdx: number,
-  winnerUid: string,
-  winner: Keypair,
+  creator: Keypair,
+  amount: BN,
+  slot: number = 0,
 ) => {
   const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
-  const userPda = findUserPda(winnerUid, rafflePda);
-  const escrowPda = findEscrowPda(rafflePda);
+  const prizeVault = findPrizeEscrowPda(rafflePda, slot);
 
-  const raffleAccount = await program.account.raffleAccount.fetch(rafflePda);
-  const mint = raffleAccount.mint;
-  const creatorAta = getAssociatedTokenAddressSync(mint, raffleAccount.authority);
-  const metadata = findMetadataPda(mint);
-  const edition = findEditionPda(mint);
+  return program.methods.addPrizeSol(raffleIdx, slot, amount)
+    .accounts({
+      authority: creator.publicKey,
+      raffleAccount: rafflePda,
+      prizeVault,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([creator])
+    .rpc();
+}
 
-  const winnerAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, winner, mint, winner.publicKey);
+export const addPrize2022 = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  creator: Keypair,
+  mint: PublicKey,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const tokenAccount = getAssociatedTokenAddressSync(mint, creator.publicKey, false, TOKEN_2022_PROGRAM_ID);
 
-  return program.methods.claimPrize(raffleIdx, hexStringToNumArray(winnerUid))
+  return program.methods.addPrize2022(raffleIdx, slot)
     .accounts({
-      authority: winner.publicKey,
+      authority: creator.publicKey,
       raffleAccount: rafflePda,
-      userAccount: userPda,
-      escrowAccount: escrowPda,
+      whitelistEntry: await findPrizeWhitelistPda(program.provider.connection, mint),
       mint,
-      metadata,
-      edition,
-      creatorTokenAccount: creatorAta,
-      winnerTokenAccount: winnerAta.address,
-      metadataProgram: METADATA_PROGRAM_ID,
+      metadata: findMetadataPda(mint),
+      tokenAccount,
+      prizeEscrow: findPrizeEscrowPda(rafflePda, slot),
       systemProgram: anchor.web3.SystemProgram.programId,
-      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
       rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
     })
-    .signers([winner])
+    .signers([creator])
     .rpc();
 }
 
-export const claimPrizePnft = async (
+export const addPrizeCore = async (
   program: anchor.Program<SolanaRaffle>,
   raffleIdx: number,
-  winnerUid: string,
-  winner: Keypair,
+  creator: Keypair,
+  asset: PublicKey,
+  collection: PublicKey,
+  slot: number = 0,
 ) => {
   const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
-  const escrowPda = findEscrowPda(rafflePda);
-  const userPda = findUserPda(winnerUid, rafflePda);
-
-  const raffleAccount = await program.account.raffleAccount.fetch(rafflePda);
-  const mint = raffleAccount.mint;
-  const metadata = findMetadataPda(mint);
-  const edition = findEditionPda(mint);
-
-  const owner = raffleAccount.authority;
-  const ownerTokenAccount = getAssociatedTokenAddressSync(mint, owner);
-  const destTokenAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, winner, mint, winner.publicKey);
-  const destTokenAccount = destTokenAta.address;
-  const ownerTokenRecord = findTokenRecordPda(mint, ownerTokenAccount);
-  const destTokenRecord = findTokenRecordPda(mint, destTokenAccount);
 
-  return program.methods.claimPrizePnft(raffleIdx, hexStringToNumArray(winnerUid))
+  return program.methods.addPrizeCore(raffleIdx, slot)
     .accounts({
-      authority: winner.publicKey,
+      authority: creator.publicKey,
       raffleAccount: rafflePda,
-      escrowAccount: escrowPda,
-      userAccount: userPda,
-      mint,
-      metadata,
-      edition,
+      whitelistEntry: findWhitelistPda(collection),
+      asset,
+      collection,
+      escrowAccount: findEscrowPda(rafflePda),
+      coreProgram: MPL_CORE_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([creator])
+    .rpc();
+}
+
+// Leaf of a compressed nft with its current proof, as served by DAS `getAssetProof`
+export type CnftLeaf = {
+  merkleTree: PublicKey,
+  root: number[],
+  proof: PublicKey[],
+  nonce: number,
+  index: number,
+};
+
+const proofAccounts = (proof: PublicKey[]) =>
+  proof.map(pubkey => ({ pubkey, isSigner: false, isWritable: false }));
+
+export const addPrizeCnft = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  creator: Keypair,
+  leaf: CnftLeaf,
+  metadata: any,
+  whitelistKey: PublicKey,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+  return program.methods.addPrizeCnft(raffleIdx, slot, leaf.root, metadata, new anchor.BN(leaf.nonce), leaf.index)
+    .accounts({
+      authority: creator.publicKey,
+      raffleAccount: rafflePda,
+      whitelistEntry: findWhitelistPda(whitelistKey),
+      compressedPrize: findPrizeEscrowPda(rafflePda, slot),
+      escrowAccount: findEscrowPda(rafflePda),
+      leafDelegate: creator.publicKey,
+      treeAuthority: findTreeAuthorityPda(leaf.merkleTree),
+      merkleTree: leaf.merkleTree,
+      logWrapper: NOOP_PROGRAM_ID,
+      compressionProgram: COMPRESSION_PROGRAM_ID,
+      bubblegumProgram: BUBBLEGUM_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .remainingAccounts(proofAccounts(leaf.proof))
+    .signers([creator])
+    .rpc();
+}
+
+export const reclaimPrizeSol = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  creator: Keypair,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const prizeVault = findPrizeEscrowPda(rafflePda, slot);
+
+  return program.methods.reclaimPrizeSol(raffleIdx, slot)
+    .accounts({
+      authority: creator.publicKey,
+      raffleAccount: rafflePda,
+      prizeVault,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .remainingAccounts(await fetchShardAccounts(program, raffleIdx))
+    .signers([creator])
+    .rpc();
+}
+
+export const cancelRaffle = async (
+  program: anchor.Program<SolanaRaffle>,
+  authority: Keypair,
+  raffleIdx: number,
+) => {
+  const globalPda = findGlobalPda();
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+  return program.methods.cancelRaffle(raffleIdx)
+    .accounts({
+      authority: authority.publicKey,
+      globalAccount: globalPda,
+      raffleAccount: rafflePda,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .remainingAccounts(await fetchShardAccounts(program, raffleIdx))
+    .signers([authority])
+    .rpc();
+}
+
+export const refundTicket = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  uid: string,
+  buyer: PublicKey,
+  splMint: PublicKey,
+  payer: Keypair,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const userPda = findUserPda(uid, rafflePda);
+
+  const isNative = splMint.equals(NATIVE_MINT);
+  const tokenProgram = await findTokenProgramId(program.provider.connection, splMint);
+
+  return program.methods.refundTicket(raffleIdx, hexStringToNumArray(uid))
+    .accounts({
+      payer: payer.publicKey,
+      authority: buyer,
+      raffleAccount: rafflePda,
+      userAccount: userPda,
+      splMint,
+      userTokenAccount: isNative ? buyer : getAssociatedTokenAddressSync(splMint, buyer, false, tokenProgram),
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .remainingAccounts(await fetchShardEscrowAccounts(program, raffleIdx))
+    .signers([payer])
+    .rpc();
+}
+
+export const reclaimPrize = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  creator: Keypair,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const escrowPda = findEscrowPda(rafflePda);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const mint = raffleAccount.prizes[slot].mint;
+
+  return program.methods.reclaimPrize(raffleIdx, slot)
+    .accounts({
+      authority: creator.publicKey,
+      raffleAccount: rafflePda,
+      mint,
+      edition: findEditionPda(mint),
+      tokenAccount: getAssociatedTokenAddressSync(mint, creator.publicKey),
+      escrowAccount: escrowPda,
+      metadataProgram: METADATA_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .remainingAccounts(await fetchShardAccounts(program, raffleIdx))
+    .signers([creator])
+    .rpc();
+}
+
+export const reclaimPrize2022 = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  creator: Keypair,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const mint = raffleAccount.prizes[slot].mint;
+  const creatorAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, creator, mint, creator.publicKey, false, undefined, undefined, TOKEN_2022_PROGRAM_ID);
+
+  return program.methods.reclaimPrize2022(raffleIdx, slot)
+    .accounts({
+      authority: creator.publicKey,
+      raffleAccount: rafflePda,
+      mint,
+      prizeEscrow: findPrizeEscrowPda(rafflePda, slot),
+      tokenAccount: creatorAta.address,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .remainingAccounts(await fetchShardAccounts(program, raffleIdx))
+    .signers([creator])
+    .rpc();
+}
+
+export const reclaimPrizeCore = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  creator: Keypair,
+  collection: PublicKey,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+
+  return program.methods.reclaimPrizeCore(raffleIdx, slot)
+    .accounts({
+      authority: creator.publicKey,
+      raffleAccount: rafflePda,
+      asset: raffleAccount.prizes[slot].mint,
+      collection,
+      escrowAccount: findEscrowPda(rafflePda),
+      coreProgram: MPL_CORE_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .remainingAccounts(await fetchShardAccounts(program, raffleIdx))
+    .signers([creator])
+    .rpc();
+}
+
+export const reclaimPrizeCnft = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  creator: Keypair,
+  leaf: CnftLeaf,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+  return program.methods.reclaimPrizeCnft(raffleIdx, slot, leaf.root)
+    .accounts({
+      authority: creator.publicKey,
+      raffleAccount: rafflePda,
+      compressedPrize: findPrizeEscrowPda(rafflePda, slot),
+      escrowAccount: findEscrowPda(rafflePda),
+      treeAuthority: findTreeAuthorityPda(leaf.merkleTree),
+      merkleTree: leaf.merkleTree,
+      logWrapper: NOOP_PROGRAM_ID,
+      compressionProgram: COMPRESSION_PROGRAM_ID,
+      bubblegumProgram: BUBBLEGUM_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    // Ticket shards first, then the leaf proof
+    .remainingAccounts([...await fetchShardAccounts(program, raffleIdx), ...proofAccounts(leaf.proof)])
+    .signers([creator])
+    .rpc();
+}
+
+export const ORACLE_PROGRAM_ID = new PublicKey("VRFzZoJdhFWL8rkvu87LpKM3RbcVezpMEc6X5GVDr7y");
+const ORACLE_REQUEST_SEED = "orao-vrf-randomness-request";
+
+export function findOracleRequestPda(seed: Buffer) {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(ORACLE_REQUEST_SEED), seed],
+    ORACLE_PROGRAM_ID
+  );
+  return pda;
+}
+
+// Oracle requests are bound to the raffle, one seed per request attempt
+export function findOracleSeed(rafflePda: PublicKey, attempt: number = 0) {
+  return Buffer.from(keccak_256(Buffer.concat([rafflePda.toBuffer(), Buffer.from([attempt])])));
+}
+
+export const requestOracle = async (
+  oracle: anchor.Program<MockOracle>,
+  payer: Keypair,
+  seed: Buffer,
+) => {
+  const requestPda = findOracleRequestPda(seed);
+
+  await oracle.methods.request([...seed])
+    .accounts({
+      payer: payer.publicKey,
+      request: requestPda,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([payer])
+    .rpc();
+
+  return requestPda;
+}
+
+export const fulfillOracle = async (
+  oracle: anchor.Program<MockOracle>,
+  authority: Keypair,
+  seed: Buffer,
+  randomness: Buffer,
+) => {
+  return oracle.methods.fulfill([...seed], [...randomness])
+    .accounts({
+      authority: authority.publicKey,
+      request: findOracleRequestPda(seed),
+    })
+    .signers([authority])
+    .rpc();
+}
+
+// Anyone can request once the raffle ended, authority also once it sold out
+export const requestRandomness = async (
+  program: anchor.Program<SolanaRaffle>,
+  payer: Keypair,
+  raffleIdx: number,
+  oracleRequest: PublicKey | null = null,
+) => {
+  const globalPda = findGlobalPda();
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const source = oracleRequest ? { oracle: {} } : { slotHash: {} };
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const splMint = raffleAccount.splMint;
+  const isNative = splMint.equals(NATIVE_MINT);
+  const tokenProgram = await findTokenProgramId(program.provider.connection, splMint);
+
+  // Timed out oracle request being replaced, slot hash draws leave it unset
+  const committedRequest = raffleAccount.randomness.request.equals(PublicKey.default) ? null : raffleAccount.randomness.request;
+
+  return program.methods.requestRandomness(raffleIdx, source)
+    .accounts({
+      payer: payer.publicKey,
+      globalAccount: globalPda,
+      raffleAccount: rafflePda,
+      splMint,
+      payerTokenAccount: isNative ? payer.publicKey : getAssociatedTokenAddressSync(splMint, payer.publicKey, false, tokenProgram),
+      oracleRequest,
+      committedRequest,
+      recentSlothashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .remainingAccounts(await fetchShardEscrowAccounts(program, raffleIdx))
+    .signers([payer])
+    .rpc();
+}
+
+// Wait for the end date, unless every ticket is sold
+export const waitForRaffleEnd = async (program: anchor.Program<SolanaRaffle>, raffleIdx: number) => {
+  const raffleAccount = await program.account.raffleAccountV2.fetch(findRafflePda(new anchor.BN(raffleIdx)));
+
+  let sales = 0;
+  for (let shard = 0; shard < raffleAccount.totalShards; shard++) {
+    const ticketShard = await program.account.ticketShard.fetch(findShardPda(raffleIdx, shard));
+    sales += ticketShard.totalSales;
+  }
+  if (sales >= raffleAccount.totalSupply) {
+    return;
+  }
+
+  while (Date.now() / 1000 <= raffleAccount.endDate.toNumber()) {
+    await delay(0.5);
+  }
+}
+
+export const settleRandomness = async (
+  program: anchor.Program<SolanaRaffle>,
+  payer: Keypair,
+  raffleIdx: number,
+  oracleRequest: PublicKey | null = null,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+  return program.methods.settleRandomness(raffleIdx)
+    .accounts({
+      payer: payer.publicKey,
+      raffleAccount: rafflePda,
+      oracleRequest,
+      recentSlothashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([payer])
+    .rpc();
+}
+
+// Tag of self-invocations carrying an event, `anchor_lang::event::EVENT_IX_TAG_LE`
+const EVENT_IX_TAG = Buffer.from([228, 69, 165, 46, 81, 203, 154, 29]);
+
+// Events emitted by the raffle program in a confirmed transaction, logged or through CPI
+export const fetchEvents = async (program: anchor.Program<SolanaRaffle>, signature: string) => {
+  const tx = await program.provider.connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const coder = new anchor.BorshCoder(program.idl);
+  const parser = new anchor.EventParser(program.programId, coder);
+  const events = Array.from(parser.parseLogs(tx.meta.logMessages));
+
+  const accountKeys = tx.transaction.message.getAccountKeys({ accountKeysFromLookups: tx.meta.loadedAddresses });
+  for (const inner of tx.meta.innerInstructions ?? []) {
+    for (const ix of inner.instructions) {
+      const data = Buffer.from(anchor.utils.bytes.bs58.decode(ix.data));
+      if (!accountKeys.get(ix.programIdIndex).equals(program.programId) || !data.subarray(0, 8).equals(EVENT_IX_TAG)) {
+        continue;
+      }
+      const event = coder.events.decode(anchor.utils.bytes.base64.encode(data.subarray(8)));
+      if (event) {
+        events.push(event);
+      }
+    }
+  }
+
+  return events;
+}
+
+export const revealWinner = async (
+  program: anchor.Program<SolanaRaffle>,
+  authority: Keypair,
+  raffleIdx: number,
+) => {
+  await waitForRaffleEnd(program, raffleIdx);
+  await requestRandomness(program, authority, raffleIdx);
+
+  // Wait until committed slot hash is available
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  while (await program.provider.connection.getSlot() <= raffleAccount.randomness.slot.toNumber()) {
+    await delay(0.4);
+  }
+
+  return settleRandomness(program, authority, raffleIdx);
+}
+
+export const claimPrize = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: Keypair,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const userPda = findUserPda(winnerUid, rafflePda);
+  const escrowPda = findEscrowPda(rafflePda);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+  const mint = raffleAccount.prizes[slot].mint;
+  const creatorAta = getAssociatedTokenAddressSync(mint, raffleAccount.authority);
+  const metadata = findMetadataPda(mint);
+  const edition = findEditionPda(mint);
+
+  const winnerAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, winner, mint, winner.publicKey);
+
+  return program.methods.claimPrize(raffleIdx, slot, hexStringToNumArray(winnerUid))
+    .accounts({
+      authority: winner.publicKey,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      userAccount: userPda,
+      escrowAccount: escrowPda,
+      mint,
+      metadata,
+      edition,
+      creatorTokenAccount: creatorAta,
+      winnerTokenAccount: winnerAta.address,
+      metadataProgram: METADATA_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([winner])
+    .rpc();
+}
+
+export const claimPrizePnft = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: Keypair,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const escrowPda = findEscrowPda(rafflePda);
+  const userPda = findUserPda(winnerUid, rafflePda);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+  const mint = raffleAccount.prizes[slot].mint;
+  const metadata = findMetadataPda(mint);
+  const edition = findEditionPda(mint);
+
+  const owner = raffleAccount.authority;
+  const ownerTokenAccount = getAssociatedTokenAddressSync(mint, owner);
+  const destTokenAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, winner, mint, winner.publicKey);
+  const destTokenAccount = destTokenAta.address;
+  const ownerTokenRecord = findTokenRecordPda(mint, ownerTokenAccount);
+  const destTokenRecord = findTokenRecordPda(mint, destTokenAccount);
+
+  return program.methods.claimPrizePnft(raffleIdx, slot, hexStringToNumArray(winnerUid))
+    .accounts({
+      authority: winner.publicKey,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      escrowAccount: escrowPda,
+      userAccount: userPda,
+      mint,
+      metadata,
+      edition,
+      owner,
+      ownerTokenAccount,
+      ownerTokenRecord,
+      destTokenAccount,
+      destTokenRecord,
+      metadataProgram: METADATA_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      ataProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      authorizationRules: METADATA_PROGRAM_ID,
+      authorizationRulesProgram: AUTHRULE_PROGRAM_ID,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([winner])
+    .rpc();
+}
+
+export const claimPrizeToken = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: Keypair,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const userPda = findUserPda(winnerUid, rafflePda);
+  const prizeEscrow = findPrizeEscrowPda(rafflePda, slot);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+  const mint = raffleAccount.prizes[slot].mint;
+  const winnerAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, winner, mint, winner.publicKey);
+
+  return program.methods.claimPrizeToken(raffleIdx, slot, hexStringToNumArray(winnerUid))
+    .accounts({
+      authority: winner.publicKey,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      userAccount: userPda,
+      creator: raffleAccount.authority,
+      mint,
+      prizeEscrow,
+      winnerTokenAccount: winnerAta.address,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([winner])
+    .rpc();
+}
+
+export const claimPrizeSol = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: Keypair,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const userPda = findUserPda(winnerUid, rafflePda);
+  const prizeVault = findPrizeEscrowPda(rafflePda, slot);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+
+  return program.methods.claimPrizeSol(raffleIdx, slot, hexStringToNumArray(winnerUid))
+    .accounts({
+      authority: winner.publicKey,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      userAccount: userPda,
+      prizeVault,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([winner])
+    .rpc();
+}
+
+export const claimPrize2022 = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: Keypair,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const userPda = findUserPda(winnerUid, rafflePda);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+  const mint = raffleAccount.prizes[slot].mint;
+  const winnerAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, winner, mint, winner.publicKey, false, undefined, undefined, TOKEN_2022_PROGRAM_ID);
+
+  return program.methods.claimPrize2022(raffleIdx, slot, hexStringToNumArray(winnerUid))
+    .accounts({
+      authority: winner.publicKey,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      userAccount: userPda,
+      creator: raffleAccount.authority,
+      mint,
+      prizeEscrow: findPrizeEscrowPda(rafflePda, slot),
+      winnerTokenAccount: winnerAta.address,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([winner])
+    .rpc();
+}
+
+export const claimPrizeCore = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: Keypair,
+  collection: PublicKey,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const userPda = findUserPda(winnerUid, rafflePda);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+
+  return program.methods.claimPrizeCore(raffleIdx, slot, hexStringToNumArray(winnerUid))
+    .accounts({
+      authority: winner.publicKey,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      userAccount: userPda,
+      asset: raffleAccount.prizes[slot].mint,
+      collection,
+      escrowAccount: findEscrowPda(rafflePda),
+      coreProgram: MPL_CORE_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([winner])
+    .rpc();
+}
+
+export const claimPrizeCnft = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: Keypair,
+  leaf: CnftLeaf,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const userPda = findUserPda(winnerUid, rafflePda);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+
+  return program.methods.claimPrizeCnft(raffleIdx, slot, hexStringToNumArray(winnerUid), leaf.root)
+    .accounts({
+      authority: winner.publicKey,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      userAccount: userPda,
+      creator: raffleAccount.authority,
+      compressedPrize: findPrizeEscrowPda(rafflePda, slot),
+      escrowAccount: findEscrowPda(rafflePda),
+      treeAuthority: findTreeAuthorityPda(leaf.merkleTree),
+      merkleTree: leaf.merkleTree,
+      logWrapper: NOOP_PROGRAM_ID,
+      compressionProgram: COMPRESSION_PROGRAM_ID,
+      bubblegumProgram: BUBBLEGUM_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .remainingAccounts(proofAccounts(leaf.proof))
+    .signers([winner])
+    .rpc();
+}
+
+export const pushPrize = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: PublicKey,
+  payer: Keypair,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const userPda = findUserPda(winnerUid, rafflePda);
+  const escrowPda = findEscrowPda(rafflePda);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+  const mint = raffleAccount.prizes[slot].mint;
+  const creatorAta = getAssociatedTokenAddressSync(mint, raffleAccount.authority);
+  const metadata = findMetadataPda(mint);
+  const edition = findEditionPda(mint);
+
+  const winnerAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, mint, winner);
+
+  return program.methods.pushPrize(raffleIdx, slot, hexStringToNumArray(winnerUid))
+    .accounts({
+      payer: payer.publicKey,
+      authority: winner,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      userAccount: userPda,
+      escrowAccount: escrowPda,
+      mint,
+      metadata,
+      edition,
+      creatorTokenAccount: creatorAta,
+      winnerTokenAccount: winnerAta.address,
+      metadataProgram: METADATA_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([payer])
+    .rpc();
+}
+
+export const pushPrizePnft = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: PublicKey,
+  payer: Keypair,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const escrowPda = findEscrowPda(rafflePda);
+  const userPda = findUserPda(winnerUid, rafflePda);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+  const mint = raffleAccount.prizes[slot].mint;
+  const metadata = findMetadataPda(mint);
+  const edition = findEditionPda(mint);
+
+  const owner = raffleAccount.authority;
+  const ownerTokenAccount = getAssociatedTokenAddressSync(mint, owner);
+  const destTokenAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, mint, winner);
+  const destTokenAccount = destTokenAta.address;
+  const ownerTokenRecord = findTokenRecordPda(mint, ownerTokenAccount);
+  const destTokenRecord = findTokenRecordPda(mint, destTokenAccount);
+
+  return program.methods.pushPrizePnft(raffleIdx, slot, hexStringToNumArray(winnerUid))
+    .accounts({
+      payer: payer.publicKey,
+      authority: winner,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      escrowAccount: escrowPda,
+      userAccount: userPda,
+      mint,
+      metadata,
+      edition,
       owner,
       ownerTokenAccount,
       ownerTokenRecord,
@@ -366,40 +1547,362 @@ export const claimPrizePnft = async (
       sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
       authorizationRules: METADATA_PROGRAM_ID,
       authorizationRulesProgram: AUTHRULE_PROGRAM_ID,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
     })
-    .signers([winner])
+    .signers([payer])
+    .rpc();
+}
+
+export const pushPrizeToken = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: PublicKey,
+  payer: Keypair,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const userPda = findUserPda(winnerUid, rafflePda);
+  const prizeEscrow = findPrizeEscrowPda(rafflePda, slot);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+  const mint = raffleAccount.prizes[slot].mint;
+  const winnerAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, mint, winner);
+
+  return program.methods.pushPrizeToken(raffleIdx, slot, hexStringToNumArray(winnerUid))
+    .accounts({
+      payer: payer.publicKey,
+      authority: winner,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      userAccount: userPda,
+      creator: raffleAccount.authority,
+      mint,
+      prizeEscrow,
+      winnerTokenAccount: winnerAta.address,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([payer])
+    .rpc();
+}
+
+export const pushPrizeSol = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: PublicKey,
+  payer: Keypair,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const userPda = findUserPda(winnerUid, rafflePda);
+  const prizeVault = findPrizeEscrowPda(rafflePda, slot);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+
+  return program.methods.pushPrizeSol(raffleIdx, slot, hexStringToNumArray(winnerUid))
+    .accounts({
+      payer: payer.publicKey,
+      authority: winner,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      userAccount: userPda,
+      prizeVault,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([payer])
+    .rpc();
+}
+
+export const pushPrize2022 = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: PublicKey,
+  payer: Keypair,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const userPda = findUserPda(winnerUid, rafflePda);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+  const mint = raffleAccount.prizes[slot].mint;
+  const winnerAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, mint, winner, false, undefined, undefined, TOKEN_2022_PROGRAM_ID);
+
+  return program.methods.pushPrize2022(raffleIdx, slot, hexStringToNumArray(winnerUid))
+    .accounts({
+      payer: payer.publicKey,
+      authority: winner,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      userAccount: userPda,
+      creator: raffleAccount.authority,
+      mint,
+      prizeEscrow: findPrizeEscrowPda(rafflePda, slot),
+      winnerTokenAccount: winnerAta.address,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([payer])
+    .rpc();
+}
+
+export const pushPrizeCore = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: PublicKey,
+  payer: Keypair,
+  collection: PublicKey,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const userPda = findUserPda(winnerUid, rafflePda);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+
+  return program.methods.pushPrizeCore(raffleIdx, slot, hexStringToNumArray(winnerUid))
+    .accounts({
+      payer: payer.publicKey,
+      authority: winner,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      userAccount: userPda,
+      asset: raffleAccount.prizes[slot].mint,
+      collection,
+      escrowAccount: findEscrowPda(rafflePda),
+      coreProgram: MPL_CORE_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([payer])
+    .rpc();
+}
+
+export const pushPrizeCnft = async (
+  program: anchor.Program<SolanaRaffle>,
+  raffleIdx: number,
+  winnerUid: string,
+  winner: PublicKey,
+  payer: Keypair,
+  leaf: CnftLeaf,
+  slot: number = 0,
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+  const userPda = findUserPda(winnerUid, rafflePda);
+
+  const raffleAccount = await program.account.raffleAccountV2.fetch(rafflePda);
+  const { shard } = locateTicket(raffleAccount, raffleAccount.prizes[slot].winnerIdx - 1);
+
+  return program.methods.pushPrizeCnft(raffleIdx, slot, hexStringToNumArray(winnerUid), leaf.root)
+    .accounts({
+      payer: payer.publicKey,
+      authority: winner,
+      raffleAccount: rafflePda,
+      ticketShard: findShardPda(raffleIdx, shard),
+      userAccount: userPda,
+      creator: raffleAccount.authority,
+      compressedPrize: findPrizeEscrowPda(rafflePda, slot),
+      escrowAccount: findEscrowPda(rafflePda),
+      treeAuthority: findTreeAuthorityPda(leaf.merkleTree),
+      merkleTree: leaf.merkleTree,
+      logWrapper: NOOP_PROGRAM_ID,
+      compressionProgram: COMPRESSION_PROGRAM_ID,
+      bubblegumProgram: BUBBLEGUM_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .remainingAccounts(proofAccounts(leaf.proof))
+    .signers([payer])
+    .rpc();
+}
+
+export const updateFees = async (
+  program: anchor.Program<SolanaRaffle>,
+  authority: Keypair,
+  feePercent: number,
+  maxTicketPercent: number,
+  referralPercent: number = 0,
+  crankRewardPercent: number = 0,
+) => {
+  const globalPda = findGlobalPda();
+
+  return program.methods.updateFees(feePercent, maxTicketPercent, referralPercent, crankRewardPercent)
+    .accounts({
+      authority: authority.publicKey,
+      globalAccount: globalPda,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([authority])
+    .rpc();
+}
+
+// Non-empty split is created as the next global version
+export const setFeeConfig = async (
+  program: anchor.Program<SolanaRaffle>,
+  authority: Keypair,
+  recipients: { wallet: PublicKey, share: number }[],
+) => {
+  const globalPda = findGlobalPda();
+  const globalAccount = await program.account.globalAccount.fetch(globalPda);
+
+  return program.methods.setFeeConfig(recipients)
+    .accounts({
+      authority: authority.publicKey,
+      globalAccount: globalPda,
+      feeConfig: recipients.length ? findGlobalFeeConfigPda(globalAccount.feeConfigVersion + 1) : null,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([authority])
+    .rpc();
+}
+
+// Fees of every shard must be swept first
+export const setRaffleFeeConfig = async (
+  program: anchor.Program<SolanaRaffle>,
+  authority: Keypair,
+  raffleIdx: number,
+  recipients: { wallet: PublicKey, share: number }[],
+) => {
+  const rafflePda = findRafflePda(new anchor.BN(raffleIdx));
+
+  return program.methods.setRaffleFeeConfig(raffleIdx, recipients)
+    .accounts({
+      authority: authority.publicKey,
+      globalAccount: findGlobalPda(),
+      raffleAccount: rafflePda,
+      feeConfig: findFeeConfigPda(rafflePda),
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .remainingAccounts(await fetchShardAccounts(program, raffleIdx))
+    .signers([authority])
+    .rpc();
+}
+
+export const initReferral = async (
+  program: anchor.Program<SolanaRaffle>,
+  referrer: Keypair,
+  splMint: PublicKey,
+) => {
+  const referralPda = findReferralPda(referrer.publicKey, splMint);
+
+  return program.methods.initReferral()
+    .accounts({
+      referrer: referrer.publicKey,
+      splMint,
+      referralAccount: referralPda,
+      referralEscrow: findEscrowPda(referralPda),
+      tokenProgram: await findTokenProgramId(program.provider.connection, splMint),
+      systemProgram: anchor.web3.SystemProgram.programId,
+      rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([referrer])
+    .rpc();
+}
+
+export const claimReferral = async (
+  program: anchor.Program<SolanaRaffle>,
+  referrer: Keypair,
+  splMint: PublicKey,
+) => {
+  const referralPda = findReferralPda(referrer.publicKey, splMint);
+  const isNative = splMint.equals(NATIVE_MINT);
+  const tokenProgram = await findTokenProgramId(program.provider.connection, splMint);
+
+  return program.methods.claimReferral()
+    .accounts({
+      referrer: referrer.publicKey,
+      referralAccount: referralPda,
+      splMint,
+      referralEscrow: findEscrowPda(referralPda),
+      referrerTokenAccount: isNative ? referrer.publicKey : getAssociatedTokenAddressSync(splMint, referrer.publicKey, false, tokenProgram),
+      tokenProgram,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([referrer])
     .rpc();
 }
 
 export const addWhitelist = async (
   program: anchor.Program<SolanaRaffle>,
   authority: Keypair,
-  collection: PublicKey,
+  key: PublicKey,
+  isCreator: boolean = false,
 ) => {
   const globalPda = findGlobalPda();
+  const kind = isCreator ? { creator: {} } : { collection: {} };
 
-  return program.methods.addWhitelist(collection)
+  return program.methods.addWhitelist(key, kind)
     .accounts({
       authority: authority.publicKey,
       globalAccount: globalPda,
+      whitelistEntry: findWhitelistPda(key),
       systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
     })
     .signers([authority])
     .rpc();
 }
 
 export const removeWhitelist = async (
+  program: anchor.Program<SolanaRaffle>,
+  authority: Keypair,
+  key: PublicKey,
+) => {
+  const globalPda = findGlobalPda();
+
+  return program.methods.removeWhitelist(key)
+    .accounts({
+      authority: authority.publicKey,
+      globalAccount: globalPda,
+      whitelistEntry: findWhitelistPda(key),
+      systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
+    })
+    .signers([authority])
+    .rpc();
+}
+
+export const migrateWhitelist = async (
   program: anchor.Program<SolanaRaffle>,
   authority: Keypair,
   collection: PublicKey,
 ) => {
   const globalPda = findGlobalPda();
 
-  return program.methods.removeWhitelist(collection)
+  return program.methods.migrateWhitelist(collection)
     .accounts({
       authority: authority.publicKey,
       globalAccount: globalPda,
+      whitelistEntry: findWhitelistPda(collection),
       systemProgram: anchor.web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityPda(),
+      program: program.programId,
     })
     .signers([authority])
     .rpc();
@@ -462,4 +1965,78 @@ export const transferNft = async (
     authority: owner,
     toOwner: receiver,
   })
-}
\ No newline at end of file
+}
+
+export const mintNft2022 = async (
+  connection: Connection,
+  wallet: Keypair,
+): Promise<PublicKey> => {
+  const mplex = new Metaplex(connection)
+    .use(keypairIdentity(wallet));
+
+  // Creator is verified by identity, mint and ata live under Token-2022
+  const nft = await mplex.nfts()
+    .create({
+      name: "Test",
+      symbol: "TEST",
+      uri: "",
+      sellerFeeBasisPoints: 0,
+    }, {
+      programs: [{ name: "TokenProgram", address: TOKEN_2022_PROGRAM_ID }],
+    });
+  return nft.mintAddress;
+}
+
+const borshString = (value: string): Buffer => {
+  const len = Buffer.alloc(4);
+  len.writeUInt32LE(value.length);
+  return Buffer.concat([len, Buffer.from(value)]);
+}
+
+export const createCoreCollection = async (
+  connection: Connection,
+  wallet: Keypair,
+): Promise<PublicKey> => {
+  const collection = Keypair.generate();
+
+  // CreateCollectionV1 { name, uri, plugins: None }
+  const ix = new anchor.web3.TransactionInstruction({
+    programId: MPL_CORE_PROGRAM_ID,
+    keys: [
+      { pubkey: collection.publicKey, isSigner: true, isWritable: true },
+      { pubkey: MPL_CORE_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    data: Buffer.concat([Buffer.from([1]), borshString("Test"), borshString(""), Buffer.from([0])]),
+  });
+  await anchor.web3.sendAndConfirmTransaction(connection, new Transaction().add(ix), [wallet, collection]);
+  return collection.publicKey;
+}
+
+export const mintCoreAsset = async (
+  connection: Connection,
+  wallet: Keypair,
+  owner: PublicKey,
+  collection: PublicKey,
+): Promise<PublicKey> => {
+  const asset = Keypair.generate();
+
+  // CreateV1 { data_state: AccountState, name, uri, plugins: None }
+  const ix = new anchor.web3.TransactionInstruction({
+    programId: MPL_CORE_PROGRAM_ID,
+    keys: [
+      { pubkey: asset.publicKey, isSigner: true, isWritable: true },
+      { pubkey: collection, isSigner: false, isWritable: true },
+      { pubkey: wallet.publicKey, isSigner: true, isWritable: false },
+      { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+      { pubkey: owner, isSigner: false, isWritable: false },
+      { pubkey: MPL_CORE_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: MPL_CORE_PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    data: Buffer.concat([Buffer.from([0, 0]), borshString("Test"), borshString(""), Buffer.from([0])]),
+  });
+  await anchor.web3.sendAndConfirmTransaction(connection, new Transaction().add(ix), [wallet, asset]);
+  return asset.publicKey;
+}
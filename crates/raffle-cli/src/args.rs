@@ -0,0 +1,113 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, Parser)]
+#[command(name = "raffle-cli", about = "Admin CLI of the solana-raffle program")]
+pub struct Args {
+    /// JSON-RPC endpoint
+    #[arg(
+        long,
+        global = true,
+        env = "RAFFLE_RPC_URL",
+        default_value = "http://127.0.0.1:8899"
+    )]
+    pub url: String,
+
+    /// Signer keypair file [default: ~/.config/solana/id.json]
+    #[arg(long, global = true)]
+    pub keypair: Option<PathBuf>,
+
+    /// Seconds before an RPC request times out
+    #[arg(long, global = true, default_value_t = 30)]
+    pub timeout: u64,
+
+    /// Print JSON output
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// Simulate transactions without sending them
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Create the global account
+    Initialize {
+        #[arg(long)]
+        vault: Pubkey,
+        #[arg(long)]
+        permit_signer: Pubkey,
+    },
+    /// Update global settings, the ones not given keep their current value
+    UpdateSetting {
+        #[arg(long)]
+        new_authority: Option<Pubkey>,
+        #[arg(long)]
+        vault: Option<Pubkey>,
+        #[arg(long)]
+        permit_signer: Option<Pubkey>,
+    },
+    /// Whitelist a collection, or a creator with --creator
+    AddWhitelist {
+        key: Pubkey,
+        #[arg(long)]
+        creator: bool,
+    },
+    /// Disable a whitelisted collection or creator
+    RemoveWhitelist { key: Pubkey },
+    /// Create a raffle at the next index along with its ticket shards
+    CreateRaffle {
+        #[arg(long)]
+        mint: Pubkey,
+        #[arg(long)]
+        supply: u32,
+        #[arg(long)]
+        price: u64,
+        /// Unix timestamp
+        #[arg(long)]
+        start: u64,
+        /// Unix timestamp
+        #[arg(long)]
+        end: u64,
+        #[arg(long, default_value_t = 1)]
+        prizes: u8,
+        #[arg(long, default_value_t = 0)]
+        min_sales: u32,
+        #[arg(long, default_value_t = 1)]
+        shards: u8,
+        #[arg(long)]
+        public: bool,
+        /// Wallet cap in basis points, 0 for no cap, the global one when omitted
+        #[arg(long)]
+        max_ticket_percent: Option<u16>,
+    },
+    /// Deposit an NFT or pNFT prize, into the first empty slot by default
+    DepositPrize {
+        idx: u32,
+        mint: Pubkey,
+        #[arg(long)]
+        slot: Option<u8>,
+    },
+    /// Commit to a slot hash and settle winners
+    Reveal { idx: u32 },
+    /// Sweep shard fees and withdraw proceeds of a revealed raffle
+    Withdraw { idx: u32 },
+    /// Show the global account
+    Global,
+    /// Show a raffle
+    Raffle { idx: u32 },
+    /// Show a user by hex uid, or by wallet in public raffles
+    User {
+        idx: u32,
+        #[arg(required_unless_present = "wallet")]
+        uid: Option<String>,
+        #[arg(long, conflicts_with = "uid")]
+        wallet: Option<Pubkey>,
+    },
+}
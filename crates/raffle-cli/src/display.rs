@@ -0,0 +1,156 @@
+use std::io::{self, Write};
+
+use anchor_lang::prelude::Pubkey;
+use serde_json::{json, Value};
+use solana_raffle::states::{GlobalAccount, RaffleAccountV2, UserAccount};
+use solana_raffle_client::rpc::Simulation;
+
+pub fn global_json(address: &Pubkey, global: &GlobalAccount) -> Value {
+    let legacy_whitelist: Vec<String> = global
+        .wl_collections
+        .iter()
+        .filter(|collection| **collection != Pubkey::default())
+        .map(Pubkey::to_string)
+        .collect();
+
+    json!({
+        "address": address.to_string(),
+        "authority": global.authority.to_string(),
+        "vault": global.vault.to_string(),
+        "permitSigner": global.permit_signer.to_string(),
+        "totalRaffles": global.total_raffles,
+        "feePercent": global.platform_fee_percent(),
+        "maxTicketPercent": global.default_max_ticket_percent(),
+        "referralPercent": global.referral_percent,
+        "crankRewardPercent": global.crank_reward_percent,
+        "hasFeeConfig": global.has_fee_config != 0,
+        "feeConfigVersion": global.fee_config_version,
+        "legacyWhitelist": legacy_whitelist,
+    })
+}
+
+pub fn raffle_json(address: &Pubkey, raffle: &RaffleAccountV2) -> Value {
+    let prizes: Vec<Value> = raffle
+        .prizes()
+        .iter()
+        .enumerate()
+        .map(|(slot, prize)| {
+            json!({
+                "slot": slot,
+                "kind": format!("{:?}", prize.kind()),
+                "mint": prize.mint.to_string(),
+                "amount": prize.amount,
+                "winnerIdx": prize.winner_idx,
+                "isDeposited": prize.is_deposited != 0,
+                "isClaimed": prize.is_claimed != 0,
+            })
+        })
+        .collect();
+
+    json!({
+        "address": address.to_string(),
+        "idx": raffle.idx,
+        "authority": raffle.authority.to_string(),
+        "splMint": raffle.spl_mint.to_string(),
+        "price": raffle.price,
+        "startDate": raffle.start_date,
+        "endDate": raffle.end_date,
+        "totalSupply": raffle.total_supply,
+        "totalSales": raffle.total_sales,
+        "minSales": raffle.min_sales,
+        "feePercent": raffle.fee_percent,
+        "maxTicketPercent": raffle.max_ticket_percent,
+        "referralPercent": raffle.referral_percent,
+        "crankRewardPercent": raffle.crank_reward_percent,
+        "totalShards": raffle.total_shards,
+        "createdShards": raffle.created_shards,
+        "shardSales": &raffle.shard_sales[..raffle.total_shards as usize],
+        "isPublic": raffle.is_public != 0,
+        "isDeposited": raffle.is_deposited != 0,
+        "isRevealed": raffle.is_revealed != 0,
+        "isWithdrawn": raffle.is_withdrawn != 0,
+        "isCancelled": raffle.is_cancelled != 0,
+        "hasFeeConfig": raffle.has_fee_config != 0,
+        "feeConfigVersion": raffle.fee_config_version,
+        "randomness": {
+            "source": format!("{:?}", raffle.randomness.source()),
+            "slot": raffle.randomness.slot,
+            "timestamp": raffle.randomness.timestamp,
+            "request": raffle.randomness.request.to_string(),
+            "attempt": raffle.randomness.attempt,
+        },
+        "prizes": prizes,
+    })
+}
+
+pub fn user_json(address: &Pubkey, user: &UserAccount) -> Value {
+    json!({
+        "address": address.to_string(),
+        "raffleIdx": user.raffle_idx,
+        "authority": user.authority.to_string(),
+        "uid": hex(&user.uid),
+        "amount": user.amount,
+        "deposited": user.deposited,
+        "permitNonce": user.permit_nonce,
+    })
+}
+
+pub fn simulation_json(simulation: &Simulation) -> Value {
+    json!({
+        "err": simulation.err,
+        "unitsConsumed": simulation.units_consumed,
+        "logs": simulation.logs,
+    })
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Print as JSON, or as indented `key value` lines
+pub fn print(out: &mut impl Write, value: &Value, json: bool) -> io::Result<()> {
+    if json {
+        writeln!(
+            out,
+            "{}",
+            serde_json::to_string_pretty(value).unwrap_or_default()
+        )
+    } else {
+        print_pretty(out, value, 0)
+    }
+}
+
+fn print_pretty(out: &mut impl Write, value: &Value, indent: usize) -> io::Result<()> {
+    let Some(fields) = value.as_object() else {
+        return writeln!(out, "{:indent$}{}", "", scalar(value));
+    };
+    let width = fields.keys().map(String::len).max().unwrap_or_default();
+
+    for (key, field) in fields {
+        match field {
+            Value::Object(_) => {
+                writeln!(out, "{:indent$}{key}:", "")?;
+                print_pretty(out, field, indent + 2)?;
+            }
+            Value::Array(items) if !items.is_empty() && items.iter().all(Value::is_object) => {
+                writeln!(out, "{:indent$}{key}:", "")?;
+                for (pos, item) in items.iter().enumerate() {
+                    writeln!(out, "{:indent$}  [{pos}]", "")?;
+                    print_pretty(out, item, indent + 4)?;
+                }
+            }
+            _ => writeln!(out, "{:indent$}{key:<width$}  {}", "", scalar(field))?,
+        }
+    }
+
+    Ok(())
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(value) => value.clone(),
+        Value::Array(items) => items.iter().map(scalar).collect::<Vec<_>>().join(", "),
+        _ => value.to_string(),
+    }
+}
@@ -0,0 +1,842 @@
+//! Admin CLI of the solana-raffle program.
+
+mod args;
+mod display;
+
+use std::{
+    cell::RefCell,
+    env,
+    io::{self, Write},
+    path::PathBuf,
+    thread,
+    time::Duration,
+};
+
+use anchor_lang::solana_program::instruction::Instruction;
+use anyhow::{anyhow, bail, Context as _, Result};
+use clap::Parser;
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount, TokenStandard};
+use serde_json::{json, Value};
+use solana_raffle::{
+    constants::ID_LENGTH,
+    states::{RandomnessSource, WhitelistKind},
+    utils::wallet_uid,
+};
+use solana_raffle_client::{
+    accounts, instructions,
+    pda::*,
+    rpc::{
+        fetch_fee_config, fetch_global, fetch_prize_whitelist_key, fetch_raffle, fetch_user,
+        ClientRpc, Rpc, RpcError,
+    },
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    transaction::Transaction,
+};
+
+use crate::args::{Args, Command};
+
+/// Shards created per transaction after the raffle itself
+const SHARDS_PER_TX: usize = 6;
+const SLOT_POLL_INTERVAL: Duration = Duration::from_millis(400);
+
+/// Command context, generic over cluster access and output for tests
+struct Cli<R: Rpc, W: Write> {
+    rpc: R,
+    keypair_path: PathBuf,
+    json: bool,
+    dry_run: bool,
+    out: RefCell<W>,
+}
+
+impl<R: Rpc, W: Write> Cli<R, W> {
+    fn signer(&self) -> Result<Keypair> {
+        read_keypair_file(&self.keypair_path)
+            .map_err(|err| anyhow!("{err}"))
+            .with_context(|| format!("failed to read keypair {}", self.keypair_path.display()))
+    }
+
+    /// Sign and send, or only simulate in dry-run mode
+    fn submit(&self, signer: &Keypair, instructions: &[Instruction]) -> Result<()> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&signer.pubkey()),
+            &[signer],
+            blockhash,
+        );
+
+        if self.dry_run {
+            let simulation = self.rpc.simulate_transaction(&tx)?;
+            if self.json {
+                self.print(&display::simulation_json(&simulation))?;
+            } else {
+                for log in &simulation.logs {
+                    writeln!(self.out.borrow_mut(), "{log}")?;
+                }
+            }
+            return match (&simulation.err, simulation.raffle_error()) {
+                (None, _) => Ok(()),
+                (Some(_), Some(error)) => bail!("simulation failed: {error:?}: {error}"),
+                (Some(err), None) => bail!("simulation failed: {err}"),
+            };
+        }
+
+        let signature = self.rpc.send_and_confirm(&tx).map_err(describe)?;
+        if self.json {
+            self.print(&json!({ "signature": signature.to_string() }))
+        } else {
+            writeln!(self.out.borrow_mut(), "{signature}")?;
+            Ok(())
+        }
+    }
+
+    /// Print as JSON with `--json`, as `key value` lines otherwise
+    fn print(&self, value: &Value) -> Result<()> {
+        display::print(&mut *self.out.borrow_mut(), value, self.json)?;
+        Ok(())
+    }
+
+    fn token_program(&self, mint: &Pubkey) -> Result<Pubkey> {
+        let account = self
+            .rpc
+            .get_account(mint)?
+            .ok_or_else(|| anyhow!("mint {mint} not found"))?;
+        Ok(account.owner)
+    }
+}
+
+/// Name the program error of a failed preflight
+fn describe(err: RpcError) -> anyhow::Error {
+    match err.raffle_error() {
+        Some(error) => anyhow!("{err} ({error:?}: {error})"),
+        None => err.into(),
+    }
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("error: {err:#}");
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let args = Args::parse();
+    let keypair_path = args.keypair.unwrap_or_else(|| {
+        PathBuf::from(env::var("HOME").unwrap_or_default()).join(".config/solana/id.json")
+    });
+    let cli = Cli {
+        rpc: ClientRpc::new(&args.url, Duration::from_secs(args.timeout)),
+        keypair_path,
+        json: args.json,
+        dry_run: args.dry_run,
+        out: RefCell::new(io::stdout()),
+    };
+
+    execute(&cli, args.command)
+}
+
+fn execute(cli: &Cli<impl Rpc, impl Write>, command: Command) -> Result<()> {
+    match command {
+        Command::Initialize {
+            vault,
+            permit_signer,
+        } => initialize(cli, &vault, &permit_signer),
+        Command::UpdateSetting {
+            new_authority,
+            vault,
+            permit_signer,
+        } => update_setting(cli, new_authority, vault, permit_signer),
+        Command::AddWhitelist { key, creator } => add_whitelist(cli, &key, creator),
+        Command::RemoveWhitelist { key } => remove_whitelist(cli, &key),
+        command @ Command::CreateRaffle { .. } => create_raffle(cli, command),
+        Command::DepositPrize { idx, mint, slot } => deposit_prize(cli, idx, &mint, slot),
+        Command::Reveal { idx } => reveal(cli, idx),
+        Command::Withdraw { idx } => withdraw(cli, idx),
+        Command::Global => show_global(cli),
+        Command::Raffle { idx } => show_raffle(cli, idx),
+        Command::User { idx, uid, wallet } => show_user(cli, idx, uid.as_deref(), wallet),
+    }
+}
+
+fn initialize(
+    cli: &Cli<impl Rpc, impl Write>,
+    vault: &Pubkey,
+    permit_signer: &Pubkey,
+) -> Result<()> {
+    let signer = cli.signer()?;
+    let ix = instructions::initialize(&signer.pubkey(), vault, permit_signer);
+    cli.submit(&signer, &[ix])
+}
+
+/// Settings not given keep their current value
+fn update_setting(
+    cli: &Cli<impl Rpc, impl Write>,
+    new_authority: Option<Pubkey>,
+    vault: Option<Pubkey>,
+    permit_signer: Option<Pubkey>,
+) -> Result<()> {
+    let signer = cli.signer()?;
+    let global = fetch_global(&cli.rpc)?;
+
+    let ix = instructions::update_setting(
+        &signer.pubkey(),
+        &new_authority.unwrap_or(global.authority),
+        &vault.unwrap_or(global.vault),
+        &permit_signer.unwrap_or(global.permit_signer),
+    );
+    cli.submit(&signer, &[ix])
+}
+
+fn add_whitelist(cli: &Cli<impl Rpc, impl Write>, key: &Pubkey, creator: bool) -> Result<()> {
+    let signer = cli.signer()?;
+    let kind = if creator {
+        WhitelistKind::Creator
+    } else {
+        WhitelistKind::Collection
+    };
+
+    let ix = instructions::add_whitelist(&signer.pubkey(), key, kind);
+    cli.submit(&signer, &[ix])
+}
+
+fn remove_whitelist(cli: &Cli<impl Rpc, impl Write>, key: &Pubkey) -> Result<()> {
+    let signer = cli.signer()?;
+    let ix = instructions::remove_whitelist(&signer.pubkey(), key);
+    cli.submit(&signer, &[ix])
+}
+
+/// Raffle takes the next index, its shards are created right after
+fn create_raffle(cli: &Cli<impl Rpc, impl Write>, command: Command) -> Result<()> {
+    let Command::CreateRaffle {
+        mint,
+        supply,
+        price,
+        start,
+        end,
+        prizes,
+        min_sales,
+        shards: total_shards,
+        public,
+        max_ticket_percent,
+    } = command
+    else {
+        unreachable!("create_raffle called with {command:?}");
+    };
+    let signer = cli.signer()?;
+    let idx = fetch_global(&cli.rpc)?.total_raffles;
+
+    let mut batches = vec![vec![instructions::create_raffle(
+        &signer.pubkey(),
+        &mint,
+        idx,
+        supply,
+        prizes,
+        price,
+        start,
+        end,
+        min_sales,
+        total_shards,
+        public,
+        max_ticket_percent,
+    )]];
+    let shards: Vec<Instruction> = (0..total_shards)
+        .map(|shard| instructions::create_shard(&signer.pubkey(), idx, shard))
+        .collect();
+    batches.extend(shards.chunks(SHARDS_PER_TX).map(<[Instruction]>::to_vec));
+
+    // Shards need the raffle account, so only the first batch can be simulated
+    if cli.dry_run {
+        return cli.submit(&signer, &batches[0]);
+    }
+    for batch in &batches {
+        cli.submit(&signer, batch)?;
+    }
+    if !cli.json {
+        writeln!(cli.out.borrow_mut(), "created raffle {idx}")?;
+    }
+
+    Ok(())
+}
+
+/// Deposit NFT or pNFT, detected from token standard of its metadata
+fn deposit_prize(
+    cli: &Cli<impl Rpc, impl Write>,
+    idx: u32,
+    mint: &Pubkey,
+    slot: Option<u8>,
+) -> Result<()> {
+    let signer = cli.signer()?;
+
+    let raffle = fetch_raffle(&cli.rpc, idx)?;
+    let slot = match slot {
+        Some(slot) => slot,
+        None => raffle
+            .prizes()
+            .iter()
+            .position(|prize| prize.is_deposited == 0)
+            .ok_or_else(|| anyhow!("every prize of raffle {idx} is deposited"))?
+            as u8,
+    };
+
+    let metadata_key = find_metadata_pda(mint);
+    let metadata_data = cli
+        .rpc
+        .get_account_data(&metadata_key)?
+        .ok_or_else(|| anyhow!("metadata {metadata_key} not found"))?;
+    let metadata = Metadata::safe_deserialize(&metadata_data)
+        .map_err(|err| anyhow!("invalid metadata of {mint}: {err}"))?;
+    let candidates = accounts::prize_whitelist_candidates(&metadata_data)?;
+    let whitelist_key = fetch_prize_whitelist_key(&cli.rpc, &candidates)?
+        .ok_or_else(|| anyhow!("collection or creator of {mint} is not whitelisted"))?;
+
+    let ix = match metadata.token_standard {
+        Some(TokenStandard::ProgrammableNonFungible) => instructions::add_prize_pnft(
+            &signer.pubkey(),
+            idx,
+            slot,
+            mint,
+            &whitelist_key,
+            accounts::rule_set(&metadata_data)?,
+        ),
+        _ => instructions::add_prize(&signer.pubkey(), idx, slot, mint, &whitelist_key),
+    };
+    cli.submit(&signer, &[ix])
+}
+
+/// Commit to a slot hash, wait for the slot and settle winners
+fn reveal(cli: &Cli<impl Rpc, impl Write>, idx: u32) -> Result<()> {
+    let signer = cli.signer()?;
+    let raffle = fetch_raffle(&cli.rpc, idx)?;
+
+    let request = instructions::request_randomness(
+        &signer.pubkey(),
+        idx,
+        RandomnessSource::SlotHash,
+        None,
+        raffle.randomness.oracle_request(),
+        &raffle.spl_mint,
+        &cli.token_program(&raffle.spl_mint)?,
+        raffle.total_shards,
+    );
+    cli.submit(&signer, &[request])?;
+    if cli.dry_run {
+        return Ok(());
+    }
+
+    let committed = fetch_raffle(&cli.rpc, idx)?.randomness.slot;
+    while cli.rpc.get_slot()? <= committed {
+        thread::sleep(SLOT_POLL_INTERVAL);
+    }
+
+    cli.submit(
+        &signer,
+        &[instructions::settle_randomness(&signer.pubkey(), idx, None)],
+    )
+}
+
+/// Sweep fees held by shard escrows, then withdraw proceeds
+fn withdraw(cli: &Cli<impl Rpc, impl Write>, idx: u32) -> Result<()> {
+    let signer = cli.signer()?;
+    let global = fetch_global(&cli.rpc)?;
+    let raffle = fetch_raffle(&cli.rpc, idx)?;
+    let token_program = cli.token_program(&raffle.spl_mint)?;
+
+    let fee_config = fetch_fee_config(&cli.rpc, &raffle)?;
+    for shard in 0..raffle.total_shards {
+        let key = find_shard_pda(idx, shard);
+        let data = cli
+            .rpc
+            .get_account_data(&key)?
+            .ok_or(RpcError::AccountNotFound(key))?;
+        if accounts::decode_ticket_shard(&data)?.0.fees == 0 {
+            continue;
+        }
+
+        let ix = instructions::sweep_fees(
+            &signer.pubkey(),
+            idx,
+            shard,
+            &raffle.spl_mint,
+            &token_program,
+            &global.vault,
+            fee_config.as_ref(),
+        );
+        cli.submit(&signer, &[ix])?;
+    }
+
+    let ix = instructions::withdraw_raffle(
+        &signer.pubkey(),
+        idx,
+        &raffle.spl_mint,
+        &token_program,
+        raffle.total_shards,
+    );
+    cli.submit(&signer, &[ix])
+}
+
+fn show_global(cli: &Cli<impl Rpc, impl Write>) -> Result<()> {
+    let global = fetch_global(&cli.rpc)?;
+    cli.print(&display::global_json(&find_global_pda(), &global))
+}
+
+fn show_raffle(cli: &Cli<impl Rpc, impl Write>, idx: u32) -> Result<()> {
+    let raffle = fetch_raffle(&cli.rpc, idx)?;
+    cli.print(&display::raffle_json(&find_raffle_pda(idx), &raffle))
+}
+
+/// User by hex uid, or by wallet in public raffles
+fn show_user(
+    cli: &Cli<impl Rpc, impl Write>,
+    idx: u32,
+    uid: Option<&str>,
+    wallet: Option<Pubkey>,
+) -> Result<()> {
+    let uid = match (wallet, uid) {
+        (Some(wallet), _) => wallet_uid(&wallet),
+        (None, Some(uid)) => parse_uid(uid)?,
+        (None, None) => bail!("missing <UID> or --wallet"),
+    };
+
+    let user = fetch_user(&cli.rpc, idx, &uid)?;
+    let address = find_user_pda(&find_raffle_pda(idx), &uid);
+    cli.print(&display::user_json(&address, &user))
+}
+
+fn parse_uid(hex: &str) -> Result<[u8; ID_LENGTH]> {
+    if hex.len() != ID_LENGTH * 2 || !hex.is_ascii() {
+        bail!("uid must be {} hex characters", ID_LENGTH * 2);
+    }
+
+    let mut uid = [0u8; ID_LENGTH];
+    for (pos, byte) in uid.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[pos * 2..pos * 2 + 2], 16)
+            .with_context(|| format!("invalid uid `{hex}`"))?;
+    }
+    Ok(uid)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use anchor_lang::{
+        solana_program::{hash::Hash, program_pack::Pack},
+        AccountSerialize,
+    };
+    use anchor_spl::token::spl_token;
+    use solana_raffle::states::GlobalAccount;
+    use solana_raffle_client::rpc::{self, Simulation};
+    use solana_sdk::{
+        account::Account,
+        signature::{write_keypair_file, Signature},
+    };
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Args {
+        Args::try_parse_from([&["raffle-cli"], args].concat()).unwrap()
+    }
+
+    #[test]
+    fn parse_global_flags_after_subcommand() {
+        let args = parse(&["global", "--json", "--dry-run", "--url", "http://rpc"]);
+
+        assert!(args.json && args.dry_run);
+        assert_eq!(args.url, "http://rpc");
+        assert!(matches!(args.command, Command::Global));
+    }
+
+    #[test]
+    fn parse_admin_commands() {
+        let (vault, signer) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let args = parse(&[
+            "initialize",
+            "--vault",
+            &vault.to_string(),
+            "--permit-signer",
+            &signer.to_string(),
+        ]);
+        assert!(matches!(
+            args.command,
+            Command::Initialize { vault: v, permit_signer: s } if v == vault && s == signer
+        ));
+
+        let args = parse(&["update-setting", "--vault", &vault.to_string()]);
+        assert!(matches!(
+            args.command,
+            Command::UpdateSetting {
+                new_authority: None,
+                vault: Some(v),
+                permit_signer: None,
+            } if v == vault
+        ));
+
+        let args = parse(&["add-whitelist", &vault.to_string(), "--creator"]);
+        assert!(matches!(
+            args.command,
+            Command::AddWhitelist { key, creator: true } if key == vault
+        ));
+
+        let args = parse(&["remove-whitelist", &vault.to_string()]);
+        assert!(matches!(args.command, Command::RemoveWhitelist { key } if key == vault));
+
+        assert!(Args::try_parse_from(["raffle-cli", "remove-whitelist", "not-a-key"]).is_err());
+    }
+
+    #[test]
+    fn parse_create_raffle_defaults() {
+        let mint = spl_token::native_mint::ID;
+        let args = parse(&[
+            "create-raffle",
+            "--mint",
+            &mint.to_string(),
+            "--supply",
+            "100",
+            "--price",
+            "1000",
+            "--start",
+            "10",
+            "--end",
+            "20",
+        ]);
+
+        let Command::CreateRaffle {
+            supply,
+            prizes,
+            min_sales,
+            shards,
+            public,
+            max_ticket_percent,
+            ..
+        } = args.command
+        else {
+            panic!("parsed {:?}", args.command);
+        };
+        assert_eq!((supply, prizes, min_sales, shards), (100, 1, 0, 1));
+        assert!(!public);
+        assert_eq!(max_ticket_percent, None);
+
+        // Start and end dates are required
+        assert!(Args::try_parse_from(["raffle-cli", "create-raffle", "--supply", "1"]).is_err());
+    }
+
+    #[test]
+    fn parse_raffle_commands() {
+        let mint = Pubkey::new_unique();
+        let args = parse(&["deposit-prize", "3", &mint.to_string(), "--slot", "1"]);
+        assert!(matches!(
+            args.command,
+            Command::DepositPrize { idx: 3, mint: m, slot: Some(1) } if m == mint
+        ));
+
+        assert!(matches!(
+            parse(&["reveal", "4"]).command,
+            Command::Reveal { idx: 4 }
+        ));
+        assert!(matches!(
+            parse(&["withdraw", "5"]).command,
+            Command::Withdraw { idx: 5 }
+        ));
+        assert!(matches!(
+            parse(&["raffle", "6"]).command,
+            Command::Raffle { idx: 6 }
+        ));
+    }
+
+    #[test]
+    fn parse_user_by_uid_or_wallet() {
+        let wallet = Pubkey::new_unique();
+        let args = parse(&["user", "1", "--wallet", &wallet.to_string()]);
+        assert!(matches!(
+            args.command,
+            Command::User { idx: 1, uid: None, wallet: Some(w) } if w == wallet
+        ));
+
+        let args = parse(&["user", "1", "00ff"]);
+        assert!(matches!(
+            args.command,
+            Command::User {
+                uid: Some(_),
+                wallet: None,
+                ..
+            }
+        ));
+
+        assert!(Args::try_parse_from(["raffle-cli", "user", "1"]).is_err());
+        let both = [
+            "raffle-cli",
+            "user",
+            "1",
+            "00ff",
+            "--wallet",
+            &wallet.to_string(),
+        ];
+        assert!(Args::try_parse_from(both).is_err());
+    }
+
+    #[test]
+    fn parse_uid_from_hex() {
+        let hex = "ab".repeat(ID_LENGTH);
+        assert_eq!(parse_uid(&hex).unwrap(), [0xab; ID_LENGTH]);
+
+        assert!(parse_uid("ab").is_err());
+        assert!(parse_uid(&"zz".repeat(ID_LENGTH)).is_err());
+    }
+
+    /// In-memory cluster recording sent transactions
+    #[derive(Default)]
+    struct MockRpc {
+        accounts: HashMap<Pubkey, Account>,
+        simulation: Simulation,
+        simulated: RefCell<Vec<Transaction>>,
+        sent: RefCell<Vec<Transaction>>,
+    }
+
+    impl MockRpc {
+        fn with_global(global: &GlobalAccount) -> Self {
+            let mut data = Vec::new();
+            global.try_serialize(&mut data).unwrap();
+
+            let mut rpc = Self::default();
+            rpc.insert(find_global_pda(), solana_raffle::ID, data);
+            rpc.insert(
+                spl_token::native_mint::ID,
+                spl_token::ID,
+                vec![0; spl_token::state::Mint::LEN],
+            );
+            rpc
+        }
+
+        fn insert(&mut self, key: Pubkey, owner: Pubkey, data: Vec<u8>) {
+            let account = Account {
+                lamports: 1,
+                data,
+                owner,
+                ..Account::default()
+            };
+            self.accounts.insert(key, account);
+        }
+    }
+
+    impl Rpc for MockRpc {
+        fn get_account(&self, key: &Pubkey) -> rpc::Result<Option<Account>> {
+            Ok(self.accounts.get(key).cloned())
+        }
+
+        fn get_program_accounts(
+            &self,
+            _program_id: &Pubkey,
+            _filters: &[(usize, Vec<u8>)],
+        ) -> rpc::Result<Vec<(Pubkey, Vec<u8>)>> {
+            Ok(vec![])
+        }
+
+        fn get_slot(&self) -> rpc::Result<u64> {
+            Ok(0)
+        }
+
+        fn get_latest_blockhash(&self) -> rpc::Result<Hash> {
+            Ok(Hash::default())
+        }
+
+        fn simulate_transaction(&self, tx: &Transaction) -> rpc::Result<Simulation> {
+            self.simulated.borrow_mut().push(tx.clone());
+            Ok(self.simulation.clone())
+        }
+
+        fn send_transaction(&self, tx: &Transaction) -> rpc::Result<Signature> {
+            self.sent.borrow_mut().push(tx.clone());
+            Ok(tx.signatures[0])
+        }
+
+        fn get_signature_status(
+            &self,
+            _signature: &Signature,
+        ) -> rpc::Result<Option<std::result::Result<(), String>>> {
+            Ok(Some(Ok(())))
+        }
+    }
+
+    fn global(authority: Pubkey) -> GlobalAccount {
+        GlobalAccount {
+            authority,
+            vault: Pubkey::new_unique(),
+            total_raffles: 2,
+            wl_collections: Default::default(),
+            permit_signer: Pubkey::new_unique(),
+            fee_percent: 100,
+            max_ticket_percent: 4000,
+            has_fee_config: 0,
+            referral_percent: 500,
+            crank_reward_percent: 0,
+            fees_initialized: 1,
+            fee_config_version: 0,
+            reserved2: [0; 2],
+            reserved3: 0,
+        }
+    }
+
+    /// Context signing with a fresh keypair written to a temporary file
+    fn context(rpc: MockRpc, json: bool, dry_run: bool) -> (Cli<MockRpc, Vec<u8>>, Keypair) {
+        let signer = Keypair::new();
+        let keypair_path = env::temp_dir().join(format!("raffle-cli-{}.json", signer.pubkey()));
+        write_keypair_file(&signer, &keypair_path).unwrap();
+
+        let cli = Cli {
+            rpc,
+            keypair_path,
+            json,
+            dry_run,
+            out: RefCell::new(Vec::new()),
+        };
+        (cli, signer)
+    }
+
+    fn output(cli: &Cli<MockRpc, Vec<u8>>) -> String {
+        String::from_utf8(cli.out.borrow().clone()).unwrap()
+    }
+
+    #[test]
+    fn dry_run_simulates_without_sending() {
+        let mut rpc = MockRpc::with_global(&global(Pubkey::new_unique()));
+        rpc.simulation = Simulation {
+            err: None,
+            logs: vec!["Program log: Instruction: AddWhitelist".to_string()],
+            units_consumed: Some(4200),
+        };
+        let (cli, signer) = context(rpc, true, true);
+        let key = Pubkey::new_unique();
+
+        execute(
+            &cli,
+            Command::AddWhitelist {
+                key,
+                creator: false,
+            },
+        )
+        .unwrap();
+
+        assert!(cli.rpc.sent.borrow().is_empty());
+        let simulated = cli.rpc.simulated.borrow();
+        assert_eq!(simulated.len(), 1);
+        assert_eq!(simulated[0].message.account_keys[0], signer.pubkey());
+        assert!(simulated[0]
+            .message
+            .account_keys
+            .contains(&find_whitelist_pda(&key)));
+
+        let printed: Value = serde_json::from_str(&output(&cli)).unwrap();
+        assert_eq!(printed["unitsConsumed"], 4200);
+        assert_eq!(printed["logs"][0], "Program log: Instruction: AddWhitelist");
+        assert!(printed["err"].is_null());
+    }
+
+    #[test]
+    fn dry_run_names_program_error() {
+        let mut rpc = MockRpc::with_global(&global(Pubkey::new_unique()));
+        rpc.simulation = Simulation {
+            err: Some("custom program error: 0x1777".to_string()),
+            logs: vec![
+                "Program log: AnchorError occurred. Error Code: RaffleExpired. Error Number: 6007. Error Message: Raffle expired.".to_string(),
+            ],
+            units_consumed: None,
+        };
+        let (cli, _) = context(rpc, false, true);
+
+        let err = execute(
+            &cli,
+            Command::RemoveWhitelist {
+                key: Pubkey::new_unique(),
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("RaffleExpired"), "{err}");
+        assert!(cli.rpc.sent.borrow().is_empty());
+
+        // Logs are printed before failing
+        assert!(output(&cli).contains("Error Code: RaffleExpired"));
+    }
+
+    #[test]
+    fn submit_prints_signature() {
+        let rpc = MockRpc::with_global(&global(Pubkey::new_unique()));
+        let (cli, _) = context(rpc, true, false);
+        let (vault, permit_signer) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        execute(
+            &cli,
+            Command::Initialize {
+                vault,
+                permit_signer,
+            },
+        )
+        .unwrap();
+
+        let sent = cli.rpc.sent.borrow();
+        assert_eq!(sent.len(), 1);
+        let printed: Value = serde_json::from_str(&output(&cli)).unwrap();
+        assert_eq!(printed["signature"], sent[0].signatures[0].to_string());
+    }
+
+    #[test]
+    fn update_setting_keeps_settings_not_given() {
+        let current = global(Pubkey::new_unique());
+        let rpc = MockRpc::with_global(&current);
+        let (cli, signer) = context(rpc, false, false);
+        let vault = Pubkey::new_unique();
+
+        execute(
+            &cli,
+            Command::UpdateSetting {
+                new_authority: None,
+                vault: Some(vault),
+                permit_signer: None,
+            },
+        )
+        .unwrap();
+
+        let sent = cli.rpc.sent.borrow();
+        let expected = instructions::update_setting(
+            &signer.pubkey(),
+            &current.authority,
+            &vault,
+            &current.permit_signer,
+        );
+        let ix = &sent[0].message.instructions[0];
+        assert_eq!(ix.data, expected.data);
+        let keys: Vec<_> = ix
+            .accounts
+            .iter()
+            .map(|pos| sent[0].message.account_keys[*pos as usize])
+            .collect();
+        let expected_keys: Vec<_> = expected.accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(keys, expected_keys);
+        assert_eq!(output(&cli).trim(), sent[0].signatures[0].to_string());
+    }
+
+    #[test]
+    fn show_global_as_json_or_lines() {
+        let current = global(Pubkey::new_unique());
+
+        let (cli, _) = context(MockRpc::with_global(&current), true, false);
+        execute(&cli, Command::Global).unwrap();
+        let printed: Value = serde_json::from_str(&output(&cli)).unwrap();
+        assert_eq!(printed["authority"], current.authority.to_string());
+        assert_eq!(printed["totalRaffles"], 2);
+        assert_eq!(printed["referralPercent"], 500);
+        assert_eq!(printed["legacyWhitelist"], json!([]));
+
+        let (cli, _) = context(MockRpc::with_global(&current), false, false);
+        execute(&cli, Command::Global).unwrap();
+        let printed = output(&cli);
+        assert!(printed
+            .lines()
+            .any(|line| line.starts_with("authority")
+                && line.ends_with(&current.authority.to_string())));
+        assert!(serde_json::from_str::<Value>(&printed).is_err());
+    }
+}
@@ -0,0 +1,1119 @@
+//! Keeper of the solana-raffle program: reveals winners of ended raffles and
+//! optionally pushes proceeds to their creators and prizes to their winners,
+//! over any [`Rpc`] implementation. Compressed nft prizes are left to their
+//! winners, their transfer needs a merkle proof from an indexer.
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use anchor_lang::{solana_program::instruction::Instruction, Discriminator};
+use anchor_spl::{
+    token::{self, spl_token::native_mint},
+    token_2022,
+};
+use log::{debug, info, warn};
+use solana_raffle::{
+    mpl_core::CoreAsset,
+    randomness::{is_oracle_timed_out, is_slot_hash_expired, oracle_randomness},
+    states::{GlobalAccount, PrizeKind, RaffleAccountV2, RandomnessSource},
+};
+use solana_raffle_client::{
+    accounts, instructions,
+    pda::{find_metadata_pda, find_shard_pda},
+    rpc::{fetch_fee_config, fetch_global, fetch_winner, Result, Rpc, RpcError},
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Push proceeds of revealed raffles to their creators
+    pub push_proceeds: bool,
+    /// Push prizes of revealed raffles to their winners
+    pub push_prizes: bool,
+    /// Delay after the first failure, doubled on every next one
+    pub base_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            push_proceeds: false,
+            push_prizes: false,
+            base_backoff: Duration::from_secs(5),
+            max_backoff: Duration::from_secs(600),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// Commit the draw to a future slot hash, again once the committed one expired
+    /// or the oracle timed out
+    RequestRandomness,
+    SettleRandomness,
+    /// Sweep shard fees, then push proceeds to the raffle creator
+    Withdraw,
+    /// Push unclaimed prizes to their winners, one transaction each
+    PushPrizes,
+}
+
+/// Chain state a raffle is planned against
+#[derive(Clone, Copy, Debug)]
+pub struct Snapshot {
+    pub now: u64,
+    pub slot: u64,
+    /// Sum of current shard sales, ahead of the raffle `total_sales`
+    pub sales: u32,
+    /// Global authority, the only one revealing sold out raffles early
+    pub authority: Pubkey,
+    /// Committed oracle request was answered
+    pub oracle_fulfilled: bool,
+}
+
+/// Next step of `raffle`, `None` while waiting or when nothing is left to do
+pub fn plan(
+    raffle: &RaffleAccountV2,
+    keeper: &Pubkey,
+    push_proceeds: bool,
+    push_prizes: bool,
+    snapshot: &Snapshot,
+) -> Option<Action> {
+    if raffle.is_cancelled != 0 || raffle.is_deposited == 0 {
+        return None;
+    }
+
+    if raffle.is_revealed != 0 {
+        if push_proceeds && raffle.is_withdrawn == 0 {
+            return Some(Action::Withdraw);
+        }
+        let pushable = push_prizes && !pushable_prizes(raffle).is_empty();
+        return pushable.then_some(Action::PushPrizes);
+    }
+
+    let randomness = &raffle.randomness;
+    match randomness.source() {
+        RandomnessSource::None => {
+            let early_reveal =
+                snapshot.sales >= raffle.total_supply && *keeper == snapshot.authority;
+            let ended = snapshot.now >= raffle.end_date || early_reveal;
+            (ended && snapshot.sales >= raffle.min_sales).then_some(Action::RequestRandomness)
+        }
+        RandomnessSource::SlotHash if is_slot_hash_expired(randomness, snapshot.slot) => {
+            Some(Action::RequestRandomness)
+        }
+        RandomnessSource::SlotHash => {
+            (snapshot.slot > randomness.slot).then_some(Action::SettleRandomness)
+        }
+        // Oracle which never fulfilled is replaced by a slot hash draw
+        RandomnessSource::Oracle if snapshot.oracle_fulfilled => Some(Action::SettleRandomness),
+        RandomnessSource::Oracle if is_oracle_timed_out(randomness, snapshot.slot) => {
+            Some(Action::RequestRandomness)
+        }
+        // Settlement fails until the oracle fulfilled, retried with backoff
+        RandomnessSource::Oracle => Some(Action::SettleRandomness),
+    }
+}
+
+/// Slots of won prizes left unclaimed, except compressed nfts
+fn pushable_prizes(raffle: &RaffleAccountV2) -> Vec<u8> {
+    raffle
+        .prizes()
+        .iter()
+        .enumerate()
+        .filter(|(_, prize)| {
+            prize.winner_idx > 0 && prize.is_claimed == 0 && prize.kind() != PrizeKind::Cnft
+        })
+        .map(|(slot, _)| slot as u8)
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Backoff {
+    failures: u32,
+    retry_at: Instant,
+}
+
+pub struct Keeper<R: Rpc> {
+    rpc: R,
+    signer: Keypair,
+    config: Config,
+    backoff: HashMap<u32, Backoff>,
+}
+
+impl<R: Rpc> Keeper<R> {
+    pub fn new(rpc: R, signer: Keypair, config: Config) -> Self {
+        Self {
+            rpc,
+            signer,
+            config,
+            backoff: HashMap::new(),
+        }
+    }
+
+    pub fn rpc(&self) -> &R {
+        &self.rpc
+    }
+
+    /// Every raffle of the program, matched by account discriminator
+    pub fn scan(&self) -> Result<Vec<RaffleAccountV2>> {
+        let raffles = self
+            .rpc
+            .get_program_accounts(
+                &solana_raffle::ID,
+                &[(0, RaffleAccountV2::discriminator().to_vec())],
+            )?
+            .iter()
+            .filter_map(|(key, data)| match accounts::decode_raffle(data) {
+                Ok(raffle) => Some(raffle),
+                Err(err) => {
+                    warn!("skipping raffle {key}: {err}");
+                    None
+                }
+            })
+            .collect();
+
+        Ok(raffles)
+    }
+
+    /// Current sales of `raffle`, summed over its ticket shards
+    pub fn sales(&self, raffle: &RaffleAccountV2) -> Result<u32> {
+        let mut sales = 0u32;
+        for shard in 0..raffle.total_shards {
+            let key = find_shard_pda(raffle.idx, shard);
+            let data = self
+                .rpc
+                .get_account_data(&key)?
+                .ok_or(RpcError::AccountNotFound(key))?;
+            sales = sales.saturating_add(accounts::decode_ticket_shard(&data)?.0.total_sales);
+        }
+
+        Ok(sales)
+    }
+
+    /// Check oracle request `key` was answered, missing requests are pending
+    pub fn oracle_fulfilled(&self, key: &Pubkey) -> Result<bool> {
+        let fulfilled = self
+            .rpc
+            .get_account_data(key)?
+            .and_then(|data| oracle_randomness(&data))
+            .is_some_and(|value| value.iter().any(|byte| *byte != 0));
+
+        Ok(fulfilled)
+    }
+
+    /// Run one pass over every raffle, returning the actions that landed
+    pub fn tick(&mut self) -> Result<Vec<(u32, Action, Signature)>> {
+        let clock = self.rpc.get_clock()?;
+        let slot = self.rpc.get_slot()?;
+        let global = fetch_global(&self.rpc)?;
+        let keeper = self.signer.pubkey();
+
+        let mut done = Vec::new();
+        for raffle in self.scan()? {
+            if self
+                .backoff
+                .get(&raffle.idx)
+                .is_some_and(|backoff| backoff.retry_at > Instant::now())
+            {
+                continue;
+            }
+
+            let sales = if raffle.is_revealed == 0
+                && raffle.randomness.source() == RandomnessSource::None
+            {
+                match self.sales(&raffle) {
+                    Ok(sales) => sales,
+                    Err(err) => {
+                        warn!("raffle {}: failed to load shards: {err}", raffle.idx);
+                        continue;
+                    }
+                }
+            } else {
+                raffle.total_sales
+            };
+            let oracle_fulfilled = match raffle.randomness.oracle_request() {
+                Some(request) => match self.oracle_fulfilled(&request) {
+                    Ok(fulfilled) => fulfilled,
+                    Err(err) => {
+                        warn!(
+                            "raffle {}: failed to load oracle request: {err}",
+                            raffle.idx
+                        );
+                        continue;
+                    }
+                },
+                None => false,
+            };
+            let snapshot = Snapshot {
+                now: clock.unix_timestamp as u64,
+                slot,
+                sales,
+                authority: global.authority,
+                oracle_fulfilled,
+            };
+
+            let Some(action) = plan(
+                &raffle,
+                &keeper,
+                self.config.push_proceeds,
+                self.config.push_prizes,
+                &snapshot,
+            ) else {
+                if raffle.is_revealed != 0 {
+                    let unclaimed = raffle
+                        .prizes()
+                        .iter()
+                        .filter(|prize| prize.is_claimed == 0)
+                        .count();
+                    if unclaimed > 0 {
+                        debug!(
+                            "raffle {}: {unclaimed} prizes waiting for winners",
+                            raffle.idx
+                        );
+                    }
+                }
+                continue;
+            };
+
+            match self.execute(&raffle, action, &global) {
+                Ok(signature) => {
+                    info!("raffle {}: {action:?} {signature}", raffle.idx);
+                    self.backoff.remove(&raffle.idx);
+                    done.push((raffle.idx, action, signature));
+                }
+                Err(err) => {
+                    let backoff = self.fail(raffle.idx);
+                    warn!(
+                        "raffle {}: {action:?} failed ({} in a row): {}",
+                        raffle.idx,
+                        backoff.failures,
+                        describe(&err)
+                    );
+                }
+            }
+        }
+
+        Ok(done)
+    }
+
+    /// Record a failure of raffle `idx`, delaying its next attempt exponentially
+    fn fail(&mut self, idx: u32) -> Backoff {
+        let failures = self.backoff.get(&idx).map_or(0, |backoff| backoff.failures) + 1;
+        let delay = self
+            .config
+            .base_backoff
+            .saturating_mul(1 << (failures - 1).min(16))
+            .min(self.config.max_backoff);
+
+        let backoff = Backoff {
+            failures,
+            retry_at: Instant::now() + delay,
+        };
+        self.backoff.insert(idx, backoff);
+        backoff
+    }
+
+    fn execute(
+        &self,
+        raffle: &RaffleAccountV2,
+        action: Action,
+        global: &GlobalAccount,
+    ) -> Result<Signature> {
+        let keeper = self.signer.pubkey();
+        let instructions = match action {
+            Action::RequestRandomness => {
+                // Crank reward of SPL raffles is paid to the keeper token account
+                let (token_program, mut instructions) = self.payment_account_instructions(
+                    raffle,
+                    (raffle.crank_reward_percent > 0).then_some(keeper),
+                )?;
+                instructions.push(instructions::request_randomness(
+                    &keeper,
+                    raffle.idx,
+                    RandomnessSource::SlotHash,
+                    None,
+                    raffle.randomness.oracle_request(),
+                    &raffle.spl_mint,
+                    &token_program,
+                    raffle.total_shards,
+                ));
+                instructions
+            }
+            Action::SettleRandomness => vec![instructions::settle_randomness(
+                &keeper,
+                raffle.idx,
+                raffle.randomness.oracle_request(),
+            )],
+            Action::Withdraw => {
+                let (token_program, mut instructions) =
+                    self.payment_account_instructions(raffle, Some(raffle.authority))?;
+                self.sweep_fees(raffle, global, &token_program)?;
+                instructions.push(instructions::push_proceeds(
+                    &keeper,
+                    &raffle.authority,
+                    raffle.idx,
+                    &raffle.spl_mint,
+                    &token_program,
+                    raffle.total_shards,
+                ));
+                instructions
+            }
+            Action::PushPrizes => {
+                let slots = pushable_prizes(raffle);
+                let (last, others) = slots
+                    .split_last()
+                    .expect("prizes are pushed while one is left");
+                for slot in others {
+                    let signature = self.send(&self.push_prize_instructions(raffle, *slot)?)?;
+                    debug!("raffle {}: pushed prize {slot} {signature}", raffle.idx);
+                }
+                self.push_prize_instructions(raffle, *last)?
+            }
+        };
+
+        self.send(&instructions)
+    }
+
+    /// Instructions pushing prize `slot` to its winner, after creating the
+    /// winner token account of token prizes, paid by the keeper
+    fn push_prize_instructions(
+        &self,
+        raffle: &RaffleAccountV2,
+        slot: u8,
+    ) -> Result<Vec<Instruction>> {
+        let keeper = self.signer.pubkey();
+        let prize = raffle.prizes()[slot as usize];
+        let (shard, user) = fetch_winner(&self.rpc, raffle, slot)?;
+        let (idx, winner, creator, mint) =
+            (raffle.idx, user.authority, raffle.authority, prize.mint);
+
+        let mut instructions = Vec::new();
+        let token_program = match prize.kind() {
+            PrizeKind::Nft | PrizeKind::Pnft | PrizeKind::Token => Some(token::ID),
+            PrizeKind::Nft2022 => Some(token_2022::ID),
+            PrizeKind::Sol | PrizeKind::Core | PrizeKind::Cnft => None,
+        };
+        if let Some(token_program) = token_program {
+            instructions.push(create_associated_token_account_idempotent(
+                &keeper,
+                &winner,
+                &mint,
+                &token_program,
+            ));
+        }
+
+        instructions.push(match prize.kind() {
+            PrizeKind::Nft => instructions::push_prize(
+                &keeper, &winner, idx, slot, user.uid, shard, &creator, &mint,
+            ),
+            PrizeKind::Pnft => {
+                let metadata = find_metadata_pda(&mint);
+                let data = self
+                    .rpc
+                    .get_account_data(&metadata)?
+                    .ok_or(RpcError::AccountNotFound(metadata))?;
+                instructions::push_prize_pnft(
+                    &keeper,
+                    &winner,
+                    idx,
+                    slot,
+                    user.uid,
+                    shard,
+                    &creator,
+                    &mint,
+                    accounts::rule_set(&data)?,
+                )
+            }
+            PrizeKind::Token => instructions::push_prize_token(
+                &keeper, &winner, idx, slot, user.uid, shard, &creator, &mint,
+            ),
+            PrizeKind::Sol => {
+                instructions::push_prize_sol(&keeper, &winner, idx, slot, user.uid, shard)
+            }
+            PrizeKind::Nft2022 => instructions::push_prize_2022(
+                &keeper, &winner, idx, slot, user.uid, shard, &creator, &mint,
+            ),
+            PrizeKind::Core => {
+                let data = self
+                    .rpc
+                    .get_account_data(&mint)?
+                    .ok_or(RpcError::AccountNotFound(mint))?;
+                instructions::push_prize_core(
+                    &keeper,
+                    &winner,
+                    idx,
+                    slot,
+                    user.uid,
+                    shard,
+                    &mint,
+                    CoreAsset::decode(&data)?.collection,
+                )
+            }
+            PrizeKind::Cnft => unreachable!("compressed prizes are not pushed"),
+        });
+
+        Ok(instructions)
+    }
+
+    /// Sweep fees held by shard escrows, one transaction per shard, as
+    /// proceeds can only be withdrawn once every shard was swept
+    fn sweep_fees(
+        &self,
+        raffle: &RaffleAccountV2,
+        global: &GlobalAccount,
+        token_program: &Pubkey,
+    ) -> Result<()> {
+        let fee_config = fetch_fee_config(&self.rpc, raffle)?;
+        for shard in 0..raffle.total_shards {
+            let key = find_shard_pda(raffle.idx, shard);
+            let data = self
+                .rpc
+                .get_account_data(&key)?
+                .ok_or(RpcError::AccountNotFound(key))?;
+            if accounts::decode_ticket_shard(&data)?.0.fees == 0 {
+                continue;
+            }
+
+            let signature = self.send(&[instructions::sweep_fees(
+                &self.signer.pubkey(),
+                raffle.idx,
+                shard,
+                &raffle.spl_mint,
+                token_program,
+                &global.vault,
+                fee_config.as_ref(),
+            )])?;
+            debug!(
+                "raffle {}: swept fees of shard {shard} {signature}",
+                raffle.idx
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Token program of the payment mint, and when `owner` is set the
+    /// instruction creating its token account of SPL mints, paid by the keeper
+    fn payment_account_instructions(
+        &self,
+        raffle: &RaffleAccountV2,
+        owner: Option<Pubkey>,
+    ) -> Result<(Pubkey, Vec<Instruction>)> {
+        let keeper = self.signer.pubkey();
+        let token_program = self
+            .rpc
+            .get_account(&raffle.spl_mint)?
+            .ok_or(RpcError::AccountNotFound(raffle.spl_mint))?
+            .owner;
+
+        let mut instructions = Vec::new();
+        if let Some(owner) = owner.filter(|_| raffle.spl_mint != native_mint::ID) {
+            instructions.push(create_associated_token_account_idempotent(
+                &keeper,
+                &owner,
+                &raffle.spl_mint,
+                &token_program,
+            ));
+        }
+
+        Ok((token_program, instructions))
+    }
+
+    fn send(&self, instructions: &[Instruction]) -> Result<Signature> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.signer.pubkey()),
+            &[&self.signer],
+            blockhash,
+        );
+        self.rpc.send_and_confirm(&tx)
+    }
+}
+
+/// Error with the raffle program error named when it raised one
+fn describe(err: &RpcError) -> String {
+    match err.raffle_error() {
+        Some(error) => format!("{err} ({error:?})"),
+        None => err.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use anchor_lang::{
+        solana_program::{clock::Clock, hash::Hash, slot_hashes::MAX_ENTRIES, sysvar},
+        AccountSerialize,
+    };
+    use anchor_spl::token::spl_token;
+    use bytemuck::Zeroable;
+    use solana_raffle::{
+        constants::{ID_LENGTH, ORACLE_TIMEOUT_SLOTS},
+        states::{GlobalAccount, RaffleTicket, TicketShard, UserAccount},
+    };
+    use solana_raffle_client::{
+        pda::{find_global_pda, find_raffle_pda, find_user_pda},
+        rpc::Simulation,
+    };
+    use solana_sdk::account::Account;
+
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000;
+    const SLOT: u64 = 100_000;
+
+    fn raffle(authority: Pubkey) -> RaffleAccountV2 {
+        let mut raffle = RaffleAccountV2::zeroed();
+        raffle.authority = authority;
+        raffle.spl_mint = native_mint::ID;
+        raffle.end_date = NOW - 1;
+        raffle.total_supply = 10;
+        raffle.min_sales = 2;
+        raffle.total_shards = 1;
+        raffle.created_shards = 1;
+        raffle.total_prizes = 1;
+        raffle.is_deposited = 1;
+        raffle
+    }
+
+    fn snapshot(sales: u32, authority: Pubkey) -> Snapshot {
+        Snapshot {
+            now: NOW,
+            slot: SLOT,
+            sales,
+            authority,
+            oracle_fulfilled: false,
+        }
+    }
+
+    #[test]
+    fn plan_waits_until_raffle_ended() {
+        let keeper = Pubkey::new_unique();
+        let mut raffle = raffle(keeper);
+        raffle.end_date = NOW + 1;
+
+        assert_eq!(
+            plan(&raffle, &keeper, false, false, &snapshot(5, keeper)),
+            None
+        );
+    }
+
+    #[test]
+    fn plan_reveals_sold_out_raffle_early_by_authority_only() {
+        let keeper = Pubkey::new_unique();
+        let mut raffle = raffle(keeper);
+        raffle.end_date = NOW + 1;
+
+        assert_eq!(
+            plan(&raffle, &keeper, false, false, &snapshot(10, keeper)),
+            Some(Action::RequestRandomness)
+        );
+        assert_eq!(
+            plan(
+                &raffle,
+                &keeper,
+                false,
+                false,
+                &snapshot(10, Pubkey::new_unique())
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn plan_skips_raffle_below_min_sales() {
+        let keeper = Pubkey::new_unique();
+        let raffle = raffle(keeper);
+
+        assert_eq!(
+            plan(&raffle, &keeper, false, false, &snapshot(1, keeper)),
+            None
+        );
+        assert_eq!(
+            plan(&raffle, &keeper, false, false, &snapshot(2, keeper)),
+            Some(Action::RequestRandomness)
+        );
+    }
+
+    #[test]
+    fn plan_skips_cancelled_and_undeposited_raffles() {
+        let keeper = Pubkey::new_unique();
+        let mut cancelled = raffle(keeper);
+        cancelled.is_cancelled = 1;
+        let mut undeposited = raffle(keeper);
+        undeposited.is_deposited = 0;
+
+        assert_eq!(
+            plan(&cancelled, &keeper, true, true, &snapshot(5, keeper)),
+            None
+        );
+        assert_eq!(
+            plan(&undeposited, &keeper, true, true, &snapshot(5, keeper)),
+            None
+        );
+    }
+
+    #[test]
+    fn plan_settles_slot_hash_and_requests_again_once_expired() {
+        let keeper = Pubkey::new_unique();
+        let mut raffle = raffle(keeper);
+        raffle.randomness.source = RandomnessSource::SlotHash as u8;
+
+        raffle.randomness.slot = SLOT;
+        assert_eq!(
+            plan(&raffle, &keeper, false, false, &snapshot(5, keeper)),
+            None
+        );
+
+        raffle.randomness.slot = SLOT - 1;
+        assert_eq!(
+            plan(&raffle, &keeper, false, false, &snapshot(5, keeper)),
+            Some(Action::SettleRandomness)
+        );
+
+        raffle.randomness.slot = SLOT - MAX_ENTRIES as u64 - 1;
+        assert_eq!(
+            plan(&raffle, &keeper, false, false, &snapshot(5, keeper)),
+            Some(Action::RequestRandomness)
+        );
+    }
+
+    #[test]
+    fn plan_settles_pending_oracle_until_timed_out() {
+        let keeper = Pubkey::new_unique();
+        let mut raffle = raffle(keeper);
+        raffle.randomness.source = RandomnessSource::Oracle as u8;
+
+        raffle.randomness.slot = SLOT - 1;
+        assert_eq!(
+            plan(&raffle, &keeper, false, false, &snapshot(5, keeper)),
+            Some(Action::SettleRandomness)
+        );
+
+        raffle.randomness.slot = SLOT - ORACLE_TIMEOUT_SLOTS - 1;
+        assert_eq!(
+            plan(&raffle, &keeper, false, false, &snapshot(5, keeper)),
+            Some(Action::RequestRandomness)
+        );
+
+        // Late answer is settled rather than replaced
+        let fulfilled = Snapshot {
+            oracle_fulfilled: true,
+            ..snapshot(5, keeper)
+        };
+        assert_eq!(
+            plan(&raffle, &keeper, false, false, &fulfilled),
+            Some(Action::SettleRandomness)
+        );
+    }
+
+    #[test]
+    fn plan_pushes_proceeds_of_revealed_raffle_only_when_enabled() {
+        let keeper = Pubkey::new_unique();
+        let mut raffle = raffle(keeper);
+        raffle.is_revealed = 1;
+
+        assert_eq!(
+            plan(&raffle, &keeper, true, false, &snapshot(5, keeper)),
+            Some(Action::Withdraw)
+        );
+        assert_eq!(
+            plan(&raffle, &keeper, false, false, &snapshot(5, keeper)),
+            None
+        );
+
+        // Proceeds of other creators are pushed to them as well
+        let other = Pubkey::new_unique();
+        assert_eq!(
+            plan(&raffle, &other, true, false, &snapshot(5, other)),
+            Some(Action::Withdraw)
+        );
+
+        raffle.is_withdrawn = 1;
+        assert_eq!(
+            plan(&raffle, &keeper, true, false, &snapshot(5, keeper)),
+            None
+        );
+    }
+
+    #[test]
+    fn plan_pushes_prizes_of_revealed_raffle_only_when_enabled() {
+        let keeper = Pubkey::new_unique();
+        let mut raffle = raffle(keeper);
+        raffle.is_revealed = 1;
+        raffle.prizes[0].kind = PrizeKind::Sol as u8;
+        raffle.prizes[0].winner_idx = 1;
+
+        assert_eq!(
+            plan(&raffle, &keeper, false, true, &snapshot(5, keeper)),
+            Some(Action::PushPrizes)
+        );
+        assert_eq!(
+            plan(&raffle, &keeper, false, false, &snapshot(5, keeper)),
+            None
+        );
+
+        // Proceeds are pushed first
+        assert_eq!(
+            plan(&raffle, &keeper, true, true, &snapshot(5, keeper)),
+            Some(Action::Withdraw)
+        );
+        raffle.is_withdrawn = 1;
+        assert_eq!(
+            plan(&raffle, &keeper, true, true, &snapshot(5, keeper)),
+            Some(Action::PushPrizes)
+        );
+
+        // Compressed nfts need a merkle proof, their winners claim them
+        raffle.prizes[0].kind = PrizeKind::Cnft as u8;
+        assert_eq!(
+            plan(&raffle, &keeper, true, true, &snapshot(5, keeper)),
+            None
+        );
+
+        raffle.prizes[0].kind = PrizeKind::Sol as u8;
+        raffle.prizes[0].is_claimed = 1;
+        assert_eq!(
+            plan(&raffle, &keeper, true, true, &snapshot(5, keeper)),
+            None
+        );
+    }
+
+    /// In-memory cluster recording sent transactions
+    #[derive(Default)]
+    struct MockRpc {
+        accounts: HashMap<Pubkey, Account>,
+        slot: u64,
+        fail_sends: Cell<bool>,
+        sent: RefCell<Vec<Transaction>>,
+    }
+
+    impl MockRpc {
+        fn new(keeper: Pubkey, raffles: &[(RaffleAccountV2, u32)]) -> Self {
+            let mut rpc = Self {
+                slot: SLOT,
+                ..Self::default()
+            };
+
+            let clock = Clock {
+                slot: SLOT,
+                unix_timestamp: NOW as i64,
+                ..Clock::default()
+            };
+            rpc.insert(
+                sysvar::clock::ID,
+                sysvar::ID,
+                bincode::serialize(&clock).unwrap(),
+            );
+            rpc.insert(native_mint::ID, spl_token::ID, vec![0; 82]);
+
+            let global = GlobalAccount {
+                authority: keeper,
+                vault: Pubkey::new_unique(),
+                total_raffles: raffles.len() as u32,
+                wl_collections: [Pubkey::default(); 10],
+                permit_signer: Pubkey::new_unique(),
+                fee_percent: 100,
+                max_ticket_percent: 4000,
+                has_fee_config: 0,
+                referral_percent: 0,
+                crank_reward_percent: 0,
+                fees_initialized: 1,
+                fee_config_version: 0,
+                reserved2: [0; 2],
+                reserved3: 0,
+            };
+            let mut data = Vec::new();
+            global.try_serialize(&mut data).unwrap();
+            rpc.insert(find_global_pda(), solana_raffle::ID, data);
+
+            for (raffle, sales) in raffles {
+                rpc.insert(
+                    find_raffle_pda(raffle.idx),
+                    solana_raffle::ID,
+                    zero_copy_data(raffle),
+                );
+
+                let mut shard = TicketShard::zeroed();
+                shard.raffle = find_raffle_pda(raffle.idx);
+                shard.total_sales = *sales;
+                rpc.insert(
+                    find_shard_pda(raffle.idx, 0),
+                    solana_raffle::ID,
+                    zero_copy_data(&shard),
+                );
+            }
+
+            rpc
+        }
+
+        fn insert(&mut self, key: Pubkey, owner: Pubkey, data: Vec<u8>) {
+            let account = Account {
+                lamports: 1,
+                data,
+                owner,
+                ..Account::default()
+            };
+            self.accounts.insert(key, account);
+        }
+    }
+
+    fn zero_copy_data<T: Discriminator + bytemuck::Pod>(account: &T) -> Vec<u8> {
+        [&T::discriminator()[..], bytemuck::bytes_of(account)].concat()
+    }
+
+    impl Rpc for MockRpc {
+        fn get_account(&self, key: &Pubkey) -> Result<Option<Account>> {
+            Ok(self.accounts.get(key).cloned())
+        }
+
+        fn get_program_accounts(
+            &self,
+            program_id: &Pubkey,
+            filters: &[(usize, Vec<u8>)],
+        ) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+            Ok(self
+                .accounts
+                .iter()
+                .filter(|(_, account)| account.owner == *program_id)
+                .filter(|(_, account)| {
+                    filters.iter().all(|(offset, bytes)| {
+                        account.data.get(*offset..offset + bytes.len()) == Some(&bytes[..])
+                    })
+                })
+                .map(|(key, account)| (*key, account.data.clone()))
+                .collect())
+        }
+
+        fn get_slot(&self) -> Result<u64> {
+            Ok(self.slot)
+        }
+
+        fn get_latest_blockhash(&self) -> Result<Hash> {
+            Ok(Hash::default())
+        }
+
+        fn simulate_transaction(&self, _tx: &Transaction) -> Result<Simulation> {
+            Ok(Simulation::default())
+        }
+
+        fn send_transaction(&self, tx: &Transaction) -> Result<Signature> {
+            if self.fail_sends.get() {
+                return Err(RpcError::Transaction {
+                    signature: tx.signatures[0],
+                    error: "custom program error".to_string(),
+                });
+            }
+
+            self.sent.borrow_mut().push(tx.clone());
+            Ok(tx.signatures[0])
+        }
+
+        fn get_signature_status(
+            &self,
+            _signature: &Signature,
+        ) -> Result<Option<std::result::Result<(), String>>> {
+            Ok(Some(Ok(())))
+        }
+    }
+
+    #[test]
+    fn tick_requests_randomness_of_ended_raffles() {
+        let signer = Keypair::new();
+        let keeper = signer.pubkey();
+
+        let ended = raffle(Pubkey::new_unique());
+        let mut live = raffle(Pubkey::new_unique());
+        live.idx = 1;
+        live.end_date = NOW + 60;
+        let rpc = MockRpc::new(keeper, &[(ended, 3), (live, 3)]);
+
+        let mut keeper = Keeper::new(rpc, signer, Config::default());
+        let done = keeper.tick().unwrap();
+
+        assert_eq!(done.len(), 1);
+        assert_eq!((done[0].0, done[0].1), (0, Action::RequestRandomness));
+
+        let sent = keeper.rpc().sent.borrow();
+        assert_eq!(sent.len(), 1);
+        let ix = &sent[0].message.instructions[0];
+        assert_eq!(
+            sent[0].message.account_keys[ix.program_id_index as usize],
+            solana_raffle::ID
+        );
+        assert_eq!(
+            ix.data[..8],
+            solana_raffle::instruction::RequestRandomness::discriminator()
+        );
+    }
+
+    #[test]
+    fn tick_sweeps_fees_before_pushing_proceeds() {
+        let signer = Keypair::new();
+        let keeper = signer.pubkey();
+
+        let mut revealed = raffle(Pubkey::new_unique());
+        revealed.is_revealed = 1;
+        let mut rpc = MockRpc::new(keeper, &[(revealed, 3)]);
+
+        let mut shard = TicketShard::zeroed();
+        shard.raffle = find_raffle_pda(0);
+        shard.total_sales = 3;
+        shard.fees = 30;
+        rpc.insert(
+            find_shard_pda(0, 0),
+            solana_raffle::ID,
+            zero_copy_data(&shard),
+        );
+
+        let config = Config {
+            push_proceeds: true,
+            ..Config::default()
+        };
+        let mut keeper = Keeper::new(rpc, signer, config);
+        let done = keeper.tick().unwrap();
+        assert_eq!((done[0].0, done[0].1), (0, Action::Withdraw));
+
+        let sent = keeper.rpc().sent.borrow();
+        let discriminators: Vec<_> = sent
+            .iter()
+            .map(|tx| tx.message.instructions.last().unwrap().data[..8].to_vec())
+            .collect();
+        assert_eq!(
+            discriminators,
+            [
+                solana_raffle::instruction::SweepFees::discriminator().to_vec(),
+                solana_raffle::instruction::PushProceeds::discriminator().to_vec(),
+            ]
+        );
+    }
+
+    #[test]
+    fn tick_pushes_prizes_to_winners() {
+        let signer = Keypair::new();
+        let keeper = signer.pubkey();
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let mut revealed = raffle(Pubkey::new_unique());
+        revealed.is_revealed = 1;
+        revealed.total_prizes = 2;
+        revealed.shard_sales[0] = 3;
+        revealed.prizes[0].kind = PrizeKind::Sol as u8;
+        revealed.prizes[0].winner_idx = 3;
+        revealed.prizes[1].kind = PrizeKind::Token as u8;
+        revealed.prizes[1].mint = Pubkey::new_unique();
+        revealed.prizes[1].winner_idx = 1;
+        let mut rpc = MockRpc::new(keeper, &[(revealed, 3)]);
+
+        // Tickets 0 of the first buyer, 1 and 2 of the second one
+        let mut shard = TicketShard::zeroed();
+        shard.raffle = find_raffle_pda(0);
+        shard.capacity = 10;
+        let mut tickets = vec![0; RaffleTicket::SIZE * 2];
+        shard.push_ticket(&mut tickets, [1; ID_LENGTH], 1).unwrap();
+        shard.push_ticket(&mut tickets, [2; ID_LENGTH], 2).unwrap();
+        rpc.insert(
+            find_shard_pda(0, 0),
+            solana_raffle::ID,
+            [zero_copy_data(&shard), tickets].concat(),
+        );
+        for (authority, uid) in [(first, [1; ID_LENGTH]), (second, [2; ID_LENGTH])] {
+            let user = UserAccount {
+                authority,
+                uid,
+                ..UserAccount::default()
+            };
+            let mut data = Vec::new();
+            user.try_serialize(&mut data).unwrap();
+            rpc.insert(
+                find_user_pda(&find_raffle_pda(0), &uid),
+                solana_raffle::ID,
+                data,
+            );
+        }
+
+        let config = Config {
+            push_prizes: true,
+            ..Config::default()
+        };
+        let mut keeper = Keeper::new(rpc, signer, config);
+        let done = keeper.tick().unwrap();
+        assert_eq!(done.len(), 1);
+        assert_eq!((done[0].0, done[0].1), (0, Action::PushPrizes));
+
+        let sent = keeper.rpc().sent.borrow();
+        let pushed: Vec<_> = sent
+            .iter()
+            .map(|tx| {
+                let ix = tx.message.instructions.last().unwrap();
+                (ix.data[..8].to_vec(), tx.message.account_keys.clone())
+            })
+            .collect();
+        assert_eq!(pushed.len(), 2);
+        assert_eq!(
+            pushed[0].0,
+            solana_raffle::instruction::PushPrizeSol::discriminator()
+        );
+        assert!(pushed[0].1.contains(&second));
+        assert_eq!(
+            pushed[1].0,
+            solana_raffle::instruction::PushPrizeToken::discriminator()
+        );
+        assert!(pushed[1].1.contains(&first));
+        // Winner token account is created ahead of token prizes
+        assert_eq!(sent[1].message.instructions.len(), 2);
+    }
+
+    #[test]
+    fn tick_backs_off_failed_raffles() {
+        let signer = Keypair::new();
+        let keeper = signer.pubkey();
+        let rpc = MockRpc::new(keeper, &[(raffle(Pubkey::new_unique()), 3)]);
+        rpc.fail_sends.set(true);
+
+        let config = Config {
+            base_backoff: Duration::from_secs(60),
+            ..Config::default()
+        };
+        let mut keeper = Keeper::new(rpc, signer, config);
+        assert!(keeper.tick().unwrap().is_empty());
+        assert_eq!(keeper.backoff[&0].failures, 1);
+
+        // Raffle is skipped until its backoff elapsed
+        keeper.rpc().fail_sends.set(false);
+        assert!(keeper.tick().unwrap().is_empty());
+        assert!(keeper.rpc().sent.borrow().is_empty());
+
+        keeper.backoff.get_mut(&0).unwrap().retry_at = Instant::now();
+        assert_eq!(keeper.tick().unwrap().len(), 1);
+        assert!(keeper.backoff.is_empty());
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_max() {
+        let config = Config {
+            base_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(4),
+            ..Config::default()
+        };
+        let mut keeper = Keeper::new(MockRpc::default(), Keypair::new(), config);
+
+        for (failures, delay) in [(1, 1), (2, 2), (3, 4), (4, 4)] {
+            let before = Instant::now();
+            let backoff = keeper.fail(7);
+            assert_eq!(backoff.failures, failures);
+            assert!(backoff.retry_at >= before + Duration::from_secs(delay));
+            assert!(backoff.retry_at <= Instant::now() + Duration::from_secs(delay));
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Keeper daemon of the solana-raffle program.
+
+use std::{env, path::PathBuf, thread, time::Duration};
+
+use anyhow::{anyhow, Context, Result};
+use clap::Parser;
+use log::{error, info};
+use raffle_keeper::{Config, Keeper};
+use solana_raffle_client::rpc::ClientRpc;
+use solana_sdk::signature::{read_keypair_file, Signer};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "raffle-keeper",
+    about = "Keeper daemon of the solana-raffle program",
+    after_help = "Log level is set with RUST_LOG, `info` by default."
+)]
+struct Args {
+    /// JSON-RPC endpoint
+    #[arg(long, env = "RAFFLE_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Keeper keypair file [default: ~/.config/solana/id.json]
+    #[arg(long)]
+    keypair: Option<PathBuf>,
+
+    /// Seconds between scans
+    #[arg(long, default_value_t = 30)]
+    interval: u64,
+
+    /// Seconds before an RPC request times out
+    #[arg(long, default_value_t = 30)]
+    timeout: u64,
+
+    /// Push proceeds of revealed raffles to their creators
+    #[arg(long)]
+    push_proceeds: bool,
+
+    /// Push prizes of revealed raffles to their winners
+    #[arg(long)]
+    push_prizes: bool,
+
+    /// Run a single scan and exit
+    #[arg(long)]
+    once: bool,
+}
+
+fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    if let Err(err) = run() {
+        error!("{err:#}");
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let args = Args::parse();
+
+    let keypair = args.keypair.unwrap_or_else(|| {
+        PathBuf::from(env::var("HOME").unwrap_or_default()).join(".config/solana/id.json")
+    });
+    let signer = read_keypair_file(&keypair)
+        .map_err(|err| anyhow!("{err}"))
+        .with_context(|| format!("failed to read keypair {}", keypair.display()))?;
+    info!("keeper {} on {}", signer.pubkey(), args.url);
+
+    let config = Config {
+        push_proceeds: args.push_proceeds,
+        push_prizes: args.push_prizes,
+        ..Config::default()
+    };
+    let rpc = ClientRpc::new(&args.url, Duration::from_secs(args.timeout));
+    let mut keeper = Keeper::new(rpc, signer, config);
+
+    loop {
+        // Failed scans are retried on the next interval, actions back off on their own
+        if let Err(err) = keeper.tick() {
+            if args.once {
+                return Err(err.into());
+            }
+            error!("scan failed: {err}");
+        }
+        if args.once {
+            return Ok(());
+        }
+        thread::sleep(Duration::from_secs(args.interval));
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::{error::ErrorCode, prelude::*, ZeroCopy};
+use mpl_token_metadata::state::{Metadata, ProgrammableConfig, TokenMetadataAccount};
+use solana_raffle::{states::*, utils::PrizeOrigin};
+
+pub fn decode_global(data: &[u8]) -> Result<GlobalAccount> {
+    GlobalAccount::try_deserialize(&mut &data[..])
+}
+
+/// Copy of the zero-copy raffle header
+pub fn decode_raffle(data: &[u8]) -> Result<RaffleAccountV2> {
+    decode_zero_copy(data)
+}
+
+pub fn decode_user(data: &[u8]) -> Result<UserAccount> {
+    UserAccount::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_fee_config(data: &[u8]) -> Result<FeeConfig> {
+    FeeConfig::try_deserialize(&mut &data[..])
+}
+
+/// Shard header with every purchase entry stored in the shard
+pub fn decode_ticket_shard(data: &[u8]) -> Result<(TicketShard, Vec<RaffleTicket>)> {
+    let shard: TicketShard = decode_zero_copy(data)?;
+    let (_, tickets) = TicketShard::split(data);
+    let tickets = (0..shard.total_tickets as usize)
+        .map(|pos| shard.ticket(tickets, pos))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+
+    Ok((shard, tickets))
+}
+
+fn decode_zero_copy<T: ZeroCopy>(data: &[u8]) -> Result<T> {
+    let disc = data
+        .get(..8)
+        .ok_or(ErrorCode::AccountDiscriminatorNotFound)?;
+    require!(
+        disc == T::discriminator(),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    data.get(8..8 + std::mem::size_of::<T>())
+        .and_then(|bytes| bytemuck::try_pod_read_unaligned(bytes).ok())
+        .ok_or_else(|| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+pub fn decode_whitelist_entry(data: &[u8]) -> Result<WhitelistEntry> {
+    WhitelistEntry::try_deserialize(&mut &data[..])
+}
+
+/// Whitelist keys a prize nft can be matched against, in lookup order:
+/// verified collection then verified first creator
+pub fn prize_whitelist_candidates(metadata_data: &[u8]) -> Result<Vec<(Pubkey, WhitelistKind)>> {
+    let metadata = decode_metadata(metadata_data)?;
+    let origin = PrizeOrigin::from_metadata(&metadata);
+
+    Ok([
+        (origin.collection, WhitelistKind::Collection),
+        (origin.creator, WhitelistKind::Creator),
+    ]
+    .into_iter()
+    .filter_map(|(key, kind)| key.map(|key| (key, kind)))
+    .collect())
+}
+
+/// Rule set of a pNFT, passed as `authorization_rules` of pNFT instructions
+pub fn rule_set(metadata_data: &[u8]) -> Result<Option<Pubkey>> {
+    let metadata = decode_metadata(metadata_data)?;
+
+    Ok(match metadata.programmable_config {
+        Some(ProgrammableConfig::V1 { rule_set }) => rule_set,
+        None => None,
+    })
+}
+
+fn decode_metadata(data: &[u8]) -> Result<Metadata> {
+    Metadata::safe_deserialize(data).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
@@ -0,0 +1,140 @@
+use anchor_lang::{error::ERROR_CODE_OFFSET, solana_program::program_error::ProgramError};
+use solana_raffle::error::RaffleError;
+
+/// Every program error in declaration order, so `code - 6000` indexes it
+const ERRORS: [RaffleError; 55] = [
+    RaffleError::InvalidSigner,
+    RaffleError::InvalidWallet,
+    RaffleError::InvalidRaffleIdx,
+    RaffleError::InvalidUUID,
+    RaffleError::InvalidDate,
+    RaffleError::InsufficientBalance,
+    RaffleError::RaffleNotStarted,
+    RaffleError::RaffleExpired,
+    RaffleError::RaffleNotEnded,
+    RaffleError::RaffleAlreadyWithdrawn,
+    RaffleError::PrizeNotDeposited,
+    RaffleError::PrizeAlreadyDeposited,
+    RaffleError::PrizeAlreadyClaimed,
+    RaffleError::WinnerNotRevealed,
+    RaffleError::WinnerNotMatched,
+    RaffleError::SupplyLimitExceed,
+    RaffleError::WalletLimitExceed,
+    RaffleError::TotalSupplyLessTotalSales,
+    RaffleError::InvalidAmount,
+    RaffleError::NumericOverflow,
+    RaffleError::InvalidMint,
+    RaffleError::InvalidTokenAccount,
+    RaffleError::InvalidPubkey,
+    RaffleError::InvalidOwner,
+    RaffleError::UninitializedAccount,
+    RaffleError::InvalidCollection,
+    RaffleError::InvalidTokenStandard,
+    RaffleError::BadMetadata,
+    RaffleError::WhitelistFull,
+    RaffleError::InvalidPrizeSlot,
+    RaffleError::WinnerAlreadyRevealed,
+    RaffleError::InvalidPrizeKind,
+    RaffleError::RaffleHasSales,
+    RaffleError::RaffleCancelled,
+    RaffleError::RaffleNotRefundable,
+    RaffleError::MinSalesNotReached,
+    RaffleError::RandomnessAlreadyRequested,
+    RaffleError::RandomnessNotRequested,
+    RaffleError::RandomnessNotReady,
+    RaffleError::RandomnessExpired,
+    RaffleError::InvalidRandomnessAccount,
+    RaffleError::InvalidTicketShard,
+    RaffleError::InvalidPermit,
+    RaffleError::PermitExpired,
+    RaffleError::PermitAlreadyUsed,
+    RaffleError::InvalidFeeConfig,
+    RaffleError::InvalidFeeRecipient,
+    RaffleError::InvalidReferral,
+    RaffleError::SelfReferral,
+    RaffleError::UnsupportedMintExtension,
+    RaffleError::InvalidAsset,
+    RaffleError::EarlyRevealNotAllowed,
+    RaffleError::ShardsNotCreated,
+    RaffleError::InvalidShardEscrow,
+    RaffleError::FeesNotSwept,
+];
+
+/// Program error of a custom error code
+pub fn from_code(code: u32) -> Option<RaffleError> {
+    let error = *ERRORS.get(code.checked_sub(ERROR_CODE_OFFSET)? as usize)?;
+    (u32::from(error) == code).then_some(error)
+}
+
+pub fn from_program_error(error: &ProgramError) -> Option<RaffleError> {
+    match error {
+        ProgramError::Custom(code) => from_code(*code),
+        _ => None,
+    }
+}
+
+/// Program error reported in transaction logs by `AnchorError occurred`
+pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> Option<RaffleError> {
+    logs.iter().find_map(|log| {
+        let (_, rest) = log.as_ref().split_once("Error Number: ")?;
+        let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        from_code(digits.parse().ok()?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Variant names of `RaffleError` in declaration order, read from the program source
+    fn declared_errors() -> Vec<String> {
+        let source = include_str!("../../../programs/solana-raffle/src/error.rs");
+        let (_, body) = source.split_once("pub enum RaffleError {").unwrap();
+        let (body, _) = body.split_once('}').unwrap();
+        body.lines()
+            .map(str::trim)
+            .filter_map(|line| line.strip_suffix(','))
+            .filter(|name| !name.is_empty() && !name.starts_with('#'))
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn every_error_round_trips() {
+        let declared = declared_errors();
+        assert_eq!(ERRORS.len(), declared.len(), "ERRORS is out of date");
+
+        for (idx, (error, name)) in ERRORS.iter().zip(&declared).enumerate() {
+            let code = ERROR_CODE_OFFSET + idx as u32;
+            assert_eq!(&error.name(), name);
+            assert_eq!(u32::from(*error), code);
+            assert_eq!(from_code(code).map(|e| e.name()), Some(error.name()));
+            assert_eq!(
+                from_program_error(&ProgramError::Custom(code)).map(|e| e.name()),
+                Some(error.name())
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_codes() {
+        assert!(from_code(0).is_none());
+        assert!(from_code(ERROR_CODE_OFFSET - 1).is_none());
+        assert!(from_code(ERROR_CODE_OFFSET + ERRORS.len() as u32).is_none());
+        assert!(from_program_error(&ProgramError::InvalidArgument).is_none());
+    }
+
+    #[test]
+    fn error_from_logs() {
+        let logs = [
+            "Program GFkrs8CmUsviDmAgQRBuj9grwHe5jqKCGjmsuV8CBH7L invoke [1]",
+            "Program log: AnchorError occurred. Error Code: RaffleExpired. Error Number: 6007. Error Message: Raffle expired.",
+            "Program GFkrs8CmUsviDmAgQRBuj9grwHe5jqKCGjmsuV8CBH7L failed: custom program error: 0x1777",
+        ];
+        assert_eq!(
+            from_logs(&logs).map(|e| e.name()),
+            Some(RaffleError::RaffleExpired.name())
+        );
+        assert!(from_logs(&logs[..1]).is_none());
+    }
+}
@@ -0,0 +1,1498 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program,
+        instruction::{AccountMeta, Instruction},
+        system_program, sysvar,
+    },
+    InstructionData,
+};
+use anchor_spl::{associated_token, token, token_2022};
+use solana_raffle::{
+    accounts,
+    bubblegum::{self, MetadataArgs, BUBBLEGUM_ID},
+    constants::ID_LENGTH,
+    instruction,
+    mpl_core::MPL_CORE_ID,
+    permit::BuyPermit,
+    states::{FeeConfig, FeeRecipient, RandomnessSource, WhitelistKind},
+    utils::PrizeOrigin,
+    ID,
+};
+
+use crate::pda::*;
+
+/// Metaplex token auth rules program
+pub mod auth_rules {
+    use anchor_lang::declare_id;
+
+    declare_id!("auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg");
+}
+
+/// Leaf of a compressed nft with its current proof, as served by DAS `getAssetProof`
+#[derive(Clone, Debug)]
+pub struct CnftLeaf {
+    pub merkle_tree: Pubkey,
+    pub root: [u8; 32],
+    pub proof: Vec<Pubkey>,
+    pub nonce: u64,
+    pub index: u32,
+}
+
+impl CnftLeaf {
+    fn proof_accounts(&self) -> Vec<AccountMeta> {
+        self.proof
+            .iter()
+            .map(|node| AccountMeta::new_readonly(*node, false))
+            .collect()
+    }
+}
+
+fn build(
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+    remaining: Vec<AccountMeta>,
+) -> Instruction {
+    let mut metas = accounts.to_account_metas(None);
+    metas.extend(remaining);
+
+    Instruction {
+        program_id: ID,
+        accounts: metas,
+        data: data.data(),
+    }
+}
+
+/// Metadata program stands for a missing rule set in pNFT instructions
+fn authorization_rules(rule_set: Option<Pubkey>) -> Pubkey {
+    rule_set.unwrap_or(mpl_token_metadata::ID)
+}
+
+/// Ed25519 program instruction verifying the permit signature, must be placed
+/// right before `buy_ticket` whose permit produced `message`
+pub fn verify_permit(permit_signer: &Pubkey, signature: &[u8; 64], message: &[u8]) -> Instruction {
+    const PUBKEY_OFFSET: u16 = 16;
+    const SIGNATURE_OFFSET: u16 = PUBKEY_OFFSET + 32;
+    const MESSAGE_OFFSET: u16 = SIGNATURE_OFFSET + 64;
+
+    let mut data = vec![1, 0];
+    for offset in [
+        SIGNATURE_OFFSET,
+        u16::MAX,
+        PUBKEY_OFFSET,
+        u16::MAX,
+        MESSAGE_OFFSET,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(permit_signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+pub fn initialize(authority: &Pubkey, vault: &Pubkey, permit_signer: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeCtx {
+            authority: *authority,
+            vault: *vault,
+            permit_signer: *permit_signer,
+            global_account: find_global_pda(),
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::Initialize {},
+        vec![],
+    )
+}
+
+pub fn update_setting(
+    authority: &Pubkey,
+    new_authority: &Pubkey,
+    vault: &Pubkey,
+    permit_signer: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::UpdateSettingCtx {
+            authority: *authority,
+            new_authority: *new_authority,
+            vault: *vault,
+            permit_signer: *permit_signer,
+            global_account: find_global_pda(),
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::UpdateSetting {},
+        vec![],
+    )
+}
+
+pub fn update_fees(
+    authority: &Pubkey,
+    fee_percent: u16,
+    max_ticket_percent: u16,
+    referral_percent: u16,
+    crank_reward_percent: u16,
+) -> Instruction {
+    build(
+        accounts::UpdateFeesCtx {
+            authority: *authority,
+            global_account: find_global_pda(),
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::UpdateFees {
+            fee_percent,
+            max_ticket_percent,
+            referral_percent,
+            crank_reward_percent,
+        },
+        vec![],
+    )
+}
+
+/// `fee_config_version` is the current global one, a non-empty split is
+/// created as the next version
+pub fn set_fee_config(
+    authority: &Pubkey,
+    fee_config_version: u32,
+    recipients: Vec<FeeRecipient>,
+) -> Instruction {
+    let fee_config =
+        (!recipients.is_empty()).then(|| find_global_fee_config_pda(fee_config_version + 1));
+
+    build(
+        accounts::SetFeeConfigCtx {
+            authority: *authority,
+            global_account: find_global_pda(),
+            fee_config,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::SetFeeConfig { recipients },
+        vec![],
+    )
+}
+
+/// Fees of every shard must be swept with [`sweep_fees`] first
+pub fn set_raffle_fee_config(
+    authority: &Pubkey,
+    idx: u32,
+    recipients: Vec<FeeRecipient>,
+    total_shards: u8,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::SetRaffleFeeConfigCtx {
+            authority: *authority,
+            global_account: find_global_pda(),
+            raffle_account: raffle,
+            fee_config: find_fee_config_pda(&raffle),
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::SetRaffleFeeConfig { idx, recipients },
+        shard_accounts(idx, total_shards),
+    )
+}
+
+pub fn add_whitelist(authority: &Pubkey, key: &Pubkey, kind: WhitelistKind) -> Instruction {
+    build(
+        accounts::AddWhitelistCtx {
+            authority: *authority,
+            global_account: find_global_pda(),
+            whitelist_entry: find_whitelist_pda(key),
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::AddWhitelist { key: *key, kind },
+        vec![],
+    )
+}
+
+pub fn remove_whitelist(authority: &Pubkey, key: &Pubkey) -> Instruction {
+    build(
+        accounts::RemoveWhitelistCtx {
+            authority: *authority,
+            global_account: find_global_pda(),
+            whitelist_entry: find_whitelist_pda(key),
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::RemoveWhitelist { key: *key },
+        vec![],
+    )
+}
+
+pub fn migrate_whitelist(authority: &Pubkey, collection: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateWhitelistCtx {
+            authority: *authority,
+            global_account: find_global_pda(),
+            whitelist_entry: find_whitelist_pda(collection),
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::MigrateWhitelist {
+            collection: *collection,
+        },
+        vec![],
+    )
+}
+
+/// Raffle `idx` must be the current `total_raffles` of the global account,
+/// its shards are created separately by [`create_shard`]
+pub fn create_raffle(
+    authority: &Pubkey,
+    spl_mint: &Pubkey,
+    idx: u32,
+    total_supply: u32,
+    total_prizes: u8,
+    price: u64,
+    start_date: u64,
+    end_date: u64,
+    min_sales: u32,
+    total_shards: u8,
+    is_public: bool,
+    max_ticket_percent: Option<u16>,
+) -> Instruction {
+    build(
+        accounts::CreateRaffleCtx {
+            authority: *authority,
+            global_account: find_global_pda(),
+            spl_mint: *spl_mint,
+            raffle_account: find_raffle_pda(idx),
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::CreateRaffle {
+            idx,
+            total_supply,
+            total_prizes,
+            price,
+            start_date,
+            end_date,
+            min_sales,
+            total_shards,
+            is_public,
+            max_ticket_percent,
+        },
+        vec![],
+    )
+}
+
+pub fn create_shard(authority: &Pubkey, idx: u32, shard: u8) -> Instruction {
+    build(
+        accounts::CreateShardCtx {
+            authority: *authority,
+            raffle_account: find_raffle_pda(idx),
+            ticket_shard: find_shard_pda(idx, shard),
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::CreateShard { idx, shard },
+        vec![],
+    )
+}
+
+/// `whitelist_key` is the verified collection or first creator of the nft,
+/// see [`crate::rpc::fetch_prize_whitelist_key`]
+pub fn add_prize(
+    authority: &Pubkey,
+    idx: u32,
+    slot: u8,
+    mint: &Pubkey,
+    whitelist_key: &Pubkey,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::AddPrizeCtx {
+            authority: *authority,
+            raffle_account: raffle,
+            mint: *mint,
+            whitelist_entry: find_whitelist_pda(whitelist_key),
+            metadata: find_metadata_pda(mint),
+            edition: find_edition_pda(mint),
+            token_account: associated_token::get_associated_token_address(authority, mint),
+            escrow_account: find_escrow_pda(&raffle),
+            metadata_program: mpl_token_metadata::ID,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::AddPrize { idx, slot },
+        vec![],
+    )
+}
+
+/// `rule_set` of the pNFT, see [`crate::accounts::rule_set`]
+pub fn add_prize_pnft(
+    authority: &Pubkey,
+    idx: u32,
+    slot: u8,
+    mint: &Pubkey,
+    whitelist_key: &Pubkey,
+    rule_set: Option<Pubkey>,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+    let escrow = find_escrow_pda(&raffle);
+    let token_account = associated_token::get_associated_token_address(authority, mint);
+
+    build(
+        accounts::AddPrizePnftCtx {
+            authority: *authority,
+            raffle_account: raffle,
+            mint: *mint,
+            whitelist_entry: find_whitelist_pda(whitelist_key),
+            metadata: find_metadata_pda(mint),
+            edition: find_edition_pda(mint),
+            token_record: find_token_record_pda(mint, &token_account),
+            delegate_record: find_token_record_pda(mint, &escrow),
+            token_account,
+            escrow_account: escrow,
+            authorization_rules: authorization_rules(rule_set),
+            sysvar_instructions: sysvar::instructions::ID,
+            token_program: token::ID,
+            metadata_program: mpl_token_metadata::ID,
+            authorization_rules_program: auth_rules::ID,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::AddPrizePnft { idx, slot },
+        vec![],
+    )
+}
+
+pub fn add_prize_token(
+    authority: &Pubkey,
+    idx: u32,
+    slot: u8,
+    mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::AddPrizeTokenCtx {
+            authority: *authority,
+            raffle_account: raffle,
+            mint: *mint,
+            token_account: associated_token::get_associated_token_address(authority, mint),
+            prize_escrow: find_prize_escrow_pda(&raffle, slot),
+            system_program: system_program::ID,
+            token_program: token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::AddPrizeToken { idx, slot, amount },
+        vec![],
+    )
+}
+
+pub fn add_prize_sol(authority: &Pubkey, idx: u32, slot: u8, amount: u64) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::AddPrizeSolCtx {
+            authority: *authority,
+            raffle_account: raffle,
+            prize_vault: find_prize_escrow_pda(&raffle, slot),
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::AddPrizeSol { idx, slot, amount },
+        vec![],
+    )
+}
+
+pub fn add_prize_2022(
+    authority: &Pubkey,
+    idx: u32,
+    slot: u8,
+    mint: &Pubkey,
+    whitelist_key: &Pubkey,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::AddPrize2022Ctx {
+            authority: *authority,
+            raffle_account: raffle,
+            whitelist_entry: find_whitelist_pda(whitelist_key),
+            mint: *mint,
+            metadata: find_metadata_pda(mint),
+            token_account: find_payment_account(authority, mint, &token_2022::ID),
+            prize_escrow: find_prize_escrow_pda(&raffle, slot),
+            system_program: system_program::ID,
+            token_program: token_2022::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::AddPrize2022 { idx, slot },
+        vec![],
+    )
+}
+
+/// Core assets are whitelisted by collection
+pub fn add_prize_core(
+    authority: &Pubkey,
+    idx: u32,
+    slot: u8,
+    asset: &Pubkey,
+    collection: Option<Pubkey>,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::AddPrizeCoreCtx {
+            authority: *authority,
+            raffle_account: raffle,
+            whitelist_entry: find_whitelist_pda(&collection.unwrap_or_default()),
+            asset: *asset,
+            collection,
+            escrow_account: find_escrow_pda(&raffle),
+            core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::AddPrizeCore { idx, slot },
+        vec![],
+    )
+}
+
+/// Leaf owned by `authority`, whitelist entry matched from `metadata`
+pub fn add_prize_cnft(
+    authority: &Pubkey,
+    idx: u32,
+    slot: u8,
+    leaf: &CnftLeaf,
+    metadata: MetadataArgs,
+    leaf_delegate: &Pubkey,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+    let origin = PrizeOrigin::from_compressed(&metadata);
+    let whitelist_key = origin.collection.or(origin.creator).unwrap_or_default();
+
+    build(
+        accounts::AddPrizeCnftCtx {
+            authority: *authority,
+            raffle_account: raffle,
+            whitelist_entry: find_whitelist_pda(&whitelist_key),
+            compressed_prize: find_prize_escrow_pda(&raffle, slot),
+            escrow_account: find_escrow_pda(&raffle),
+            leaf_delegate: *leaf_delegate,
+            tree_authority: find_tree_authority_pda(&leaf.merkle_tree),
+            merkle_tree: leaf.merkle_tree,
+            log_wrapper: bubblegum::noop::ID,
+            compression_program: bubblegum::compression::ID,
+            bubblegum_program: BUBBLEGUM_ID,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::AddPrizeCnft {
+            idx,
+            slot,
+            root: leaf.root,
+            metadata,
+            nonce: leaf.nonce,
+            index: leaf.index,
+        },
+        leaf.proof_accounts(),
+    )
+}
+
+/// Permit purchases need [`verify_permit`] right before this instruction
+pub fn buy_ticket(
+    buyer: &Pubkey,
+    idx: u32,
+    uid: [u8; ID_LENGTH],
+    amount: u32,
+    shard: u8,
+    spl_mint: &Pubkey,
+    token_program: &Pubkey,
+    permit: Option<BuyPermit>,
+    referrer: Option<&Pubkey>,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+    let referral = referrer.map(|referrer| find_referral_pda(referrer, spl_mint));
+
+    build(
+        accounts::BuyTicketCtx {
+            buyer: *buyer,
+            global_account: find_global_pda(),
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            spl_mint: *spl_mint,
+            user_token_account: find_payment_account(buyer, spl_mint, token_program),
+            referral_account: referral,
+            referral_escrow: referral.as_ref().map(find_escrow_pda),
+            escrow_account: find_shard_escrow_pda(&raffle, shard),
+            sysvar_instructions: sysvar::instructions::ID,
+            token_program: *token_program,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::BuyTicket {
+            idx,
+            uid,
+            amount,
+            shard,
+            permit,
+        },
+        vec![],
+    )
+}
+
+/// Sweep fees held by `shard` escrow, `fee_config` is the split in effect:
+/// raffle one, else global version snapshot by the raffle if any
+pub fn sweep_fees(
+    payer: &Pubkey,
+    idx: u32,
+    shard: u8,
+    spl_mint: &Pubkey,
+    token_program: &Pubkey,
+    vault: &Pubkey,
+    fee_config: Option<&FeeConfig>,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    // Fee recipients of the split are passed as remaining accounts in order
+    let fee_accounts = fee_config
+        .map(|fee_config| {
+            fee_config
+                .recipients()
+                .iter()
+                .map(|recipient| {
+                    AccountMeta::new(
+                        find_payment_account(&recipient.wallet, spl_mint, token_program),
+                        false,
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    build(
+        accounts::SweepFeesCtx {
+            payer: *payer,
+            global_account: find_global_pda(),
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            spl_mint: *spl_mint,
+            escrow_account: find_shard_escrow_pda(&raffle, shard),
+            vault_token_account: find_payment_account(vault, spl_mint, token_program),
+            raffle_fee_config: fee_config
+                .filter(|fee_config| fee_config.raffle != Pubkey::default())
+                .map(|fee_config| find_fee_config_pda(&fee_config.raffle)),
+            global_fee_config: fee_config
+                .filter(|fee_config| fee_config.raffle == Pubkey::default())
+                .map(|fee_config| find_global_fee_config_pda(fee_config.version)),
+            system_program: system_program::ID,
+            token_program: *token_program,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::SweepFees { idx, shard },
+        fee_accounts,
+    )
+}
+
+/// Fees of every shard must be swept with [`sweep_fees`] first
+pub fn withdraw_raffle(
+    authority: &Pubkey,
+    idx: u32,
+    spl_mint: &Pubkey,
+    token_program: &Pubkey,
+    total_shards: u8,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::WithdrawRaffleCtx {
+            authority: *authority,
+            raffle_account: raffle,
+            spl_mint: *spl_mint,
+            treasury_token_account: find_payment_account(authority, spl_mint, token_program),
+            system_program: system_program::ID,
+            token_program: *token_program,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::WithdrawRaffle { idx },
+        shard_escrow_accounts(idx, total_shards),
+    )
+}
+
+/// Proceeds are paid to `authority`, the raffle creator, anyone can pay the transaction.
+/// Its token account must exist for SPL mints
+pub fn push_proceeds(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    idx: u32,
+    spl_mint: &Pubkey,
+    token_program: &Pubkey,
+    total_shards: u8,
+) -> Instruction {
+    build(
+        accounts::PushProceedsCtx {
+            payer: *payer,
+            authority: *authority,
+            raffle_account: find_raffle_pda(idx),
+            spl_mint: *spl_mint,
+            treasury_token_account: find_payment_account(authority, spl_mint, token_program),
+            system_program: system_program::ID,
+            token_program: *token_program,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::PushProceeds { idx },
+        shard_escrow_accounts(idx, total_shards),
+    )
+}
+
+/// Crank reward is paid to the payer wallet for SOL raffles and to its token
+/// account otherwise, which must exist when a reward is due.
+/// `committed_request` is the timed out oracle request being replaced, if any
+pub fn request_randomness(
+    payer: &Pubkey,
+    idx: u32,
+    source: RandomnessSource,
+    oracle_request: Option<Pubkey>,
+    committed_request: Option<Pubkey>,
+    spl_mint: &Pubkey,
+    token_program: &Pubkey,
+    total_shards: u8,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::RequestRandomnessCtx {
+            payer: *payer,
+            global_account: find_global_pda(),
+            raffle_account: raffle,
+            spl_mint: *spl_mint,
+            payer_token_account: find_payment_account(payer, spl_mint, token_program),
+            oracle_request,
+            committed_request,
+            recent_slothashes: sysvar::slot_hashes::ID,
+            system_program: system_program::ID,
+            token_program: *token_program,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::RequestRandomness { idx, source },
+        shard_escrow_accounts(idx, total_shards),
+    )
+}
+
+pub fn settle_randomness(payer: &Pubkey, idx: u32, oracle_request: Option<Pubkey>) -> Instruction {
+    build(
+        accounts::SettleRandomnessCtx {
+            payer: *payer,
+            raffle_account: find_raffle_pda(idx),
+            oracle_request,
+            recent_slothashes: sysvar::slot_hashes::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::SettleRandomness { idx },
+        vec![],
+    )
+}
+
+/// `shard` holds the winning ticket, see `RaffleAccountV2::locate_ticket`.
+/// Winner token account must already exist, same for every claim below
+pub fn claim_prize(
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+    creator: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::ClaimPrizeCtx {
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            mint: *mint,
+            metadata: find_metadata_pda(mint),
+            edition: find_edition_pda(mint),
+            creator_token_account: associated_token::get_associated_token_address(creator, mint),
+            winner_token_account: associated_token::get_associated_token_address(winner, mint),
+            escrow_account: find_escrow_pda(&raffle),
+            metadata_program: mpl_token_metadata::ID,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ClaimPrize { idx, slot, uid },
+        vec![],
+    )
+}
+
+pub fn claim_prize_pnft(
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    rule_set: Option<Pubkey>,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+    let owner_token_account = associated_token::get_associated_token_address(creator, mint);
+    let dest_token_account = associated_token::get_associated_token_address(winner, mint);
+
+    build(
+        accounts::ClaimPrizePnftCtx {
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            owner: *creator,
+            mint: *mint,
+            metadata: find_metadata_pda(mint),
+            edition: find_edition_pda(mint),
+            owner_token_record: find_token_record_pda(mint, &owner_token_account),
+            owner_token_account,
+            dest_token_record: find_token_record_pda(mint, &dest_token_account),
+            dest_token_account,
+            escrow_account: find_escrow_pda(&raffle),
+            authorization_rules: authorization_rules(rule_set),
+            sysvar_instructions: sysvar::instructions::ID,
+            token_program: token::ID,
+            ata_program: associated_token::ID,
+            metadata_program: mpl_token_metadata::ID,
+            authorization_rules_program: auth_rules::ID,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ClaimPrizePnft { idx, slot, uid },
+        vec![],
+    )
+}
+
+pub fn claim_prize_token(
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+    creator: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::ClaimPrizeTokenCtx {
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            creator: *creator,
+            mint: *mint,
+            prize_escrow: find_prize_escrow_pda(&raffle, slot),
+            winner_token_account: associated_token::get_associated_token_address(winner, mint),
+            system_program: system_program::ID,
+            token_program: token::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ClaimPrizeToken { idx, slot, uid },
+        vec![],
+    )
+}
+
+pub fn claim_prize_sol(
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::ClaimPrizeSolCtx {
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            prize_vault: find_prize_escrow_pda(&raffle, slot),
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ClaimPrizeSol { idx, slot, uid },
+        vec![],
+    )
+}
+
+pub fn claim_prize_2022(
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+    creator: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::ClaimPrize2022Ctx {
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            creator: *creator,
+            mint: *mint,
+            prize_escrow: find_prize_escrow_pda(&raffle, slot),
+            winner_token_account: find_payment_account(winner, mint, &token_2022::ID),
+            system_program: system_program::ID,
+            token_program: token_2022::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ClaimPrize2022 { idx, slot, uid },
+        vec![],
+    )
+}
+
+pub fn claim_prize_core(
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+    asset: &Pubkey,
+    collection: Option<Pubkey>,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::ClaimPrizeCoreCtx {
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            asset: *asset,
+            collection,
+            escrow_account: find_escrow_pda(&raffle),
+            core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ClaimPrizeCore { idx, slot, uid },
+        vec![],
+    )
+}
+
+pub fn claim_prize_cnft(
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+    creator: &Pubkey,
+    leaf: &CnftLeaf,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::ClaimPrizeCnftCtx {
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            creator: *creator,
+            compressed_prize: find_prize_escrow_pda(&raffle, slot),
+            escrow_account: find_escrow_pda(&raffle),
+            tree_authority: find_tree_authority_pda(&leaf.merkle_tree),
+            merkle_tree: leaf.merkle_tree,
+            log_wrapper: bubblegum::noop::ID,
+            compression_program: bubblegum::compression::ID,
+            bubblegum_program: BUBBLEGUM_ID,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ClaimPrizeCnft {
+            idx,
+            slot,
+            uid,
+            root: leaf.root,
+        },
+        leaf.proof_accounts(),
+    )
+}
+
+/// Same accounts as the matching claim, signed by `payer` instead of the winner
+pub fn push_prize(
+    payer: &Pubkey,
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+    creator: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::PushPrizeCtx {
+            payer: *payer,
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            mint: *mint,
+            metadata: find_metadata_pda(mint),
+            edition: find_edition_pda(mint),
+            creator_token_account: associated_token::get_associated_token_address(creator, mint),
+            winner_token_account: associated_token::get_associated_token_address(winner, mint),
+            escrow_account: find_escrow_pda(&raffle),
+            metadata_program: mpl_token_metadata::ID,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::PushPrize { idx, slot, uid },
+        vec![],
+    )
+}
+
+pub fn push_prize_pnft(
+    payer: &Pubkey,
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    rule_set: Option<Pubkey>,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+    let owner_token_account = associated_token::get_associated_token_address(creator, mint);
+    let dest_token_account = associated_token::get_associated_token_address(winner, mint);
+
+    build(
+        accounts::PushPrizePnftCtx {
+            payer: *payer,
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            owner: *creator,
+            mint: *mint,
+            metadata: find_metadata_pda(mint),
+            edition: find_edition_pda(mint),
+            owner_token_record: find_token_record_pda(mint, &owner_token_account),
+            owner_token_account,
+            dest_token_record: find_token_record_pda(mint, &dest_token_account),
+            dest_token_account,
+            escrow_account: find_escrow_pda(&raffle),
+            authorization_rules: authorization_rules(rule_set),
+            sysvar_instructions: sysvar::instructions::ID,
+            token_program: token::ID,
+            ata_program: associated_token::ID,
+            metadata_program: mpl_token_metadata::ID,
+            authorization_rules_program: auth_rules::ID,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::PushPrizePnft { idx, slot, uid },
+        vec![],
+    )
+}
+
+pub fn push_prize_token(
+    payer: &Pubkey,
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+    creator: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::PushPrizeTokenCtx {
+            payer: *payer,
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            creator: *creator,
+            mint: *mint,
+            prize_escrow: find_prize_escrow_pda(&raffle, slot),
+            winner_token_account: associated_token::get_associated_token_address(winner, mint),
+            system_program: system_program::ID,
+            token_program: token::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::PushPrizeToken { idx, slot, uid },
+        vec![],
+    )
+}
+
+pub fn push_prize_sol(
+    payer: &Pubkey,
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::PushPrizeSolCtx {
+            payer: *payer,
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            prize_vault: find_prize_escrow_pda(&raffle, slot),
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::PushPrizeSol { idx, slot, uid },
+        vec![],
+    )
+}
+
+pub fn push_prize_2022(
+    payer: &Pubkey,
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+    creator: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::PushPrize2022Ctx {
+            payer: *payer,
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            creator: *creator,
+            mint: *mint,
+            prize_escrow: find_prize_escrow_pda(&raffle, slot),
+            winner_token_account: find_payment_account(winner, mint, &token_2022::ID),
+            system_program: system_program::ID,
+            token_program: token_2022::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::PushPrize2022 { idx, slot, uid },
+        vec![],
+    )
+}
+
+pub fn push_prize_core(
+    payer: &Pubkey,
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+    asset: &Pubkey,
+    collection: Option<Pubkey>,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::PushPrizeCoreCtx {
+            payer: *payer,
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            asset: *asset,
+            collection,
+            escrow_account: find_escrow_pda(&raffle),
+            core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::PushPrizeCore { idx, slot, uid },
+        vec![],
+    )
+}
+
+pub fn push_prize_cnft(
+    payer: &Pubkey,
+    winner: &Pubkey,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    shard: u8,
+    creator: &Pubkey,
+    leaf: &CnftLeaf,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::PushPrizeCnftCtx {
+            payer: *payer,
+            authority: *winner,
+            raffle_account: raffle,
+            ticket_shard: find_shard_pda(idx, shard),
+            user_account: find_user_pda(&raffle, &uid),
+            creator: *creator,
+            compressed_prize: find_prize_escrow_pda(&raffle, slot),
+            escrow_account: find_escrow_pda(&raffle),
+            tree_authority: find_tree_authority_pda(&leaf.merkle_tree),
+            merkle_tree: leaf.merkle_tree,
+            log_wrapper: bubblegum::noop::ID,
+            compression_program: bubblegum::compression::ID,
+            bubblegum_program: BUBBLEGUM_ID,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::PushPrizeCnft {
+            idx,
+            slot,
+            uid,
+            root: leaf.root,
+        },
+        leaf.proof_accounts(),
+    )
+}
+
+pub fn init_referral(referrer: &Pubkey, spl_mint: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let referral = find_referral_pda(referrer, spl_mint);
+
+    build(
+        accounts::InitReferralCtx {
+            referrer: *referrer,
+            spl_mint: *spl_mint,
+            referral_account: referral,
+            referral_escrow: find_escrow_pda(&referral),
+            token_program: *token_program,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::InitReferral {},
+        vec![],
+    )
+}
+
+pub fn claim_referral(referrer: &Pubkey, spl_mint: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let referral = find_referral_pda(referrer, spl_mint);
+
+    build(
+        accounts::ClaimReferralCtx {
+            referrer: *referrer,
+            referral_account: referral,
+            spl_mint: *spl_mint,
+            referral_escrow: find_escrow_pda(&referral),
+            referrer_token_account: find_payment_account(referrer, spl_mint, token_program),
+            token_program: *token_program,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ClaimReferral {},
+        vec![],
+    )
+}
+
+pub fn cancel_raffle(authority: &Pubkey, idx: u32, total_shards: u8) -> Instruction {
+    build(
+        accounts::CancelRaffleCtx {
+            authority: *authority,
+            global_account: find_global_pda(),
+            raffle_account: find_raffle_pda(idx),
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::CancelRaffle { idx },
+        shard_accounts(idx, total_shards),
+    )
+}
+
+/// Refund is paid to `buyer`, anyone can pay the transaction
+pub fn refund_ticket(
+    payer: &Pubkey,
+    buyer: &Pubkey,
+    idx: u32,
+    uid: [u8; ID_LENGTH],
+    spl_mint: &Pubkey,
+    token_program: &Pubkey,
+    total_shards: u8,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::RefundTicketCtx {
+            payer: *payer,
+            authority: *buyer,
+            raffle_account: raffle,
+            user_account: find_user_pda(&raffle, &uid),
+            spl_mint: *spl_mint,
+            user_token_account: find_payment_account(buyer, spl_mint, token_program),
+            system_program: system_program::ID,
+            token_program: *token_program,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::RefundTicket { idx, uid },
+        shard_escrow_accounts(idx, total_shards),
+    )
+}
+
+pub fn reclaim_prize(
+    authority: &Pubkey,
+    idx: u32,
+    slot: u8,
+    mint: &Pubkey,
+    total_shards: u8,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::ReclaimPrizeCtx {
+            authority: *authority,
+            raffle_account: raffle,
+            mint: *mint,
+            edition: find_edition_pda(mint),
+            token_account: associated_token::get_associated_token_address(authority, mint),
+            escrow_account: find_escrow_pda(&raffle),
+            metadata_program: mpl_token_metadata::ID,
+            token_program: token::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ReclaimPrize { idx, slot },
+        shard_accounts(idx, total_shards),
+    )
+}
+
+pub fn reclaim_prize_pnft(
+    authority: &Pubkey,
+    idx: u32,
+    slot: u8,
+    mint: &Pubkey,
+    rule_set: Option<Pubkey>,
+    total_shards: u8,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+    let token_account = associated_token::get_associated_token_address(authority, mint);
+
+    build(
+        accounts::ReclaimPrizePnftCtx {
+            authority: *authority,
+            raffle_account: raffle,
+            mint: *mint,
+            metadata: find_metadata_pda(mint),
+            edition: find_edition_pda(mint),
+            token_record: find_token_record_pda(mint, &token_account),
+            token_account,
+            escrow_account: find_escrow_pda(&raffle),
+            authorization_rules: authorization_rules(rule_set),
+            sysvar_instructions: sysvar::instructions::ID,
+            token_program: token::ID,
+            metadata_program: mpl_token_metadata::ID,
+            authorization_rules_program: auth_rules::ID,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ReclaimPrizePnft { idx, slot },
+        shard_accounts(idx, total_shards),
+    )
+}
+
+pub fn reclaim_prize_token(
+    authority: &Pubkey,
+    idx: u32,
+    slot: u8,
+    mint: &Pubkey,
+    total_shards: u8,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::ReclaimPrizeTokenCtx {
+            authority: *authority,
+            raffle_account: raffle,
+            mint: *mint,
+            prize_escrow: find_prize_escrow_pda(&raffle, slot),
+            token_account: associated_token::get_associated_token_address(authority, mint),
+            system_program: system_program::ID,
+            token_program: token::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ReclaimPrizeToken { idx, slot },
+        shard_accounts(idx, total_shards),
+    )
+}
+
+pub fn reclaim_prize_sol(authority: &Pubkey, idx: u32, slot: u8, total_shards: u8) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::ReclaimPrizeSolCtx {
+            authority: *authority,
+            raffle_account: raffle,
+            prize_vault: find_prize_escrow_pda(&raffle, slot),
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ReclaimPrizeSol { idx, slot },
+        shard_accounts(idx, total_shards),
+    )
+}
+
+pub fn reclaim_prize_2022(
+    authority: &Pubkey,
+    idx: u32,
+    slot: u8,
+    mint: &Pubkey,
+    total_shards: u8,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::ReclaimPrize2022Ctx {
+            authority: *authority,
+            raffle_account: raffle,
+            mint: *mint,
+            prize_escrow: find_prize_escrow_pda(&raffle, slot),
+            token_account: find_payment_account(authority, mint, &token_2022::ID),
+            system_program: system_program::ID,
+            token_program: token_2022::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ReclaimPrize2022 { idx, slot },
+        shard_accounts(idx, total_shards),
+    )
+}
+
+pub fn reclaim_prize_core(
+    authority: &Pubkey,
+    idx: u32,
+    slot: u8,
+    asset: &Pubkey,
+    collection: Option<Pubkey>,
+    total_shards: u8,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+
+    build(
+        accounts::ReclaimPrizeCoreCtx {
+            authority: *authority,
+            raffle_account: raffle,
+            asset: *asset,
+            collection,
+            escrow_account: find_escrow_pda(&raffle),
+            core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ReclaimPrizeCore { idx, slot },
+        shard_accounts(idx, total_shards),
+    )
+}
+
+/// Ticket shards come before the leaf proof in remaining accounts
+pub fn reclaim_prize_cnft(
+    authority: &Pubkey,
+    idx: u32,
+    slot: u8,
+    leaf: &CnftLeaf,
+    total_shards: u8,
+) -> Instruction {
+    let raffle = find_raffle_pda(idx);
+    let mut remaining = shard_accounts(idx, total_shards);
+    remaining.extend(leaf.proof_accounts());
+
+    build(
+        accounts::ReclaimPrizeCnftCtx {
+            authority: *authority,
+            raffle_account: raffle,
+            compressed_prize: find_prize_escrow_pda(&raffle, slot),
+            escrow_account: find_escrow_pda(&raffle),
+            tree_authority: find_tree_authority_pda(&leaf.merkle_tree),
+            merkle_tree: leaf.merkle_tree,
+            log_wrapper: bubblegum::noop::ID,
+            compression_program: bubblegum::compression::ID,
+            bubblegum_program: BUBBLEGUM_ID,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_pda(),
+            program: ID,
+        },
+        instruction::ReclaimPrizeCnft {
+            idx,
+            slot,
+            root: leaf.root,
+        },
+        remaining,
+    )
+}
@@ -0,0 +1,12 @@
+#![allow(clippy::result_large_err, clippy::too_many_arguments)]
+
+//! Off-chain client of the solana-raffle program: PDA helpers, instruction
+//! builders, account decoders, error mapping and RPC access.
+
+pub mod accounts;
+pub mod errors;
+pub mod instructions;
+pub mod pda;
+pub mod rpc;
+
+pub use solana_raffle::{self, ID};
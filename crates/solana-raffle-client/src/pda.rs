@@ -0,0 +1,219 @@
+use anchor_lang::{prelude::*, solana_program::instruction::AccountMeta};
+use anchor_spl::{associated_token::get_associated_token_address_with_program_id, token};
+use solana_raffle::{bubblegum, constants::*, ID};
+
+/// Global settings PDA
+pub fn find_global_pda() -> Pubkey {
+    Pubkey::find_program_address(&[PREFIX], &ID).0
+}
+
+/// Signer of the self-invocations logging events of `emit_cpi!`
+pub fn find_event_authority_pda() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], &ID).0
+}
+
+pub fn find_raffle_pda(idx: u32) -> Pubkey {
+    Pubkey::find_program_address(&[PREFIX, RAFFLE, &idx.to_be_bytes()], &ID).0
+}
+
+pub fn find_shard_pda(idx: u32, shard: u8) -> Pubkey {
+    Pubkey::find_program_address(&[PREFIX, RAFFLE, &idx.to_be_bytes(), &[shard]], &ID).0
+}
+
+/// Escrow of raffle nft prizes, or of referral rewards
+pub fn find_escrow_pda(owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[PREFIX, owner.as_ref(), ESCROW], &ID).0
+}
+
+/// Escrow of ticket proceeds and unswept fees of `shard`
+pub fn find_shard_escrow_pda(raffle: &Pubkey, shard: u8) -> Pubkey {
+    Pubkey::find_program_address(&[PREFIX, raffle.as_ref(), ESCROW, &[shard]], &ID).0
+}
+
+/// Escrow of token, SOL, Token-2022 and compressed prizes in `slot`
+pub fn find_prize_escrow_pda(raffle: &Pubkey, slot: u8) -> Pubkey {
+    Pubkey::find_program_address(&[PREFIX, raffle.as_ref(), PRIZE, &[slot]], &ID).0
+}
+
+pub fn find_user_pda(raffle: &Pubkey, uid: &[u8; ID_LENGTH]) -> Pubkey {
+    Pubkey::find_program_address(&[USER, raffle.as_ref(), uid.as_ref()], &ID).0
+}
+
+/// Fee split of a single raffle
+pub fn find_fee_config_pda(raffle: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[PREFIX, raffle.as_ref(), FEE], &ID).0
+}
+
+/// Global fee split, each change is a new `version` starting from 1
+pub fn find_global_fee_config_pda(version: u32) -> Pubkey {
+    Pubkey::find_program_address(&[PREFIX, FEE, &version.to_be_bytes()], &ID).0
+}
+
+pub fn find_whitelist_pda(key: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[PREFIX, WHITELIST, key.as_ref()], &ID).0
+}
+
+pub fn find_referral_pda(referrer: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[PREFIX, REFERRAL, referrer.as_ref(), mint.as_ref()], &ID).0
+}
+
+pub fn find_metadata_pda(mint: &Pubkey) -> Pubkey {
+    mpl_token_metadata::pda::find_metadata_account(mint).0
+}
+
+pub fn find_edition_pda(mint: &Pubkey) -> Pubkey {
+    mpl_token_metadata::pda::find_master_edition_account(mint).0
+}
+
+/// Token record of a pNFT held by `token_account`
+pub fn find_token_record_pda(mint: &Pubkey, token_account: &Pubkey) -> Pubkey {
+    mpl_token_metadata::pda::find_token_record_account(mint, token_account).0
+}
+
+pub fn find_tree_authority_pda(merkle_tree: &Pubkey) -> Pubkey {
+    bubblegum::tree_authority(merkle_tree)
+}
+
+/// Account paying or receiving `mint`, the wallet itself for native SOL
+pub fn find_payment_account(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    if *mint == token::spl_token::native_mint::ID {
+        *owner
+    } else {
+        get_associated_token_address_with_program_id(owner, mint, token_program)
+    }
+}
+
+/// Every ticket shard in order, passed where the program needs final sales totals
+pub fn shard_accounts(idx: u32, total_shards: u8) -> Vec<AccountMeta> {
+    (0..total_shards)
+        .map(|shard| AccountMeta::new_readonly(find_shard_pda(idx, shard), false))
+        .collect()
+}
+
+/// Every ticket shard in order followed by their escrows, passed where the
+/// program pays out of ticket proceeds
+pub fn shard_escrow_accounts(idx: u32, total_shards: u8) -> Vec<AccountMeta> {
+    let raffle = find_raffle_pda(idx);
+    let mut accounts = shard_accounts(idx, total_shards);
+    accounts.extend(
+        (0..total_shards)
+            .map(|shard| AccountMeta::new(find_shard_escrow_pda(&raffle, shard), false)),
+    );
+    accounts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Seeds are spelled out as the program derives them, so a changed constant or layout fails here
+
+    fn pda(seeds: &[&[u8]]) -> Pubkey {
+        Pubkey::find_program_address(seeds, &ID).0
+    }
+
+    #[test]
+    fn global_and_event_authority() {
+        assert_eq!(find_global_pda(), pda(&[b"solana-raffle"]));
+        assert_eq!(find_event_authority_pda(), pda(&[b"__event_authority"]));
+    }
+
+    #[test]
+    fn raffle_and_shards() {
+        let raffle = pda(&[b"solana-raffle", b"raffle", &[0, 0, 1, 2]]);
+        assert_eq!(find_raffle_pda(0x0102), raffle);
+        assert_eq!(
+            find_shard_pda(0x0102, 3),
+            pda(&[b"solana-raffle", b"raffle", &[0, 0, 1, 2], &[3]])
+        );
+        assert_ne!(find_shard_pda(0x0102, 0), raffle);
+
+        let shards = shard_accounts(0x0102, 3);
+        assert_eq!(shards.len(), 3);
+        for (shard, meta) in shards.iter().enumerate() {
+            assert_eq!(meta.pubkey, find_shard_pda(0x0102, shard as u8));
+            assert!(!meta.is_signer && !meta.is_writable);
+        }
+
+        // Escrows follow the shards, writable as proceeds are paid out of them
+        let accounts = shard_escrow_accounts(0x0102, 3);
+        assert_eq!(accounts[..3], shards[..]);
+        for (shard, meta) in accounts[3..].iter().enumerate() {
+            assert_eq!(
+                meta.pubkey,
+                pda(&[b"solana-raffle", raffle.as_ref(), b"escrow", &[shard as u8]])
+            );
+            assert_eq!(meta.pubkey, find_shard_escrow_pda(&raffle, shard as u8));
+            assert!(!meta.is_signer && meta.is_writable);
+        }
+    }
+
+    #[test]
+    fn raffle_escrows_and_users() {
+        let raffle = find_raffle_pda(7);
+        assert_eq!(
+            find_escrow_pda(&raffle),
+            pda(&[b"solana-raffle", raffle.as_ref(), b"escrow"])
+        );
+        assert_eq!(
+            find_prize_escrow_pda(&raffle, 2),
+            pda(&[b"solana-raffle", raffle.as_ref(), b"prize", &[2]])
+        );
+
+        // User accounts are derived without the program prefix
+        let uid = [0xab; ID_LENGTH];
+        assert_eq!(
+            find_user_pda(&raffle, &uid),
+            pda(&[b"user", raffle.as_ref(), &uid])
+        );
+    }
+
+    #[test]
+    fn fees_whitelist_and_referrals() {
+        let raffle = find_raffle_pda(7);
+        assert_eq!(
+            find_global_fee_config_pda(2),
+            pda(&[b"solana-raffle", b"fee", &2u32.to_be_bytes()])
+        );
+        assert_eq!(
+            find_fee_config_pda(&raffle),
+            pda(&[b"solana-raffle", raffle.as_ref(), b"fee"])
+        );
+
+        let key = Pubkey::new_unique();
+        assert_eq!(
+            find_whitelist_pda(&key),
+            pda(&[b"solana-raffle", b"wl", key.as_ref()])
+        );
+
+        let (referrer, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let referral = find_referral_pda(&referrer, &mint);
+        assert_eq!(
+            referral,
+            pda(&[
+                b"solana-raffle",
+                b"referral",
+                referrer.as_ref(),
+                mint.as_ref()
+            ])
+        );
+        assert_eq!(
+            find_escrow_pda(&referral),
+            pda(&[b"solana-raffle", referral.as_ref(), b"escrow"])
+        );
+    }
+
+    #[test]
+    fn payment_accounts() {
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        assert_eq!(
+            find_payment_account(&owner, &token::spl_token::native_mint::ID, &token::ID),
+            owner
+        );
+        assert_eq!(
+            find_payment_account(&owner, &mint, &token::ID),
+            get_associated_token_address_with_program_id(&owner, &mint, &token::ID)
+        );
+    }
+}
@@ -0,0 +1,343 @@
+use std::{thread, time::Duration};
+
+use anchor_lang::{
+    error::Error,
+    solana_program::{clock::Clock, hash::Hash, pubkey::Pubkey, sysvar},
+};
+use solana_account_decoder::UiAccountEncoding;
+use solana_raffle::{
+    constants::ID_LENGTH,
+    error::RaffleError,
+    states::{FeeConfig, GlobalAccount, RaffleAccountV2, UserAccount, WhitelistKind},
+};
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::{
+    client_error::{Error as ClientError, ErrorKind},
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::{Memcmp, RpcFilterType},
+    request::{RpcError as RpcRequestError, RpcResponseErrorData},
+};
+use solana_sdk::{
+    account::Account, commitment_config::CommitmentConfig, signature::Signature,
+    transaction::Transaction,
+};
+use thiserror::Error;
+
+use crate::{accounts, errors, pda::*};
+
+/// Polls of signature status before giving up on confirmation
+const CONFIRM_RETRIES: usize = 60;
+const CONFIRM_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Error)]
+pub enum RpcError {
+    #[error("rpc error: {0}")]
+    Client(Box<ClientError>),
+
+    #[error("account {0} not found")]
+    AccountNotFound(Pubkey),
+
+    #[error("account decode error: {0}")]
+    Decode(String),
+
+    #[error("transaction {signature} failed: {error}")]
+    Transaction { signature: Signature, error: String },
+
+    #[error("transaction {0} not confirmed")]
+    NotConfirmed(Signature),
+}
+
+impl RpcError {
+    /// Program error behind a failed preflight, if raised by the raffle program
+    pub fn raffle_error(&self) -> Option<RaffleError> {
+        match self {
+            Self::Client(error) => match error.kind() {
+                ErrorKind::RpcError(RpcRequestError::RpcResponseError {
+                    data: RpcResponseErrorData::SendTransactionPreflightFailure(simulation),
+                    ..
+                }) => errors::from_logs(simulation.logs.as_deref().unwrap_or_default()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl From<ClientError> for RpcError {
+    fn from(error: ClientError) -> Self {
+        Self::Client(Box::new(error))
+    }
+}
+
+impl From<anchor_lang::error::Error> for RpcError {
+    fn from(error: anchor_lang::error::Error) -> Self {
+        Self::Decode(error.to_string())
+    }
+}
+
+pub type Result<T> = std::result::Result<T, RpcError>;
+
+/// Outcome of a simulated transaction
+#[derive(Clone, Debug, Default)]
+pub struct Simulation {
+    pub err: Option<String>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+}
+
+impl Simulation {
+    pub fn raffle_error(&self) -> Option<RaffleError> {
+        errors::from_logs(&self.logs)
+    }
+}
+
+/// Cluster access needed by off-chain tooling, implemented over JSON-RPC by
+/// [`ClientRpc`] and by in-memory mocks in tests
+pub trait Rpc {
+    /// `None` when the account does not exist
+    fn get_account(&self, key: &Pubkey) -> Result<Option<Account>>;
+
+    /// Accounts owned by `program_id` whose data matches every `(offset, bytes)` filter
+    fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: &[(usize, Vec<u8>)],
+    ) -> Result<Vec<(Pubkey, Vec<u8>)>>;
+
+    fn get_slot(&self) -> Result<u64>;
+
+    fn get_latest_blockhash(&self) -> Result<Hash>;
+
+    fn simulate_transaction(&self, tx: &Transaction) -> Result<Simulation>;
+
+    fn send_transaction(&self, tx: &Transaction) -> Result<Signature>;
+
+    /// `None` while pending, transaction error once failed
+    fn get_signature_status(
+        &self,
+        signature: &Signature,
+    ) -> Result<Option<std::result::Result<(), String>>>;
+
+    fn get_account_data(&self, key: &Pubkey) -> Result<Option<Vec<u8>>> {
+        Ok(self.get_account(key)?.map(|account| account.data))
+    }
+
+    fn get_clock(&self) -> Result<Clock> {
+        let data = self
+            .get_account_data(&sysvar::clock::ID)?
+            .ok_or(RpcError::AccountNotFound(sysvar::clock::ID))?;
+        bincode::deserialize(&data).map_err(|err| RpcError::Decode(err.to_string()))
+    }
+
+    fn send_and_confirm(&self, tx: &Transaction) -> Result<Signature> {
+        let signature = self.send_transaction(tx)?;
+        for _ in 0..CONFIRM_RETRIES {
+            match self.get_signature_status(&signature)? {
+                Some(Ok(())) => return Ok(signature),
+                Some(Err(error)) => return Err(RpcError::Transaction { signature, error }),
+                None => thread::sleep(CONFIRM_INTERVAL),
+            }
+        }
+
+        Err(RpcError::NotConfirmed(signature))
+    }
+}
+
+pub fn fetch_global<R: Rpc + ?Sized>(rpc: &R) -> Result<GlobalAccount> {
+    let key = find_global_pda();
+    let data = rpc
+        .get_account_data(&key)?
+        .ok_or(RpcError::AccountNotFound(key))?;
+    Ok(accounts::decode_global(&data)?)
+}
+
+pub fn fetch_raffle<R: Rpc + ?Sized>(rpc: &R, idx: u32) -> Result<RaffleAccountV2> {
+    let key = find_raffle_pda(idx);
+    let data = rpc
+        .get_account_data(&key)?
+        .ok_or(RpcError::AccountNotFound(key))?;
+    Ok(accounts::decode_raffle(&data)?)
+}
+
+pub fn fetch_user<R: Rpc + ?Sized>(
+    rpc: &R,
+    idx: u32,
+    uid: &[u8; ID_LENGTH],
+) -> Result<UserAccount> {
+    let key = find_user_pda(&find_raffle_pda(idx), uid);
+    let data = rpc
+        .get_account_data(&key)?
+        .ok_or(RpcError::AccountNotFound(key))?;
+    Ok(accounts::decode_user(&data)?)
+}
+
+/// Shard holding the winning ticket of prize `slot` and the user account of
+/// its buyer, resolved as `RaffleAccountV2::claimable_prize` does
+pub fn fetch_winner<R: Rpc + ?Sized>(
+    rpc: &R,
+    raffle: &RaffleAccountV2,
+    slot: u8,
+) -> Result<(u8, UserAccount)> {
+    let winner_idx = raffle
+        .prizes()
+        .get(slot as usize)
+        .and_then(|prize| prize.winner_idx.checked_sub(1))
+        .ok_or_else(|| Error::from(RaffleError::WinnerNotRevealed))?;
+    let (shard, shard_idx) = raffle
+        .locate_ticket(winner_idx)
+        .ok_or_else(|| Error::from(RaffleError::WinnerNotMatched))?;
+
+    let key = find_shard_pda(raffle.idx, shard);
+    let data = rpc
+        .get_account_data(&key)?
+        .ok_or(RpcError::AccountNotFound(key))?;
+    let ticket = accounts::decode_ticket_shard(&data)?
+        .1
+        .into_iter()
+        .find(|ticket| {
+            shard_idx >= ticket.start_idx && shard_idx - ticket.start_idx < ticket.amount
+        })
+        .ok_or_else(|| Error::from(RaffleError::WinnerNotMatched))?;
+
+    Ok((shard, fetch_user(rpc, raffle.idx, &ticket.uid)?))
+}
+
+/// Fee split in effect for `raffle`: its own one, else the global version
+/// snapshot at its creation if any
+pub fn fetch_fee_config<R: Rpc + ?Sized>(
+    rpc: &R,
+    raffle: &RaffleAccountV2,
+) -> Result<Option<FeeConfig>> {
+    let key = if raffle.has_fee_config != 0 {
+        find_fee_config_pda(&find_raffle_pda(raffle.idx))
+    } else if raffle.fee_config_version != 0 {
+        find_global_fee_config_pda(raffle.fee_config_version)
+    } else {
+        return Ok(None);
+    };
+    let data = rpc
+        .get_account_data(&key)?
+        .ok_or(RpcError::AccountNotFound(key))?;
+    Ok(Some(accounts::decode_fee_config(&data)?))
+}
+
+/// First of `candidates` backed by an enabled [`WhitelistEntry`] of matching kind,
+/// see [`accounts::prize_whitelist_candidates`]
+///
+/// [`WhitelistEntry`]: solana_raffle::states::WhitelistEntry
+pub fn fetch_prize_whitelist_key<R: Rpc + ?Sized>(
+    rpc: &R,
+    candidates: &[(Pubkey, WhitelistKind)],
+) -> Result<Option<Pubkey>> {
+    for (key, kind) in candidates {
+        let Some(data) = rpc.get_account_data(&find_whitelist_pda(key))? else {
+            continue;
+        };
+        let Ok(entry) = accounts::decode_whitelist_entry(&data) else {
+            continue;
+        };
+        if entry.is_enabled != 0 && entry.kind() == *kind {
+            return Ok(Some(*key));
+        }
+    }
+
+    Ok(None)
+}
+
+/// [`Rpc`] over the JSON-RPC client of the solana SDK
+pub struct ClientRpc {
+    client: RpcClient,
+}
+
+impl ClientRpc {
+    /// Client at `url` with `confirmed` commitment, giving up requests after `timeout`
+    pub fn new(url: &str, timeout: Duration) -> Self {
+        RpcClient::new_with_timeout_and_commitment(
+            url.to_string(),
+            timeout,
+            CommitmentConfig::confirmed(),
+        )
+        .into()
+    }
+
+    pub fn client(&self) -> &RpcClient {
+        &self.client
+    }
+}
+
+impl From<RpcClient> for ClientRpc {
+    fn from(client: RpcClient) -> Self {
+        Self { client }
+    }
+}
+
+impl Rpc for ClientRpc {
+    fn get_account(&self, key: &Pubkey) -> Result<Option<Account>> {
+        Ok(self
+            .client
+            .get_account_with_commitment(key, self.client.commitment())?
+            .value)
+    }
+
+    fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: &[(usize, Vec<u8>)],
+    ) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(
+                filters
+                    .iter()
+                    .map(|(offset, bytes)| {
+                        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(*offset, bytes))
+                    })
+                    .collect(),
+            ),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(self.client.commitment()),
+                ..RpcAccountInfoConfig::default()
+            },
+            with_context: None,
+        };
+
+        Ok(self
+            .client
+            .get_program_accounts_with_config(program_id, config)?
+            .into_iter()
+            .map(|(key, account)| (key, account.data))
+            .collect())
+    }
+
+    fn get_slot(&self) -> Result<u64> {
+        Ok(self.client.get_slot()?)
+    }
+
+    fn get_latest_blockhash(&self) -> Result<Hash> {
+        Ok(self.client.get_latest_blockhash()?)
+    }
+
+    fn simulate_transaction(&self, tx: &Transaction) -> Result<Simulation> {
+        let result = self.client.simulate_transaction(tx)?.value;
+
+        Ok(Simulation {
+            err: result.err.map(|err| err.to_string()),
+            logs: result.logs.unwrap_or_default(),
+            units_consumed: result.units_consumed,
+        })
+    }
+
+    fn send_transaction(&self, tx: &Transaction) -> Result<Signature> {
+        Ok(self.client.send_transaction(tx)?)
+    }
+
+    fn get_signature_status(
+        &self,
+        signature: &Signature,
+    ) -> Result<Option<std::result::Result<(), String>>> {
+        let status = self
+            .client
+            .get_signature_status_with_commitment(signature, self.client.commitment())?;
+        Ok(status.map(|result| result.map_err(|err| err.to_string())))
+    }
+}
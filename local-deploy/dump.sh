@@ -0,0 +1,15 @@
+#!/bin/sh
+# Dump programs loaded in local validator genesis from mainnet
+set -e
+cd "$(dirname "$0")"
+
+dump() {
+  [ -f "$2.so" ] || solana program dump -u m "$1" "$2.so"
+}
+
+dump metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s mpl_token_metadata
+dump auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg mpl_token_auth_rules
+dump CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d mpl_core
+dump BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY mpl_bubblegum
+dump cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK spl_account_compression
+dump noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV spl_noop
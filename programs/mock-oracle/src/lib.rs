@@ -0,0 +1,98 @@
+#![allow(clippy::result_large_err)]
+
+use anchor_lang::prelude::*;
+
+declare_id!("VRFzZoJdhFWL8rkvu87LpKM3RbcVezpMEc6X5GVDr7y");
+
+pub const RANDOMNESS_ACCOUNT_SEED: &[u8] = b"orao-vrf-randomness-request";
+
+/// Mock of ORAO VRF program, where randomness is fulfilled by any signer
+#[program]
+pub mod mock_oracle {
+    use super::*;
+
+    /// Open randomness request for `seed`
+    pub fn request(ctx: Context<RequestCtx>, seed: [u8; 32]) -> Result<()> {
+        let request = &mut ctx.accounts.request;
+        request.seed = seed;
+        request.randomness = [0; 64];
+
+        Ok(())
+    }
+
+    /// Fulfill randomness request with given value
+    pub fn fulfill(ctx: Context<FulfillCtx>, _seed: [u8; 32], randomness: [u8; 64]) -> Result<()> {
+        let request = &mut ctx.accounts.request;
+        require!(
+            request.randomness.iter().all(|byte| *byte == 0),
+            MockOracleError::AlreadyFulfilled
+        );
+
+        request.randomness = randomness;
+        request.responses.push(RandomnessResponse {
+            pubkey: ctx.accounts.authority.key(),
+            randomness,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(seed: [u8; 32])]
+pub struct RequestCtx<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [RANDOMNESS_ACCOUNT_SEED, &seed],
+        bump,
+        payer = payer,
+        space = Randomness::SIZE,
+    )]
+    pub request: Account<'info, Randomness>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(seed: [u8; 32])]
+pub struct FulfillCtx<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RANDOMNESS_ACCOUNT_SEED, &seed],
+        bump,
+    )]
+    pub request: Account<'info, Randomness>,
+}
+
+/// Same layout as ORAO VRF randomness account
+#[account]
+pub struct Randomness {
+    pub seed: [u8; 32],
+    pub randomness: [u8; 64],
+    pub responses: Vec<RandomnessResponse>,
+}
+
+impl Randomness {
+    pub const SIZE: usize = 8 + 32 + 64 + 4 + RandomnessResponse::SIZE;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RandomnessResponse {
+    pub pubkey: Pubkey,
+    pub randomness: [u8; 64],
+}
+
+impl RandomnessResponse {
+    pub const SIZE: usize = 32 + 64;
+}
+
+#[error_code]
+pub enum MockOracleError {
+    #[msg("Randomness already fulfilled")]
+    AlreadyFulfilled,
+}
@@ -0,0 +1,174 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        keccak,
+    },
+};
+
+/// Metaplex Bubblegum program
+pub mod program {
+    use anchor_lang::declare_id;
+
+    declare_id!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+}
+
+/// SPL account compression program
+pub mod compression {
+    use anchor_lang::declare_id;
+
+    declare_id!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+}
+
+/// SPL noop program used as log wrapper
+pub mod noop {
+    use anchor_lang::declare_id;
+
+    declare_id!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+}
+
+pub const BUBBLEGUM_ID: Pubkey = program::ID;
+
+/// Anchor discriminant of Bubblegum `transfer`
+const TRANSFER: [u8; 8] = [163, 52, 200, 231, 140, 3, 69, 186];
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenStandard {
+    NonFungible,
+    FungibleAsset,
+    Fungible,
+    NonFungibleEdition,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UseMethod {
+    Burn,
+    Multiple,
+    Single,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Uses {
+    pub use_method: UseMethod,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Collection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenProgramVersion {
+    Original,
+    Token2022,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+/// Metadata of a compressed nft, layout must match Bubblegum to reproduce leaf hashes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct MetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<TokenStandard>,
+    pub collection: Option<Collection>,
+    pub uses: Option<Uses>,
+    pub token_program_version: TokenProgramVersion,
+    pub creators: Vec<Creator>,
+}
+
+impl MetadataArgs {
+    pub fn data_hash(&self) -> Result<[u8; 32]> {
+        let metadata_hash = keccak::hash(&self.try_to_vec()?);
+        Ok(keccak::hashv(&[
+            metadata_hash.as_ref(),
+            &self.seller_fee_basis_points.to_le_bytes(),
+        ])
+        .to_bytes())
+    }
+
+    pub fn creator_hash(&self) -> [u8; 32] {
+        let creators: Vec<u8> = self
+            .creators
+            .iter()
+            .flat_map(|creator| {
+                [
+                    creator.address.as_ref(),
+                    &[creator.verified as u8, creator.share],
+                ]
+                .concat()
+            })
+            .collect();
+        keccak::hash(&creators).to_bytes()
+    }
+}
+
+/// Asset id of the leaf minted with `nonce` into `merkle_tree`
+pub fn asset_id(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"asset", merkle_tree.as_ref(), &nonce.to_le_bytes()],
+        &BUBBLEGUM_ID,
+    )
+    .0
+}
+
+/// Tree config PDA of `merkle_tree`
+pub fn tree_authority(merkle_tree: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[merkle_tree.as_ref()], &BUBBLEGUM_ID).0
+}
+
+/// Transfer leaf to `new_leaf_owner`, signed by leaf owner, proof accounts appended
+pub fn transfer(
+    merkle_tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    leaf_delegate: &Pubkey,
+    new_leaf_owner: &Pubkey,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+    proof: &[AccountInfo],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(tree_authority(merkle_tree), false),
+        AccountMeta::new_readonly(*leaf_owner, true),
+        AccountMeta::new_readonly(*leaf_delegate, false),
+        AccountMeta::new_readonly(*new_leaf_owner, false),
+        AccountMeta::new(*merkle_tree, false),
+        AccountMeta::new_readonly(noop::ID, false),
+        AccountMeta::new_readonly(compression::ID, false),
+        AccountMeta::new_readonly(System::id(), false),
+    ];
+    accounts.extend(
+        proof
+            .iter()
+            .map(|node| AccountMeta::new_readonly(*node.key, false)),
+    );
+
+    let mut data = TRANSFER.to_vec();
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&data_hash);
+    data.extend_from_slice(&creator_hash);
+    data.extend_from_slice(&nonce.to_le_bytes());
+    data.extend_from_slice(&index.to_le_bytes());
+
+    Instruction {
+        program_id: BUBBLEGUM_ID,
+        accounts,
+        data,
+    }
+}
@@ -1,13 +1,30 @@
 pub const PREFIX: &[u8] = b"solana-raffle";
 pub const ESCROW: &[u8] = b"escrow";
+pub const PRIZE: &[u8] = b"prize";
 pub const RAFFLE: &[u8] = b"raffle";
 pub const USER: &[u8] = b"user";
+pub const FEE: &[u8] = b"fee";
+pub const REFERRAL: &[u8] = b"referral";
+pub const WHITELIST: &[u8] = b"wl";
 
-pub const FEE_PERCENT: usize = 100; // 1%
-pub const MAX_TICKET_PERCENT: usize = 4000; // 40%
+/// Defaults of global settings, updated by `update_fees`
+pub const FEE_PERCENT: u16 = 100; // 1%
+pub const MAX_TICKET_PERCENT: u16 = 4000; // 40%
+
+pub const MAX_FEE_PERCENT: u16 = 1000; // 10%
+pub const MAX_CRANK_REWARD_PERCENT: u16 = 100; // 1%
 pub const BASIS_POINTS: usize = 10000;
 
 pub const MAX_TICKET_AMOUNT: usize = 10000;
 
 pub const ID_LENGTH: usize = 12;
 pub const MAX_COLLECTIONS: usize = 10;
+pub const MAX_PRIZES: usize = 10;
+pub const MAX_SHARDS: usize = 16;
+pub const MAX_FEE_RECIPIENTS: usize = 5;
+
+/// Slots between randomness request and the slot whose hash is used
+pub const RANDOMNESS_SLOT_DELAY: u64 = 10;
+
+/// Slots after which a pending oracle request can be replaced, ~20 minutes
+pub const ORACLE_TIMEOUT_SLOTS: u64 = 3000;
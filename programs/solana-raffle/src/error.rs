@@ -88,4 +88,82 @@ pub enum RaffleError {
 
     #[msg("Whitelist is full")]
     WhitelistFull,
+
+    #[msg("Invalid prize slot")]
+    InvalidPrizeSlot,
+
+    #[msg("Winner already revealed")]
+    WinnerAlreadyRevealed,
+
+    #[msg("Invalid prize kind")]
+    InvalidPrizeKind,
+
+    #[msg("Raffle already has ticket sales")]
+    RaffleHasSales,
+
+    #[msg("Raffle has been cancelled")]
+    RaffleCancelled,
+
+    #[msg("Raffle not refundable")]
+    RaffleNotRefundable,
+
+    #[msg("Minimum sales not reached")]
+    MinSalesNotReached,
+
+    #[msg("Randomness already requested")]
+    RandomnessAlreadyRequested,
+
+    #[msg("Randomness not requested")]
+    RandomnessNotRequested,
+
+    #[msg("Randomness not ready")]
+    RandomnessNotReady,
+
+    #[msg("Randomness expired")]
+    RandomnessExpired,
+
+    #[msg("Invalid randomness account")]
+    InvalidRandomnessAccount,
+
+    #[msg("Invalid ticket shard")]
+    InvalidTicketShard,
+
+    #[msg("Invalid purchase permit")]
+    InvalidPermit,
+
+    #[msg("Purchase permit expired")]
+    PermitExpired,
+
+    #[msg("Purchase permit already used")]
+    PermitAlreadyUsed,
+
+    #[msg("Invalid fee config")]
+    InvalidFeeConfig,
+
+    #[msg("Invalid fee recipient")]
+    InvalidFeeRecipient,
+
+    #[msg("Invalid referral account")]
+    InvalidReferral,
+
+    #[msg("Self referral not allowed")]
+    SelfReferral,
+
+    #[msg("Unsupported mint extension")]
+    UnsupportedMintExtension,
+
+    #[msg("Invalid core asset")]
+    InvalidAsset,
+
+    #[msg("Early reveal restricted to authority")]
+    EarlyRevealNotAllowed,
+
+    #[msg("Ticket shards not created")]
+    ShardsNotCreated,
+
+    #[msg("Invalid shard escrow")]
+    InvalidShardEscrow,
+
+    #[msg("Shard fees not swept")]
+    FeesNotSwept,
 }
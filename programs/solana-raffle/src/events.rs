@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::ID_LENGTH,
+    states::{FeeRecipient, PrizeKind, RandomnessSource, WhitelistKind},
+};
+
+/// Raffle registered by creator
+#[event]
+pub struct RaffleCreated {
+    pub raffle: Pubkey,
+    pub idx: u32,
+    pub authority: Pubkey,
+    pub spl_mint: Pubkey,
+    pub total_supply: u32,
+    pub total_prizes: u8,
+    pub price: u64,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub min_sales: u32,
+    pub total_shards: u8,
+    pub is_public: bool,
+}
+
+/// Ticket shard of raffle created by creator
+#[event]
+pub struct ShardCreated {
+    pub raffle: Pubkey,
+    pub idx: u32,
+    pub shard: u8,
+}
+
+/// Prize deposited into a raffle slot
+#[event]
+pub struct PrizeAdded {
+    pub raffle: Pubkey,
+    pub idx: u32,
+    pub slot: u8,
+    pub kind: PrizeKind,
+    /// Mint, Core asset or compressed asset id
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Ticket range bought, `start_idx` is local to the ticket shard
+#[event]
+pub struct TicketsBought {
+    pub raffle: Pubkey,
+    pub idx: u32,
+    pub buyer: Pubkey,
+    pub uid: [u8; ID_LENGTH],
+    pub shard: u8,
+    pub start_idx: u32,
+    pub amount: u32,
+    /// Total paid by buyer, fee included
+    pub paid: u64,
+    pub fee: u64,
+    /// Slice of the fee credited to `referrer`
+    pub referral_fee: u64,
+    pub referrer: Option<Pubkey>,
+}
+
+/// Fees held by a shard escrow paid out to the vault or fee config recipients
+#[event]
+pub struct FeesSwept {
+    pub raffle: Pubkey,
+    pub idx: u32,
+    pub shard: u8,
+    pub amount: u64,
+}
+
+/// Randomness committed to for drawing winners
+#[event]
+pub struct RandomnessRequested {
+    pub raffle: Pubkey,
+    pub idx: u32,
+    pub source: RandomnessSource,
+    pub request: Pubkey,
+    pub slot: u64,
+    pub total_sales: u32,
+    pub requester: Pubkey,
+    /// Paid to `requester` from escrow, zero on early reveal by authority
+    pub crank_reward: u64,
+}
+
+/// Seed material and outcome of a raffle draw
+#[event]
+pub struct WinnersRevealed {
+    pub raffle: Pubkey,
+    pub idx: u32,
+    pub source: RandomnessSource,
+    pub request: Pubkey,
+    pub slot: u64,
+    pub value: [u8; 64],
+    pub seed: [u8; 32],
+    pub timestamp: u64,
+    pub total_sales: u32,
+    /// 1-based winning ticket index of every prize slot
+    pub winners: Vec<u32>,
+}
+
+/// Prize sent to winner
+#[event]
+pub struct PrizeClaimed {
+    pub raffle: Pubkey,
+    pub idx: u32,
+    pub slot: u8,
+    pub kind: PrizeKind,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub winner: Pubkey,
+    pub uid: [u8; ID_LENGTH],
+}
+
+/// Prize returned to creator
+#[event]
+pub struct PrizeReclaimed {
+    pub raffle: Pubkey,
+    pub idx: u32,
+    pub slot: u8,
+    pub kind: PrizeKind,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Ticket sales paid out to creator
+#[event]
+pub struct RaffleWithdrawn {
+    pub raffle: Pubkey,
+    pub idx: u32,
+    pub treasury: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RaffleCancelled {
+    pub raffle: Pubkey,
+    pub idx: u32,
+}
+
+/// Deposit of a user returned after cancel or missed minimum sales
+#[event]
+pub struct TicketsRefunded {
+    pub raffle: Pubkey,
+    pub idx: u32,
+    pub buyer: Pubkey,
+    pub uid: [u8; ID_LENGTH],
+    pub amount: u64,
+}
+
+#[event]
+pub struct ReferralInitialized {
+    pub referrer: Pubkey,
+    pub referral: Pubkey,
+}
+
+#[event]
+pub struct ReferralClaimed {
+    pub referrer: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Whitelist entry added, removed or migrated from the legacy list
+#[event]
+pub struct WhitelistUpdated {
+    pub key: Pubkey,
+    pub kind: WhitelistKind,
+    pub is_enabled: bool,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ProgramInitialized {
+    pub authority: Pubkey,
+    pub vault: Pubkey,
+    pub permit_signer: Pubkey,
+}
+
+#[event]
+pub struct SettingsUpdated {
+    pub authority: Pubkey,
+    pub vault: Pubkey,
+    pub permit_signer: Pubkey,
+}
+
+#[event]
+pub struct FeesUpdated {
+    pub fee_percent: u16,
+    pub max_ticket_percent: u16,
+    pub referral_percent: u16,
+    pub crank_reward_percent: u16,
+}
+
+/// Fee split set, `raffle` is default key for the global split
+#[event]
+pub struct FeeConfigUpdated {
+    pub raffle: Pubkey,
+    pub recipients: Vec<FeeRecipient>,
+}
@@ -1,13 +1,23 @@
+#![allow(clippy::result_large_err, clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
 
+pub mod bubblegum;
 pub mod constants;
 pub mod error;
+pub mod events;
+pub mod mpl_core;
+pub mod permit;
 pub mod processor;
+pub mod randomness;
 pub mod states;
 pub mod utils;
 
+use crate::bubblegum::MetadataArgs;
 use crate::constants::ID_LENGTH;
+use crate::permit::BuyPermit;
 use crate::processor::*;
+use crate::states::{FeeRecipient, RandomnessSource, WhitelistKind};
 
 declare_id!("GFkrs8CmUsviDmAgQRBuj9grwHe5jqKCGjmsuV8CBH7L");
 
@@ -24,59 +34,358 @@ pub mod solana_raffle {
         processor::update_setting(ctx)
     }
 
-    pub fn add_whitelist(ctx: Context<AddWhitelistCtx>, collection: Pubkey) -> Result<()> {
-        processor::add_whitelist(ctx, collection)
+    pub fn update_fees(
+        ctx: Context<UpdateFeesCtx>,
+        fee_percent: u16,
+        max_ticket_percent: u16,
+        referral_percent: u16,
+        crank_reward_percent: u16,
+    ) -> Result<()> {
+        processor::update_fees(
+            ctx,
+            fee_percent,
+            max_ticket_percent,
+            referral_percent,
+            crank_reward_percent,
+        )
+    }
+
+    pub fn set_fee_config(
+        ctx: Context<SetFeeConfigCtx>,
+        recipients: Vec<FeeRecipient>,
+    ) -> Result<()> {
+        processor::set_fee_config(ctx, recipients)
+    }
+
+    pub fn set_raffle_fee_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetRaffleFeeConfigCtx<'info>>,
+        idx: u32,
+        recipients: Vec<FeeRecipient>,
+    ) -> Result<()> {
+        processor::set_raffle_fee_config(ctx, idx, recipients)
+    }
+
+    pub fn add_whitelist(
+        ctx: Context<AddWhitelistCtx>,
+        key: Pubkey,
+        kind: WhitelistKind,
+    ) -> Result<()> {
+        processor::add_whitelist(ctx, key, kind)
+    }
+
+    pub fn remove_whitelist(ctx: Context<RemoveWhitelistCtx>, key: Pubkey) -> Result<()> {
+        processor::remove_whitelist(ctx, key)
     }
 
-    pub fn remove_whitelist(ctx: Context<RemoveWhitelistCtx>, collection: Pubkey) -> Result<()> {
-        processor::remove_whitelist(ctx, collection)
+    pub fn migrate_whitelist(ctx: Context<MigrateWhitelistCtx>, collection: Pubkey) -> Result<()> {
+        processor::migrate_whitelist(ctx, collection)
     }
 
     pub fn create_raffle(
         ctx: Context<CreateRaffleCtx>,
         idx: u32,
         total_supply: u32,
+        total_prizes: u8,
         price: u64,
         start_date: u64,
         end_date: u64,
+        min_sales: u32,
+        total_shards: u8,
+        is_public: bool,
+        max_ticket_percent: Option<u16>,
     ) -> Result<()> {
-        processor::create_raffle(ctx, idx, total_supply, price, start_date, end_date)
+        processor::create_raffle(
+            ctx,
+            idx,
+            total_supply,
+            total_prizes,
+            price,
+            start_date,
+            end_date,
+            min_sales,
+            total_shards,
+            is_public,
+            max_ticket_percent,
+        )
     }
 
-    pub fn add_prize(ctx: Context<AddPrizeCtx>, idx: u32) -> Result<()> {
-        processor::add_prize(ctx, idx)
+    pub fn create_shard(ctx: Context<CreateShardCtx>, idx: u32, shard: u8) -> Result<()> {
+        processor::create_shard(ctx, idx, shard)
     }
 
-    pub fn add_prize_pnft(ctx: Context<AddPrizePnftCtx>, idx: u32) -> Result<()> {
-        processor::add_prize_pnft(ctx, idx)
+    pub fn add_prize(ctx: Context<AddPrizeCtx>, idx: u32, slot: u8) -> Result<()> {
+        processor::add_prize(ctx, idx, slot)
     }
 
-    pub fn buy_ticket(
-        ctx: Context<BuyTicketCtx>,
+    pub fn add_prize_pnft(ctx: Context<AddPrizePnftCtx>, idx: u32, slot: u8) -> Result<()> {
+        processor::add_prize_pnft(ctx, idx, slot)
+    }
+
+    pub fn add_prize_token(
+        ctx: Context<AddPrizeTokenCtx>,
+        idx: u32,
+        slot: u8,
+        amount: u64,
+    ) -> Result<()> {
+        processor::add_prize_token(ctx, idx, slot, amount)
+    }
+
+    pub fn add_prize_sol(
+        ctx: Context<AddPrizeSolCtx>,
+        idx: u32,
+        slot: u8,
+        amount: u64,
+    ) -> Result<()> {
+        processor::add_prize_sol(ctx, idx, slot, amount)
+    }
+
+    pub fn add_prize_2022(ctx: Context<AddPrize2022Ctx>, idx: u32, slot: u8) -> Result<()> {
+        processor::add_prize_2022(ctx, idx, slot)
+    }
+
+    pub fn add_prize_core(ctx: Context<AddPrizeCoreCtx>, idx: u32, slot: u8) -> Result<()> {
+        processor::add_prize_core(ctx, idx, slot)
+    }
+
+    pub fn add_prize_cnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddPrizeCnftCtx<'info>>,
+        idx: u32,
+        slot: u8,
+        root: [u8; 32],
+        metadata: MetadataArgs,
+        nonce: u64,
+        index: u32,
+    ) -> Result<()> {
+        processor::add_prize_cnft(ctx, idx, slot, root, metadata, nonce, index)
+    }
+
+    pub fn buy_ticket<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyTicketCtx<'info>>,
         idx: u32,
         uid: [u8; ID_LENGTH],
         amount: u32,
+        shard: u8,
+        permit: Option<BuyPermit>,
     ) -> Result<()> {
-        processor::buy_ticket(ctx, idx, uid, amount)
+        processor::buy_ticket(ctx, idx, uid, amount, shard, permit)
     }
 
-    pub fn withdraw_raffle(ctx: Context<WithdrawRaffleCtx>, idx: u32) -> Result<()> {
+    pub fn sweep_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, SweepFeesCtx<'info>>,
+        idx: u32,
+        shard: u8,
+    ) -> Result<()> {
+        processor::sweep_fees(ctx, idx, shard)
+    }
+
+    pub fn withdraw_raffle<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawRaffleCtx<'info>>,
+        idx: u32,
+    ) -> Result<()> {
         processor::withdraw_raffle(ctx, idx)
     }
 
-    pub fn reveal_winner(ctx: Context<RevealWinnerCtx>, idx: u32) -> Result<()> {
-        processor::reveal_winner(ctx, idx)
+    pub fn push_proceeds<'info>(
+        ctx: Context<'_, '_, '_, 'info, PushProceedsCtx<'info>>,
+        idx: u32,
+    ) -> Result<()> {
+        processor::push_proceeds(ctx, idx)
+    }
+
+    pub fn request_randomness<'info>(
+        ctx: Context<'_, '_, '_, 'info, RequestRandomnessCtx<'info>>,
+        idx: u32,
+        source: RandomnessSource,
+    ) -> Result<()> {
+        processor::request_randomness(ctx, idx, source)
     }
 
-    pub fn claim_prize(ctx: Context<ClaimPrizeCtx>, idx: u32, uid: [u8; ID_LENGTH]) -> Result<()> {
-        processor::claim_prize(ctx, idx, uid)
+    pub fn settle_randomness(ctx: Context<SettleRandomnessCtx>, idx: u32) -> Result<()> {
+        processor::settle_randomness(ctx, idx)
+    }
+
+    pub fn claim_prize(
+        ctx: Context<ClaimPrizeCtx>,
+        idx: u32,
+        slot: u8,
+        uid: [u8; ID_LENGTH],
+    ) -> Result<()> {
+        processor::claim_prize(ctx, idx, slot, uid)
     }
 
     pub fn claim_prize_pnft(
         ctx: Context<ClaimPrizePnftCtx>,
         idx: u32,
+        slot: u8,
+        uid: [u8; ID_LENGTH],
+    ) -> Result<()> {
+        processor::claim_prize_pnft(ctx, idx, slot, uid)
+    }
+
+    pub fn claim_prize_token(
+        ctx: Context<ClaimPrizeTokenCtx>,
+        idx: u32,
+        slot: u8,
+        uid: [u8; ID_LENGTH],
+    ) -> Result<()> {
+        processor::claim_prize_token(ctx, idx, slot, uid)
+    }
+
+    pub fn claim_prize_sol(
+        ctx: Context<ClaimPrizeSolCtx>,
+        idx: u32,
+        slot: u8,
+        uid: [u8; ID_LENGTH],
+    ) -> Result<()> {
+        processor::claim_prize_sol(ctx, idx, slot, uid)
+    }
+
+    pub fn claim_prize_2022(
+        ctx: Context<ClaimPrize2022Ctx>,
+        idx: u32,
+        slot: u8,
+        uid: [u8; ID_LENGTH],
+    ) -> Result<()> {
+        processor::claim_prize_2022(ctx, idx, slot, uid)
+    }
+
+    pub fn claim_prize_core(
+        ctx: Context<ClaimPrizeCoreCtx>,
+        idx: u32,
+        slot: u8,
         uid: [u8; ID_LENGTH],
     ) -> Result<()> {
-        processor::claim_prize_pnft(ctx, idx, uid)
+        processor::claim_prize_core(ctx, idx, slot, uid)
+    }
+
+    pub fn claim_prize_cnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimPrizeCnftCtx<'info>>,
+        idx: u32,
+        slot: u8,
+        uid: [u8; ID_LENGTH],
+        root: [u8; 32],
+    ) -> Result<()> {
+        processor::claim_prize_cnft(ctx, idx, slot, uid, root)
+    }
+
+    pub fn push_prize(
+        ctx: Context<PushPrizeCtx>,
+        idx: u32,
+        slot: u8,
+        uid: [u8; ID_LENGTH],
+    ) -> Result<()> {
+        processor::push_prize(ctx, idx, slot, uid)
+    }
+
+    pub fn push_prize_pnft(
+        ctx: Context<PushPrizePnftCtx>,
+        idx: u32,
+        slot: u8,
+        uid: [u8; ID_LENGTH],
+    ) -> Result<()> {
+        processor::push_prize_pnft(ctx, idx, slot, uid)
+    }
+
+    pub fn push_prize_token(
+        ctx: Context<PushPrizeTokenCtx>,
+        idx: u32,
+        slot: u8,
+        uid: [u8; ID_LENGTH],
+    ) -> Result<()> {
+        processor::push_prize_token(ctx, idx, slot, uid)
+    }
+
+    pub fn push_prize_sol(
+        ctx: Context<PushPrizeSolCtx>,
+        idx: u32,
+        slot: u8,
+        uid: [u8; ID_LENGTH],
+    ) -> Result<()> {
+        processor::push_prize_sol(ctx, idx, slot, uid)
+    }
+
+    pub fn push_prize_2022(
+        ctx: Context<PushPrize2022Ctx>,
+        idx: u32,
+        slot: u8,
+        uid: [u8; ID_LENGTH],
+    ) -> Result<()> {
+        processor::push_prize_2022(ctx, idx, slot, uid)
+    }
+
+    pub fn push_prize_core(
+        ctx: Context<PushPrizeCoreCtx>,
+        idx: u32,
+        slot: u8,
+        uid: [u8; ID_LENGTH],
+    ) -> Result<()> {
+        processor::push_prize_core(ctx, idx, slot, uid)
+    }
+
+    pub fn push_prize_cnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, PushPrizeCnftCtx<'info>>,
+        idx: u32,
+        slot: u8,
+        uid: [u8; ID_LENGTH],
+        root: [u8; 32],
+    ) -> Result<()> {
+        processor::push_prize_cnft(ctx, idx, slot, uid, root)
+    }
+
+    pub fn init_referral(ctx: Context<InitReferralCtx>) -> Result<()> {
+        processor::init_referral(ctx)
+    }
+
+    pub fn claim_referral(ctx: Context<ClaimReferralCtx>) -> Result<()> {
+        processor::claim_referral(ctx)
+    }
+
+    pub fn cancel_raffle(ctx: Context<CancelRaffleCtx>, idx: u32) -> Result<()> {
+        processor::cancel_raffle(ctx, idx)
+    }
+
+    pub fn refund_ticket<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundTicketCtx<'info>>,
+        idx: u32,
+        uid: [u8; ID_LENGTH],
+    ) -> Result<()> {
+        processor::refund_ticket(ctx, idx, uid)
+    }
+
+    pub fn reclaim_prize(ctx: Context<ReclaimPrizeCtx>, idx: u32, slot: u8) -> Result<()> {
+        processor::reclaim_prize(ctx, idx, slot)
+    }
+
+    pub fn reclaim_prize_pnft(ctx: Context<ReclaimPrizePnftCtx>, idx: u32, slot: u8) -> Result<()> {
+        processor::reclaim_prize_pnft(ctx, idx, slot)
+    }
+
+    pub fn reclaim_prize_token(
+        ctx: Context<ReclaimPrizeTokenCtx>,
+        idx: u32,
+        slot: u8,
+    ) -> Result<()> {
+        processor::reclaim_prize_token(ctx, idx, slot)
+    }
+
+    pub fn reclaim_prize_sol(ctx: Context<ReclaimPrizeSolCtx>, idx: u32, slot: u8) -> Result<()> {
+        processor::reclaim_prize_sol(ctx, idx, slot)
+    }
+
+    pub fn reclaim_prize_2022(ctx: Context<ReclaimPrize2022Ctx>, idx: u32, slot: u8) -> Result<()> {
+        processor::reclaim_prize_2022(ctx, idx, slot)
+    }
+
+    pub fn reclaim_prize_core(ctx: Context<ReclaimPrizeCoreCtx>, idx: u32, slot: u8) -> Result<()> {
+        processor::reclaim_prize_core(ctx, idx, slot)
+    }
+
+    pub fn reclaim_prize_cnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReclaimPrizeCnftCtx<'info>>,
+        idx: u32,
+        slot: u8,
+        root: [u8; 32],
+    ) -> Result<()> {
+        processor::reclaim_prize_cnft(ctx, idx, slot, root)
     }
 }
@@ -0,0 +1,184 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::instruction::{AccountMeta, Instruction},
+};
+
+use crate::error::RaffleError;
+
+/// Metaplex Core program
+pub mod program {
+    use anchor_lang::declare_id;
+
+    declare_id!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+}
+
+pub const MPL_CORE_ID: Pubkey = program::ID;
+
+/// Instruction discriminants of the Core program
+const ADD_PLUGIN_V1: u8 = 2;
+const UPDATE_PLUGIN_V1: u8 = 6;
+const REVOKE_PLUGIN_AUTHORITY_V1: u8 = 10;
+const TRANSFER_V1: u8 = 14;
+
+/// `Key::AssetV1` account discriminant
+const ASSET_V1: u8 = 1;
+/// `UpdateAuthority::Collection` discriminant
+const UPDATE_AUTHORITY_COLLECTION: u8 = 2;
+/// `PluginAuthority::Address` discriminant
+const AUTHORITY_ADDRESS: u8 = 3;
+
+/// Owner managed plugins used to hold a deposited asset
+#[derive(Clone, Copy)]
+pub enum CorePlugin {
+    FreezeDelegate { frozen: bool },
+    TransferDelegate,
+}
+
+impl CorePlugin {
+    fn plugin_type(&self) -> u8 {
+        match self {
+            Self::FreezeDelegate { .. } => 1,
+            Self::TransferDelegate => 3,
+        }
+    }
+
+    fn serialize(&self, data: &mut Vec<u8>) {
+        data.push(self.plugin_type());
+        if let Self::FreezeDelegate { frozen } = self {
+            data.push(*frozen as u8);
+        }
+    }
+}
+
+/// Header of a Core `AssetV1` account
+pub struct CoreAsset {
+    pub owner: Pubkey,
+    /// Collection the asset belongs to, set only by the collection authority
+    pub collection: Option<Pubkey>,
+}
+
+impl CoreAsset {
+    pub fn from_account_info(asset: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(*asset.owner, MPL_CORE_ID, RaffleError::InvalidAsset);
+        Self::decode(&asset.data.borrow())
+    }
+
+    /// Header of `AssetV1` account data, its owner program is not checked
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= 66 && data[0] == ASSET_V1,
+            RaffleError::InvalidAsset
+        );
+
+        let owner = Pubkey::try_from(&data[1..33]).unwrap();
+        let collection = if data[33] == UPDATE_AUTHORITY_COLLECTION {
+            Some(Pubkey::try_from(&data[34..66]).unwrap())
+        } else {
+            None
+        };
+
+        Ok(Self { owner, collection })
+    }
+}
+
+/// Account metas shared by Core plugin instructions, absent optional
+/// accounts are passed as the Core program id
+fn plugin_accounts(
+    asset: &Pubkey,
+    collection: Option<&Pubkey>,
+    payer: &Pubkey,
+    authority: &Pubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(*asset, false),
+        match collection {
+            Some(collection) => AccountMeta::new(*collection, false),
+            None => AccountMeta::new_readonly(MPL_CORE_ID, false),
+        },
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(System::id(), false),
+        AccountMeta::new_readonly(MPL_CORE_ID, false),
+    ]
+}
+
+/// Add owner managed plugin delegated to `delegate`, signed by asset owner
+pub fn add_plugin(
+    asset: &Pubkey,
+    collection: Option<&Pubkey>,
+    owner: &Pubkey,
+    plugin: CorePlugin,
+    delegate: &Pubkey,
+) -> Instruction {
+    let mut data = vec![ADD_PLUGIN_V1];
+    plugin.serialize(&mut data);
+    data.push(1);
+    data.push(AUTHORITY_ADDRESS);
+    data.extend_from_slice(delegate.as_ref());
+
+    Instruction {
+        program_id: MPL_CORE_ID,
+        accounts: plugin_accounts(asset, collection, owner, owner),
+        data,
+    }
+}
+
+/// Update plugin data, signed by plugin authority
+pub fn update_plugin(
+    asset: &Pubkey,
+    collection: Option<&Pubkey>,
+    payer: &Pubkey,
+    authority: &Pubkey,
+    plugin: CorePlugin,
+) -> Instruction {
+    let mut data = vec![UPDATE_PLUGIN_V1];
+    plugin.serialize(&mut data);
+
+    Instruction {
+        program_id: MPL_CORE_ID,
+        accounts: plugin_accounts(asset, collection, payer, authority),
+        data,
+    }
+}
+
+/// Hand plugin authority back to asset owner, signed by the delegate
+pub fn revoke_plugin_authority(
+    asset: &Pubkey,
+    collection: Option<&Pubkey>,
+    payer: &Pubkey,
+    authority: &Pubkey,
+    plugin: CorePlugin,
+) -> Instruction {
+    Instruction {
+        program_id: MPL_CORE_ID,
+        accounts: plugin_accounts(asset, collection, payer, authority),
+        data: vec![REVOKE_PLUGIN_AUTHORITY_V1, plugin.plugin_type()],
+    }
+}
+
+/// Transfer asset to `new_owner`, signed by owner or transfer delegate
+pub fn transfer(
+    asset: &Pubkey,
+    collection: Option<&Pubkey>,
+    payer: &Pubkey,
+    authority: &Pubkey,
+    new_owner: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: MPL_CORE_ID,
+        accounts: vec![
+            AccountMeta::new(*asset, false),
+            match collection {
+                Some(collection) => AccountMeta::new_readonly(*collection, false),
+                None => AccountMeta::new_readonly(MPL_CORE_ID, false),
+            },
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(*new_owner, false),
+            AccountMeta::new_readonly(System::id(), false),
+            AccountMeta::new_readonly(MPL_CORE_ID, false),
+        ],
+        // No compression proof
+        data: vec![TRANSFER_V1, 0],
+    }
+}
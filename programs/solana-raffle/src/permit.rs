@@ -0,0 +1,98 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program,
+        instruction::Instruction,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+};
+
+use crate::{constants::*, error::RaffleError};
+
+/// Size of signature offsets entry in ed25519 program instruction data
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+const SIGNATURE_OFFSETS_START: usize = 2;
+
+/// Purchase permit issued off-chain by the global permit signer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct BuyPermit {
+    /// Max tickets allowed to buy with this permit
+    pub max_amount: u32,
+    /// Unix timestamp after which the permit is rejected
+    pub expiry: u64,
+    /// Must be greater than the last nonce used by the user account
+    pub nonce: u64,
+}
+
+impl BuyPermit {
+    /// Message signed by the permit signer,
+    /// `PREFIX || idx || uid || buyer || max_amount || expiry || nonce`
+    pub fn message(&self, idx: u32, uid: &[u8; ID_LENGTH], buyer: &Pubkey) -> Vec<u8> {
+        let mut message = Vec::with_capacity(PREFIX.len() + 68);
+        message.extend_from_slice(PREFIX);
+        message.extend_from_slice(&idx.to_le_bytes());
+        message.extend_from_slice(uid);
+        message.extend_from_slice(buyer.as_ref());
+        message.extend_from_slice(&self.max_amount.to_le_bytes());
+        message.extend_from_slice(&self.expiry.to_le_bytes());
+        message.extend_from_slice(&self.nonce.to_le_bytes());
+        message
+    }
+
+    /// Check the instruction right before the current one verifies `signer`
+    /// signature of the permit message with the ed25519 program
+    pub fn verify(
+        &self,
+        instructions: &AccountInfo,
+        signer: &Pubkey,
+        idx: u32,
+        uid: &[u8; ID_LENGTH],
+        buyer: &Pubkey,
+    ) -> Result<()> {
+        let current = load_current_index_checked(instructions)?;
+        let prev = current.checked_sub(1).ok_or(RaffleError::InvalidPermit)?;
+        let ix = load_instruction_at_checked(prev as usize, instructions)?;
+
+        verify_ed25519_ix(&ix, signer, &self.message(idx, uid, buyer))
+    }
+}
+
+/// Validate ed25519 program instruction holds a single signature of `message`
+/// by `signer`, with all data inlined in the instruction itself
+pub fn verify_ed25519_ix(ix: &Instruction, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    require!(
+        ix.program_id == ed25519_program::ID && ix.accounts.is_empty(),
+        RaffleError::InvalidPermit
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE && data[0] == 1,
+        RaffleError::InvalidPermit
+    );
+
+    let offsets = &data[SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE];
+    let read_u16 = |pos: usize| u16::from_le_bytes([offsets[pos], offsets[pos + 1]]);
+
+    // Offsets referencing other instructions would verify data we don't check
+    require!(
+        read_u16(2) == u16::MAX && read_u16(6) == u16::MAX && read_u16(12) == u16::MAX,
+        RaffleError::InvalidPermit
+    );
+
+    let pubkey_offset = read_u16(4) as usize;
+    let message_offset = read_u16(8) as usize;
+    let message_size = read_u16(10) as usize;
+
+    let pubkey = data
+        .get(pubkey_offset..pubkey_offset + 32)
+        .ok_or(RaffleError::InvalidPermit)?;
+    require!(pubkey == signer.as_ref(), RaffleError::InvalidPermit);
+
+    let signed = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(RaffleError::InvalidPermit)?;
+    require!(signed == message, RaffleError::InvalidPermit);
+
+    Ok(())
+}
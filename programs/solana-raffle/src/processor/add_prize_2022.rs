@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    constants::*,
+    error::*,
+    events::PrizeAdded,
+    states::*,
+    utils::{
+        assert_decode_metadata, assert_escrowable_mint, assert_prize_eligible, bump,
+        create_program_token_account_if_not_present, PrizeOrigin,
+    },
+};
+
+/// Add prize Token-2022 NFT by creator, moved into a prize escrow
+pub fn add_prize_2022(ctx: Context<AddPrize2022Ctx>, idx: u32, slot: u8) -> Result<()> {
+    let raffle_key = ctx.accounts.raffle_account.key();
+    let mint_key = ctx.accounts.mint.key();
+    {
+        let raffle_account = ctx.accounts.raffle_account.load()?;
+        let prize = raffle_account
+            .prizes()
+            .get(slot as usize)
+            .ok_or(RaffleError::InvalidPrizeSlot)?;
+        require!(prize.is_deposited == 0, RaffleError::PrizeAlreadyDeposited);
+        require!(
+            raffle_account.is_cancelled == 0,
+            RaffleError::RaffleCancelled
+        );
+        require!(
+            !raffle_account
+                .prizes()
+                .iter()
+                .any(|prize| prize.is_deposited != 0 && prize.mint == mint_key),
+            RaffleError::PrizeAlreadyDeposited
+        );
+    }
+
+    // Escrowing the token is only safe when no extension can move it out
+    let mint_info = ctx.accounts.mint.to_account_info();
+    require_keys_eq!(
+        *mint_info.owner,
+        spl_token_2022::ID,
+        RaffleError::InvalidMint
+    );
+    assert_escrowable_mint(&mint_info)?;
+
+    // Check collection is whitelisted
+    let metadata = assert_decode_metadata(&mint_key, &ctx.accounts.metadata)?;
+    assert_prize_eligible(
+        &PrizeOrigin::from_metadata(&metadata),
+        &ctx.accounts.whitelist_entry,
+        ctx.program_id,
+    )?;
+
+    // Deposit nft to prize escrow owned by raffle
+    let escrow_seeds = &[
+        PREFIX,
+        raffle_key.as_ref(),
+        PRIZE,
+        &[slot],
+        &[bump(
+            &[PREFIX, raffle_key.as_ref(), PRIZE, &[slot]],
+            ctx.program_id,
+        )],
+    ];
+    create_program_token_account_if_not_present(
+        &ctx.accounts.prize_escrow,
+        &ctx.accounts.system_program,
+        &ctx.accounts.authority,
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.raffle_account.to_account_info(),
+        &ctx.accounts.rent,
+        &escrow_seeds[..],
+        &[],
+        false,
+    )?;
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.token_account.to_account_info(),
+                mint: mint_info,
+                to: ctx.accounts.prize_escrow.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        1,
+        0,
+    )?;
+
+    // Update raffle account
+    let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+    let prize = &mut raffle_account.prizes_mut()[slot as usize];
+    prize.kind = PrizeKind::Nft2022 as u8;
+    prize.mint = mint_key;
+    prize.amount = 1;
+    prize.is_deposited = 1;
+
+    raffle_account.is_deposited = raffle_account
+        .prizes()
+        .iter()
+        .all(|prize| prize.is_deposited != 0) as u8;
+
+    let prize = raffle_account.prizes()[slot as usize];
+    emit_cpi!(PrizeAdded {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8)]
+pub struct AddPrize2022Ctx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+        has_one = authority,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    /// CHECK: validate whitelist entry of metadata collection or creator
+    whitelist_entry: UncheckedAccount<'info>,
+
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: validate whitelist collection
+    metadata: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = token_account.mint == mint.key()
+        && token_account.owner == authority.key()
+        && token_account.amount == 1
+        @ RaffleError::InvalidTokenAccount
+    )]
+    token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: token account for prize escrow, created in handler with mint extensions
+    #[account(
+        mut,
+        seeds = [PREFIX, raffle_account.key().as_ref(), PRIZE, &[slot]],
+        bump,
+    )]
+    prize_escrow: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,
+    rent: Sysvar<'info, Rent>,
+}
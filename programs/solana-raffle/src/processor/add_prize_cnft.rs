@@ -0,0 +1,180 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke};
+
+use crate::{
+    bubblegum::{self, compression, noop, MetadataArgs, BUBBLEGUM_ID},
+    constants::*,
+    error::*,
+    events::PrizeAdded,
+    states::*,
+    utils::{assert_prize_eligible, PrizeOrigin},
+};
+
+/// Add prize compressed NFT by creator, leaf is transferred to escrow account
+pub fn add_prize_cnft<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddPrizeCnftCtx<'info>>,
+    idx: u32,
+    slot: u8,
+    root: [u8; 32],
+    metadata: MetadataArgs,
+    nonce: u64,
+    index: u32,
+) -> Result<()> {
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let asset_id = bubblegum::asset_id(&merkle_tree, nonce);
+    {
+        let raffle_account = ctx.accounts.raffle_account.load()?;
+        let prize = raffle_account
+            .prizes()
+            .get(slot as usize)
+            .ok_or(RaffleError::InvalidPrizeSlot)?;
+        require!(prize.is_deposited == 0, RaffleError::PrizeAlreadyDeposited);
+        require!(
+            raffle_account.is_cancelled == 0,
+            RaffleError::RaffleCancelled
+        );
+        require!(
+            !raffle_account
+                .prizes()
+                .iter()
+                .any(|prize| prize.is_deposited != 0 && prize.mint == asset_id),
+            RaffleError::PrizeAlreadyDeposited
+        );
+    }
+
+    // Check collection is whitelisted, metadata is proven by the transfer below
+    assert_prize_eligible(
+        &PrizeOrigin::from_compressed(&metadata),
+        &ctx.accounts.whitelist_entry,
+        ctx.program_id,
+    )?;
+
+    let data_hash = metadata.data_hash()?;
+    let creator_hash = metadata.creator_hash();
+
+    // Move leaf to escrow account
+    let mut account_infos = vec![
+        ctx.accounts.tree_authority.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.leaf_delegate.to_account_info(),
+        ctx.accounts.escrow_account.to_account_info(),
+        ctx.accounts.merkle_tree.to_account_info(),
+        ctx.accounts.log_wrapper.to_account_info(),
+        ctx.accounts.compression_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.bubblegum_program.to_account_info(),
+    ];
+    account_infos.extend_from_slice(ctx.remaining_accounts);
+
+    invoke(
+        &bubblegum::transfer(
+            &merkle_tree,
+            &ctx.accounts.authority.key(),
+            &ctx.accounts.leaf_delegate.key(),
+            &ctx.accounts.escrow_account.key(),
+            root,
+            data_hash,
+            creator_hash,
+            nonce,
+            index,
+            ctx.remaining_accounts,
+        ),
+        &account_infos,
+    )?;
+
+    // Keep leaf details for claim
+    let compressed_prize = &mut ctx.accounts.compressed_prize;
+    compressed_prize.merkle_tree = merkle_tree;
+    compressed_prize.data_hash = data_hash;
+    compressed_prize.creator_hash = creator_hash;
+    compressed_prize.nonce = nonce;
+    compressed_prize.index = index;
+
+    // Update raffle account
+    let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+    let prize = &mut raffle_account.prizes_mut()[slot as usize];
+    prize.kind = PrizeKind::Cnft as u8;
+    prize.mint = asset_id;
+    prize.amount = 1;
+    prize.is_deposited = 1;
+
+    raffle_account.is_deposited = raffle_account
+        .prizes()
+        .iter()
+        .all(|prize| prize.is_deposited != 0) as u8;
+
+    let prize = raffle_account.prizes()[slot as usize];
+    emit_cpi!(PrizeAdded {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8)]
+pub struct AddPrizeCnftCtx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+        has_one = authority,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    /// CHECK: validate whitelist entry of metadata collection or creator
+    whitelist_entry: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [PREFIX, raffle_account.key().as_ref(), PRIZE, &[slot]],
+        bump,
+        space=8+std::mem::size_of::<CompressedPrize>(),
+        payer = authority,
+    )]
+    compressed_prize: Box<Account<'info, CompressedPrize>>,
+
+    /// CHECK: new leaf owner holding the prize
+    #[account(
+        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW],
+        bump
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: current leaf delegate, same as authority when not delegated
+    leaf_delegate: UncheckedAccount<'info>,
+
+    /// CHECK: tree config of merkle tree
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        seeds::program = bubblegum_program.key(),
+    )]
+    tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: validated by compression program
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = noop::ID)]
+    log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = compression::ID)]
+    compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = BUBBLEGUM_ID)]
+    bubblegum_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,144 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke};
+
+use crate::{
+    constants::*,
+    error::*,
+    events::PrizeAdded,
+    mpl_core::{self, CoreAsset, CorePlugin, MPL_CORE_ID},
+    states::*,
+    utils::{assert_prize_eligible, PrizeOrigin},
+};
+
+/// Add prize Metaplex Core asset by creator
+pub fn add_prize_core(ctx: Context<AddPrizeCoreCtx>, idx: u32, slot: u8) -> Result<()> {
+    let asset_key = ctx.accounts.asset.key();
+    {
+        let raffle_account = ctx.accounts.raffle_account.load()?;
+        let prize = raffle_account
+            .prizes()
+            .get(slot as usize)
+            .ok_or(RaffleError::InvalidPrizeSlot)?;
+        require!(prize.is_deposited == 0, RaffleError::PrizeAlreadyDeposited);
+        require!(
+            raffle_account.is_cancelled == 0,
+            RaffleError::RaffleCancelled
+        );
+        require!(
+            !raffle_account
+                .prizes()
+                .iter()
+                .any(|prize| prize.is_deposited != 0 && prize.mint == asset_key),
+            RaffleError::PrizeAlreadyDeposited
+        );
+    }
+
+    let asset = CoreAsset::from_account_info(&ctx.accounts.asset)?;
+    require_keys_eq!(
+        asset.owner,
+        ctx.accounts.authority.key(),
+        RaffleError::InvalidOwner
+    );
+    require!(
+        asset.collection == ctx.accounts.collection.as_ref().map(|c| c.key()),
+        RaffleError::InvalidAsset
+    );
+
+    // Check collection is whitelisted
+    assert_prize_eligible(
+        &PrizeOrigin {
+            collection: asset.collection,
+            creator: None,
+        },
+        &ctx.accounts.whitelist_entry,
+        ctx.program_id,
+    )?;
+
+    // Freeze asset and delegate transfer to escrow account
+    let collection = asset.collection.as_ref();
+    let escrow_key = ctx.accounts.escrow_account.key();
+    let authority_key = ctx.accounts.authority.key();
+    let mut account_infos = vec![
+        ctx.accounts.asset.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.core_program.to_account_info(),
+    ];
+    if let Some(collection) = &ctx.accounts.collection {
+        account_infos.push(collection.to_account_info());
+    }
+
+    for plugin in [
+        CorePlugin::FreezeDelegate { frozen: true },
+        CorePlugin::TransferDelegate,
+    ] {
+        invoke(
+            &mpl_core::add_plugin(&asset_key, collection, &authority_key, plugin, &escrow_key),
+            &account_infos,
+        )?;
+    }
+
+    // Update raffle account
+    let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+    let prize = &mut raffle_account.prizes_mut()[slot as usize];
+    prize.kind = PrizeKind::Core as u8;
+    prize.mint = asset_key;
+    prize.amount = 1;
+    prize.is_deposited = 1;
+
+    raffle_account.is_deposited = raffle_account
+        .prizes()
+        .iter()
+        .all(|prize| prize.is_deposited != 0) as u8;
+
+    let prize = raffle_account.prizes()[slot as usize];
+    emit_cpi!(PrizeAdded {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8)]
+pub struct AddPrizeCoreCtx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+        has_one = authority,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    /// CHECK: validate whitelist entry of asset collection
+    whitelist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Core asset, parsed in handler
+    #[account(mut)]
+    asset: UncheckedAccount<'info>,
+
+    /// CHECK: collection of the asset, matched against asset in handler
+    #[account(mut)]
+    collection: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: plugin authority of deposited asset
+    #[account(
+        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW],
+        bump
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = MPL_CORE_ID)]
+    core_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
@@ -16,40 +16,45 @@ use mpl_token_metadata::{
 use crate::{
     constants::*,
     error::*,
+    events::PrizeAdded,
     states::*,
-    utils::{assert_decode_metadata, bump},
+    utils::{assert_decode_metadata, assert_prize_eligible, bump, PrizeOrigin},
 };
 
 /// Add prize PNFT by creator
-pub fn add_prize_pnft(ctx: Context<AddPrizePnftCtx>, idx: u32) -> Result<()> {
-    let global_account = &ctx.accounts.global_account;
-    let raffle_account = &mut ctx.accounts.raffle_account;
+pub fn add_prize_pnft(ctx: Context<AddPrizePnftCtx>, idx: u32, slot: u8) -> Result<()> {
+    let raffle_key = ctx.accounts.raffle_account.key();
+    let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
     let metadata_info = &ctx.accounts.metadata.to_account_info();
 
+    let prize = raffle_account
+        .prizes()
+        .get(slot as usize)
+        .ok_or(RaffleError::InvalidPrizeSlot)?;
+    require!(prize.is_deposited == 0, RaffleError::PrizeAlreadyDeposited);
     require!(
-        !raffle_account.is_deposited,
+        raffle_account.is_cancelled == 0,
+        RaffleError::RaffleCancelled
+    );
+    require!(
+        !raffle_account
+            .prizes()
+            .iter()
+            .any(|prize| prize.is_deposited != 0 && prize.mint == ctx.accounts.mint.key()),
         RaffleError::PrizeAlreadyDeposited
     );
 
     // Check collection is whitelisted
-    let metadata = assert_decode_metadata(&ctx.accounts.mint, metadata_info)?;
+    let metadata = assert_decode_metadata(&ctx.accounts.mint.key(), metadata_info)?;
     require!(
         metadata.token_standard == Some(TokenStandard::ProgrammableNonFungible),
         RaffleError::InvalidTokenStandard
     );
-    require!(
-        metadata.collection.is_some(),
-        RaffleError::InvalidCollection
-    );
-
-    let collection = metadata.collection.unwrap();
-    require!(collection.verified, RaffleError::InvalidCollection);
-
-    let collection_idx = global_account
-        .wl_collections
-        .iter()
-        .position(|&_collection| _collection == collection.key);
-    require!(collection_idx.is_some(), RaffleError::InvalidCollection);
+    assert_prize_eligible(
+        &PrizeOrigin::from_metadata(&metadata),
+        &ctx.accounts.whitelist_entry,
+        ctx.program_id,
+    )?;
 
     // Delegate transfer authority to escrow account
     let delegate_ix = DelegateBuilder::new()
@@ -95,15 +100,11 @@ pub fn add_prize_pnft(ctx: Context<AddPrizePnftCtx>, idx: u32) -> Result<()> {
     invoke(&delegate_ix, &delegate_accounts)?;
 
     // Lock nft while delegate
-    let raffle_account_key = raffle_account.key();
     let seeds = &[
         PREFIX,
-        raffle_account_key.as_ref(),
+        raffle_key.as_ref(),
         ESCROW,
-        &[bump(
-            &[PREFIX, raffle_account_key.as_ref(), ESCROW],
-            ctx.program_id,
-        )],
+        &[bump(&[PREFIX, raffle_key.as_ref(), ESCROW], ctx.program_id)],
     ];
 
     let lock_ix = LockBuilder::new()
@@ -144,34 +145,50 @@ pub fn add_prize_pnft(ctx: Context<AddPrizePnftCtx>, idx: u32) -> Result<()> {
     invoke_signed(&lock_ix, &lock_accounts, &[seeds])?;
 
     // Update raffle account
-    raffle_account.mint = ctx.accounts.mint.key();
-    raffle_account.is_deposited = true;
+    let prize = &mut raffle_account.prizes_mut()[slot as usize];
+    prize.kind = PrizeKind::Pnft as u8;
+    prize.mint = ctx.accounts.mint.key();
+    prize.amount = 1;
+    prize.is_deposited = 1;
+
+    raffle_account.is_deposited = raffle_account
+        .prizes()
+        .iter()
+        .all(|prize| prize.is_deposited != 0) as u8;
+
+    let prize = raffle_account.prizes()[slot as usize];
+    emit_cpi!(PrizeAdded {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+    });
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(idx: u32)]
+#[instruction(idx: u32, slot: u8)]
 pub struct AddPrizePnftCtx<'info> {
     #[account(mut)]
     authority: Signer<'info>,
 
-    #[account(
-        seeds=[PREFIX], 
-        bump,
-    )]
-    global_account: Box<Account<'info, GlobalAccount>>,
-
     #[account(
         mut, 
         seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
         bump,
         has_one = authority,
     )]
-    raffle_account: Box<Account<'info, RaffleAccount>>,
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
 
     mint: Box<Account<'info, Mint>>,
 
+    /// CHECK: validate whitelist entry of metadata collection or creator
+    whitelist_entry: UncheckedAccount<'info>,
+
     /// CHECK: validate whitelist collection
     #[account(mut)]
     metadata: UncheckedAccount<'info>,
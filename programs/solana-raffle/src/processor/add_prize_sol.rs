@@ -0,0 +1,89 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+
+use crate::{constants::*, error::*, events::PrizeAdded, states::*};
+
+/// Add prize SOL amount by creator
+pub fn add_prize_sol(ctx: Context<AddPrizeSolCtx>, idx: u32, slot: u8, amount: u64) -> Result<()> {
+    let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+
+    let prize = raffle_account
+        .prizes()
+        .get(slot as usize)
+        .ok_or(RaffleError::InvalidPrizeSlot)?;
+    require!(prize.is_deposited == 0, RaffleError::PrizeAlreadyDeposited);
+    require!(
+        raffle_account.is_cancelled == 0,
+        RaffleError::RaffleCancelled
+    );
+    require!(
+        amount >= Rent::get()?.minimum_balance(0),
+        RaffleError::InvalidAmount
+    );
+
+    // Deposit SOL to prize vault
+    invoke(
+        &system_instruction::transfer(
+            &ctx.accounts.authority.key(),
+            &ctx.accounts.prize_vault.key(),
+            amount,
+        ),
+        &[
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.prize_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    // Update raffle account
+    let prize = &mut raffle_account.prizes_mut()[slot as usize];
+    prize.kind = PrizeKind::Sol as u8;
+    prize.mint = Pubkey::default();
+    prize.amount = amount;
+    prize.is_deposited = 1;
+
+    raffle_account.is_deposited = raffle_account
+        .prizes()
+        .iter()
+        .all(|prize| prize.is_deposited != 0) as u8;
+
+    let prize = raffle_account.prizes()[slot as usize];
+    emit_cpi!(PrizeAdded {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8)]
+pub struct AddPrizeSolCtx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+        has_one = authority,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    /// CHECK: system account holding the prize lamports
+    #[account(
+        mut,
+        seeds = [PREFIX, raffle_account.key().as_ref(), PRIZE, &[slot]],
+        bump
+    )]
+    prize_vault: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
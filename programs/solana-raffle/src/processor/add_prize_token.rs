@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{constants::*, error::*, events::PrizeAdded, states::*};
+
+/// Add prize SPL token pool by creator
+pub fn add_prize_token(
+    ctx: Context<AddPrizeTokenCtx>,
+    idx: u32,
+    slot: u8,
+    amount: u64,
+) -> Result<()> {
+    let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+
+    let prize = raffle_account
+        .prizes()
+        .get(slot as usize)
+        .ok_or(RaffleError::InvalidPrizeSlot)?;
+    require!(prize.is_deposited == 0, RaffleError::PrizeAlreadyDeposited);
+    require!(
+        raffle_account.is_cancelled == 0,
+        RaffleError::RaffleCancelled
+    );
+    require!(amount > 0, RaffleError::InvalidAmount);
+
+    // Deposit tokens to prize escrow
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.token_account.to_account_info(),
+                to: ctx.accounts.prize_escrow.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // Update raffle account
+    let prize = &mut raffle_account.prizes_mut()[slot as usize];
+    prize.kind = PrizeKind::Token as u8;
+    prize.mint = ctx.accounts.mint.key();
+    prize.amount = amount;
+    prize.is_deposited = 1;
+
+    raffle_account.is_deposited = raffle_account
+        .prizes()
+        .iter()
+        .all(|prize| prize.is_deposited != 0) as u8;
+
+    let prize = raffle_account.prizes()[slot as usize];
+    emit_cpi!(PrizeAdded {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8)]
+pub struct AddPrizeTokenCtx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+        has_one = authority,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = token_account.mint == mint.key()
+        && token_account.owner == authority.key()
+        @ RaffleError::InvalidTokenAccount
+    )]
+    token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        seeds = [PREFIX, raffle_account.key().as_ref(), PRIZE, &[slot]],
+        bump,
+        token::mint = mint,
+        token::authority = raffle_account,
+        payer = authority,
+    )]
+    prize_escrow: Box<Account<'info, TokenAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    rent: Sysvar<'info, Rent>,
+}
@@ -1,44 +1,58 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, error::RaffleError, states::*};
-
-/// Add collection to whitelist
-pub fn add_whitelist(ctx: Context<AddWhitelistCtx>, collection: Pubkey) -> Result<()> {
-    let global_account = &mut ctx.accounts.global_account;
-
-    if let Some(_) = global_account
-        .wl_collections
-        .iter()
-        .position(|&_collection| _collection == collection)
-    {
-        return err!(RaffleError::InvalidCollection);
-    }
-
-    if let Some(idx) = global_account
-        .wl_collections
-        .iter_mut()
-        .position(|&mut _collection| _collection == Pubkey::default())
-    {
-        global_account.wl_collections[idx] = collection;
-    } else {
-        return err!(RaffleError::WhitelistFull);
-    }
+use crate::{constants::*, error::RaffleError, events::WhitelistUpdated, states::*};
+
+/// Add collection or legacy creator to whitelist
+pub fn add_whitelist(
+    ctx: Context<AddWhitelistCtx>,
+    key: Pubkey,
+    kind: WhitelistKind,
+) -> Result<()> {
+    let whitelist_entry = &mut ctx.accounts.whitelist_entry;
+
+    require!(
+        whitelist_entry.is_enabled == 0,
+        RaffleError::InvalidCollection
+    );
+
+    whitelist_entry.key = key;
+    whitelist_entry.kind = kind as u8;
+    whitelist_entry.added_by = ctx.accounts.authority.key();
+    whitelist_entry.added_at = Clock::get()?.unix_timestamp;
+    whitelist_entry.is_enabled = 1;
+
+    emit_cpi!(WhitelistUpdated {
+        key,
+        kind,
+        is_enabled: true,
+        authority: ctx.accounts.authority.key(),
+    });
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(key: Pubkey)]
 pub struct AddWhitelistCtx<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
-        mut, 
         seeds=[PREFIX], 
         bump, 
         has_one = authority
     )]
     pub global_account: Box<Account<'info, GlobalAccount>>,
 
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space=8+std::mem::size_of::<WhitelistEntry>(),
+        seeds=[PREFIX, WHITELIST, key.as_ref()],
+        bump
+    )]
+    pub whitelist_entry: Box<Account<'info, WhitelistEntry>>,
+
     pub system_program: Program<'info, System>,
 }
@@ -1,60 +1,115 @@
 use anchor_lang::{
     prelude::*,
-    solana_program::{program::invoke, system_instruction},
+    solana_program::{program::invoke, system_instruction, sysvar},
 };
-use anchor_spl::token::{
-    spl_token::{instruction::transfer, native_mint},
-    Mint, Token,
+use anchor_spl::{
+    token::spl_token::native_mint,
+    token_interface::{self, Mint, TokenInterface, TransferChecked},
 };
 
-use crate::{constants::*, error::*, states::*, utils::*};
+use crate::{
+    constants::*, error::*, events::TicketsBought, permit::BuyPermit, states::*, utils::*,
+};
 
 /// Buy ticket by user
-pub fn buy_ticket(
-    ctx: Context<BuyTicketCtx>,
+pub fn buy_ticket<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyTicketCtx<'info>>,
     idx: u32,
     uid: [u8; ID_LENGTH],
     amount: u32,
+    shard: u8,
+    permit: Option<BuyPermit>,
 ) -> Result<()> {
-    let raffle_account = &mut ctx.accounts.raffle_account;
+    let raffle_account = &ctx.accounts.raffle_account;
     let user_account = &mut ctx.accounts.user_account;
     let buyer = &ctx.accounts.buyer;
     let global_account = &ctx.accounts.global_account;
     let user_token_account = &ctx.accounts.user_token_account;
     let escrow_account = &ctx.accounts.escrow_account;
-    let vault_token_account = &ctx.accounts.vault_token_account;
 
     let system_program = &ctx.accounts.system_program;
     let token_program = &ctx.accounts.token_program;
+    let spl_mint_info = ctx.accounts.spl_mint.to_account_info();
     let rent = &ctx.accounts.rent;
 
     require!(uid.len() == ID_LENGTH, RaffleError::InvalidUUID);
 
     let now = (Clock::get().unwrap().unix_timestamp) as u64;
-    require!(
-        now >= raffle_account.start_date,
-        RaffleError::RaffleNotStarted
-    );
-    require!(raffle_account.end_date >= now, RaffleError::RaffleExpired);
-    require!(raffle_account.is_deposited, RaffleError::PrizeNotDeposited);
+    let (
+        spl_mint,
+        price,
+        total_supply,
+        is_public,
+        fee_percent,
+        max_ticket_percent,
+        referral_percent,
+    ) = {
+        let raffle = raffle_account.load()?;
+        require!(now >= raffle.start_date, RaffleError::RaffleNotStarted);
+        require!(raffle.end_date >= now, RaffleError::RaffleExpired);
+        require!(raffle.is_deposited != 0, RaffleError::PrizeNotDeposited);
+        // Every shard is needed to settle, refund or withdraw the raffle
+        require!(
+            raffle.created_shards == raffle.total_shards,
+            RaffleError::ShardsNotCreated
+        );
+        require!(raffle.is_cancelled == 0, RaffleError::RaffleCancelled);
+        require!(
+            raffle.randomness.source() == RandomnessSource::None,
+            RaffleError::RandomnessAlreadyRequested
+        );
+
+        (
+            raffle.spl_mint,
+            raffle.price,
+            raffle.total_supply,
+            raffle.is_public != 0,
+            raffle.fee_percent,
+            raffle.max_ticket_percent,
+            raffle.referral_percent,
+        )
+    };
     require!(amount > 0, RaffleError::InvalidAmount);
 
-    // Check wallet supply
+    if is_public {
+        // Public raffles key user account by buyer wallet
+        require!(uid == wallet_uid(buyer.key), RaffleError::InvalidUUID);
+    } else {
+        // Check purchase permit
+        let permit = permit.ok_or(RaffleError::InvalidPermit)?;
+        permit.verify(
+            &ctx.accounts.sysvar_instructions,
+            &global_account.permit_signer,
+            idx,
+            &uid,
+            buyer.key,
+        )?;
+        require!(now <= permit.expiry, RaffleError::PermitExpired);
+        require!(amount <= permit.max_amount, RaffleError::InvalidAmount);
+        require!(
+            permit.nonce > user_account.permit_nonce,
+            RaffleError::PermitAlreadyUsed
+        );
+        user_account.permit_nonce = permit.nonce;
+    }
+
+    // Check wallet supply, uncapped when no wallet cap is set
     let wallet_amount = user_account
         .amount
         .checked_add(amount)
         .ok_or(RaffleError::NumericOverflow)?;
 
-    let wallet_limit = raffle_account
-        .total_supply
-        .checked_mul(MAX_TICKET_PERCENT as u32)
-        .ok_or(RaffleError::NumericOverflow)?
-        .checked_div(BASIS_POINTS as u32)
-        .ok_or(RaffleError::NumericOverflow)?;
-    require!(
-        wallet_amount <= wallet_limit,
-        RaffleError::WalletLimitExceed
-    );
+    if max_ticket_percent > 0 {
+        let wallet_limit = total_supply
+            .checked_mul(max_ticket_percent as u32)
+            .ok_or(RaffleError::NumericOverflow)?
+            .checked_div(BASIS_POINTS as u32)
+            .ok_or(RaffleError::NumericOverflow)?;
+        require!(
+            wallet_amount <= wallet_limit,
+            RaffleError::WalletLimitExceed
+        );
+    }
 
     // Create & update user account
     if is_zero_account(&user_account.to_account_info()) {
@@ -71,7 +126,6 @@ pub fn buy_ticket(
     }
 
     // Check token mint
-    let spl_mint = raffle_account.spl_mint;
     require!(
         ctx.accounts.spl_mint.key() == spl_mint,
         RaffleError::InvalidMint
@@ -79,20 +133,18 @@ pub fn buy_ticket(
 
     let is_native = spl_mint == native_mint::id();
 
-    // Create escrow pda if not created
+    // Create shard escrow pda if not created
     let escrow_signer_seeds = &[
         PREFIX,
         &raffle_account.key().to_bytes(),
         ESCROW,
-        &[bump(
-            &[PREFIX, &raffle_account.key().to_bytes(), ESCROW],
-            ctx.program_id,
-        )],
+        &[shard],
+        &[ctx.accounts.ticket_shard.load()?.escrow_bump],
     ];
     create_program_token_account_if_not_present(
         escrow_account,
         system_program,
-        &buyer,
+        buyer,
         token_program,
         &ctx.accounts.spl_mint,
         &raffle_account.to_account_info(),
@@ -103,8 +155,7 @@ pub fn buy_ticket(
     )?;
 
     // Reach rental exemption and then add deposit amount.
-    let total_amount = raffle_account
-        .price
+    let total_amount = price
         .checked_mul(amount as u64)
         .ok_or(RaffleError::NumericOverflow)?;
     if is_native {
@@ -121,139 +172,153 @@ pub fn buy_ticket(
         );
     }
 
-    // Transfer SOL/USDC to fee wallet
     let fee_amount = total_amount
-        .checked_mul(FEE_PERCENT as u64)
+        .checked_mul(fee_percent as u64)
         .ok_or(RaffleError::NumericOverflow)?
         .checked_div(BASIS_POINTS as u64)
         .ok_or(RaffleError::NumericOverflow)?;
 
-    if is_native {
-        assert_keys_equal(vault_token_account.key(), global_account.vault)?;
-        invoke(
-            &system_instruction::transfer(
-                &user_token_account.key(),
-                &vault_token_account.key(),
-                fee_amount,
-            ),
-            &[
-                user_token_account.to_account_info(),
-                vault_token_account.to_account_info(),
-                system_program.to_account_info(),
-            ],
-        )?;
-    } else {
-        assert_is_ata(
-            &vault_token_account.to_account_info(),
-            &global_account.vault,
-            &spl_mint,
-        )?;
-        invoke(
-            &transfer(
-                &token_program.key(),
-                &user_token_account.key(),
-                &vault_token_account.key(),
-                &buyer.key(),
-                &[],
-                fee_amount,
-            )?,
-            &[
-                buyer.to_account_info(),
-                user_token_account.to_account_info(),
-                vault_token_account.to_account_info(),
-                token_program.to_account_info(),
-            ],
-        )?;
-    }
+    // Referral slice of the fee is paid to referrer escrow, which is owned by
+    // referral account for SPL and a system account for SOL
+    let referral_amount = match &ctx.accounts.referral_account {
+        Some(referral_account) => {
+            require!(
+                referral_account.referrer != buyer.key(),
+                RaffleError::SelfReferral
+            );
+            require!(
+                referral_account.mint == spl_mint,
+                RaffleError::InvalidReferral
+            );
 
-    // Transfer SOL/USDC to escrow wallet
-    let remain_amount = total_amount
-        .checked_sub(fee_amount)
-        .ok_or(RaffleError::NumericOverflow)?;
+            let referral_escrow = ctx
+                .accounts
+                .referral_escrow
+                .as_ref()
+                .ok_or(RaffleError::InvalidReferral)?;
+            let (escrow_key, _) = Pubkey::find_program_address(
+                &[PREFIX, referral_account.key().as_ref(), ESCROW],
+                ctx.program_id,
+            );
+            require!(
+                referral_escrow.key() == escrow_key,
+                RaffleError::InvalidReferral
+            );
+            if !is_native {
+                assert_is_ata(referral_escrow, &referral_account.key(), &spl_mint)?;
+            }
 
-    if is_native {
-        invoke(
-            &system_instruction::transfer(
-                &user_token_account.key(),
-                &escrow_account.key(),
-                remain_amount,
-            ),
-            &[
-                user_token_account.to_account_info(),
-                escrow_account.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-            ],
-        )?;
-    } else {
+            let referral_amount = fee_amount
+                .checked_mul(referral_percent as u64)
+                .ok_or(RaffleError::NumericOverflow)?
+                .checked_div(BASIS_POINTS as u64)
+                .ok_or(RaffleError::NumericOverflow)?;
+            pay(ctx.accounts, referral_escrow, referral_amount, is_native)?;
+            referral_amount
+        }
+        None => 0,
+    };
+
+    // Rest of the fee stays in the shard escrow along with the proceeds, and
+    // is swept to the vault or fee config recipients later by `sweep_fees`, so
+    // purchases on different shards never lock a shared account
+    if !is_native {
         assert_is_ata(
             &escrow_account.to_account_info(),
             &raffle_account.key(),
             &spl_mint,
         )?;
-        invoke(
-            &transfer(
-                &token_program.key(),
-                &user_token_account.key(),
-                &escrow_account.key(),
-                &buyer.key(),
-                &[],
-                remain_amount,
-            )?,
-            &[
-                buyer.to_account_info(),
-                user_token_account.to_account_info(),
-                escrow_account.to_account_info(),
-                token_program.to_account_info(),
-            ],
-        )?;
     }
+    let escrow_amount = total_amount
+        .checked_sub(referral_amount)
+        .ok_or(RaffleError::NumericOverflow)?;
+    pay(ctx.accounts, escrow_account, escrow_amount, is_native)?;
 
-    // Validate total supply
-    let total_sales = raffle_account
-        .total_sales
-        .checked_add(amount)
+    // Escrows only hold what is left after Token-2022 transfer fees
+    let remain_amount = total_amount
+        .checked_sub(fee_amount)
+        .ok_or(RaffleError::NumericOverflow)?;
+    let deposit_amount = remain_amount
+        .checked_sub(transfer_fee(&spl_mint_info, remain_amount)?)
+        .ok_or(RaffleError::NumericOverflow)?;
+    let escrow_received = escrow_amount
+        .checked_sub(transfer_fee(&spl_mint_info, escrow_amount)?)
+        .ok_or(RaffleError::NumericOverflow)?;
+    let user_account = &mut ctx.accounts.user_account;
+    user_account.deposited = user_account
+        .deposited
+        .checked_add(deposit_amount)
         .ok_or(RaffleError::NumericOverflow)?;
-    require!(
-        total_sales <= raffle_account.total_supply,
-        RaffleError::SupplyLimitExceed
-    );
 
-    // Assign ticket slot
-    for i in 0..amount {
-        let idx = raffle_account
-            .total_sales
-            .checked_add(i)
+    if let Some(referral_account) = &mut ctx.accounts.referral_account {
+        let referral_received = referral_amount
+            .checked_sub(transfer_fee(&spl_mint_info, referral_amount)?)
+            .ok_or(RaffleError::NumericOverflow)?;
+        referral_account.accrued = referral_account
+            .accrued
+            .checked_add(referral_received)
             .ok_or(RaffleError::NumericOverflow)?;
-        raffle_account.tickets[idx as usize].uid = uid;
     }
 
-    raffle_account.total_sales = total_sales;
+    // Log ticket range to shard, account space grown by realloc
+    let mut data = ctx.accounts.ticket_shard.as_ref().try_borrow_mut_data()?;
+    let (ticket_shard, tickets) = TicketShard::split_mut(&mut data);
+    let start_idx = ticket_shard.total_sales;
+    ticket_shard.push_ticket(tickets, uid, amount)?;
+    ticket_shard.fees = escrow_received
+        .checked_sub(deposit_amount)
+        .and_then(|fees| ticket_shard.fees.checked_add(fees))
+        .ok_or(RaffleError::NumericOverflow)?;
+
+    emit_cpi!(TicketsBought {
+        raffle: raffle_account.key(),
+        idx,
+        buyer: buyer.key(),
+        uid,
+        shard: ticket_shard.shard,
+        start_idx,
+        amount,
+        paid: total_amount,
+        fee: fee_amount,
+        referral_fee: referral_amount,
+        referrer: ctx
+            .accounts
+            .referral_account
+            .as_ref()
+            .map(|referral_account| referral_account.referrer),
+    });
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(idx: u32, uid: [u8; ID_LENGTH])]
+#[instruction(idx: u32, uid: [u8; ID_LENGTH], amount: u32, shard: u8)]
 pub struct BuyTicketCtx<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
-
     #[account(mut)]
     pub buyer: Signer<'info>,
 
     #[account(
         seeds=[PREFIX], 
         bump,
-        has_one = authority,
     )]
     pub global_account: Box<Account<'info, GlobalAccount>>,
 
     #[account(
-        mut, 
         seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
         bump,
     )]
-    pub raffle_account: Box<Account<'info, RaffleAccount>>,
+    pub raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes(), &[shard]], 
+        bump,
+        realloc=TicketShard::space(ticket_shard.load()?.total_tickets as usize + 1),
+        realloc::payer=buyer,
+        realloc::zero=false,
+    )]
+    pub ticket_shard: AccountLoader<'info, TicketShard>,
 
     #[account(
         init_if_needed, 
@@ -264,26 +329,72 @@ pub struct BuyTicketCtx<'info> {
     )]
     pub user_account: Box<Account<'info, UserAccount>>,
 
-    /// CHECK: token mint as SOL or USDC
-    pub spl_mint: Box<Account<'info, Mint>>,
+    /// CHECK: token mint as SOL, SPL or Token-2022
+    pub spl_mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// CHECK: token account for user's payment
     #[account(mut)]
     pub user_token_account: UncheckedAccount<'info>,
 
-    /// CHECK: token account for receive platform fee
+    /// Referrer of the purchase, credited a slice of the fee
+    #[account(mut)]
+    pub referral_account: Option<Box<Account<'info, ReferralAccount>>>,
+
+    /// CHECK: escrow of referral account, validated in handler
     #[account(mut)]
-    pub vault_token_account: UncheckedAccount<'info>,
+    pub referral_escrow: Option<UncheckedAccount<'info>>,
 
-    /// CHECK: token account for raffle escrow
+    /// CHECK: token account for shard escrow
     #[account(
         mut,
-        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW],
-        bump
+        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW, &[shard]],
+        bump = ticket_shard.load()?.escrow_bump,
     )]
     pub escrow_account: UncheckedAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    /// CHECK: instructions sysvar holding the permit signature verification
+    #[account(address = sysvar::instructions::ID)]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
+
+/// Transfer `amount` from the buyer, out of the wallet for SOL and its token account otherwise
+fn pay<'info>(
+    accounts: &BuyTicketCtx<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+    is_native: bool,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    if is_native {
+        invoke(
+            &system_instruction::transfer(accounts.user_token_account.key, to.key, amount),
+            &[
+                accounts.user_token_account.to_account_info(),
+                to.clone(),
+                accounts.system_program.to_account_info(),
+            ],
+        )
+        .map_err(Into::into)
+    } else {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.user_token_account.to_account_info(),
+                    mint: accounts.spl_mint.to_account_info(),
+                    to: to.clone(),
+                    authority: accounts.buyer.to_account_info(),
+                },
+            ),
+            amount,
+            accounts.spl_mint.decimals,
+        )
+    }
+}
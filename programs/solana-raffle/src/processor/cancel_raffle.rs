@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, events::RaffleCancelled, states::*};
+
+/// Cancel raffle by global authority, or by creator before the first sale.
+/// Neither can cancel once randomness is requested
+pub fn cancel_raffle(ctx: Context<CancelRaffleCtx>, idx: u32) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let global_account = &ctx.accounts.global_account;
+    let raffle_key = ctx.accounts.raffle_account.key();
+    let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+    let total_shards = (raffle_account.total_shards as usize).min(ctx.remaining_accounts.len());
+    raffle_account.sync_sales(&raffle_key, &ctx.remaining_accounts[..total_shards])?;
+
+    require!(
+        raffle_account.is_cancelled == 0,
+        RaffleError::RaffleCancelled
+    );
+    require!(
+        raffle_account.is_revealed == 0,
+        RaffleError::WinnerAlreadyRevealed
+    );
+    require!(
+        raffle_account.is_withdrawn == 0,
+        RaffleError::RaffleAlreadyWithdrawn
+    );
+    // Committed draw can no longer be vetoed
+    require!(
+        raffle_account.randomness.source() == RandomnessSource::None,
+        RaffleError::RandomnessAlreadyRequested
+    );
+
+    if authority.key() != global_account.authority {
+        require!(
+            authority.key() == raffle_account.authority,
+            RaffleError::InvalidSigner
+        );
+        require!(raffle_account.total_sales == 0, RaffleError::RaffleHasSales);
+    }
+
+    raffle_account.is_cancelled = 1;
+
+    emit_cpi!(RaffleCancelled {
+        raffle: raffle_key,
+        idx,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32)]
+pub struct CancelRaffleCtx<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds=[PREFIX], 
+        bump,
+    )]
+    pub global_account: Box<Account<'info, GlobalAccount>>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+    )]
+    pub raffle_account: AccountLoader<'info, RaffleAccountV2>,
+}
@@ -1,39 +1,36 @@
 use std::vec;
 
-use anchor_lang::{
-    prelude::*,
-    solana_program::program::{invoke, invoke_signed},
-};
-use anchor_spl::token::{self, Approve, Mint, Token, TokenAccount, Transfer};
-use mpl_token_metadata::instruction::{freeze_delegated_account, thaw_delegated_account};
-
-use crate::{
-    constants::*,
-    error::*,
-    states::*,
-    utils::{assert_decode_metadata, bump},
-};
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use mpl_token_metadata::instruction::thaw_delegated_account;
+
+use crate::{constants::*, error::*, events::PrizeClaimed, states::*, utils::bump};
 
 /// Claim prize NFT by winner
-pub fn claim_prize(ctx: Context<ClaimPrizeCtx>, idx: u32, uid: [u8; ID_LENGTH]) -> Result<()> {
-    let raffle_account = &mut ctx.accounts.raffle_account;
+pub fn claim_prize(
+    ctx: Context<ClaimPrizeCtx>,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+) -> Result<()> {
+    // Check winner
+    let prize = {
+        let raffle_account = ctx.accounts.raffle_account.load()?;
+        let data = ctx.accounts.ticket_shard.as_ref().try_borrow_data()?;
+        let (ticket_shard, tickets) = TicketShard::split(&data);
+        *raffle_account.claimable_prize(ticket_shard, tickets, slot, &uid)?
+    };
     require!(
-        raffle_account.winner_idx > 0,
-        RaffleError::WinnerNotRevealed
+        prize.kind == PrizeKind::Nft as u8,
+        RaffleError::InvalidPrizeKind
+    );
+    require!(
+        prize.mint == ctx.accounts.mint.key(),
+        RaffleError::InvalidMint
     );
-    require!(!raffle_account.is_claimed, RaffleError::PrizeAlreadyClaimed);
-
-    // Get winner
-    let winner_idx = raffle_account
-        .winner_idx
-        .checked_sub(1)
-        .ok_or(RaffleError::NumericOverflow)?;
-
-    let winner_uid = raffle_account.tickets[winner_idx as usize].uid;
-    require!(winner_uid == uid, RaffleError::WinnerNotMatched);
 
     // Thaw token account
-    let raffle_account_key = raffle_account.key();
+    let raffle_account_key = ctx.accounts.raffle_account.key();
     let seeds = &[
         PREFIX,
         raffle_account_key.as_ref(),
@@ -76,13 +73,25 @@ pub fn claim_prize(ctx: Context<ClaimPrizeCtx>, idx: u32, uid: [u8; ID_LENGTH])
     )?;
 
     // Update status
-    raffle_account.is_claimed = true;
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeClaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+        winner: ctx.accounts.authority.key(),
+        uid,
+    });
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(idx: u32, uid: [u8; ID_LENGTH])]
+#[instruction(idx: u32, slot: u8, uid: [u8; ID_LENGTH])]
 pub struct ClaimPrizeCtx<'info> {
     #[account(mut)]
     authority: Signer<'info>,
@@ -90,10 +99,14 @@ pub struct ClaimPrizeCtx<'info> {
     #[account(
         mut, 
         seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
-        has_one = mint,
         bump,
     )]
-    raffle_account: Box<Account<'info, RaffleAccount>>,
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        constraint = ticket_shard.load()?.raffle == raffle_account.key() @ RaffleError::InvalidTicketShard,
+    )]
+    ticket_shard: AccountLoader<'info, TicketShard>,
 
     #[account(
         mut,
@@ -114,7 +127,7 @@ pub struct ClaimPrizeCtx<'info> {
     #[account(
         mut,
         constraint = creator_token_account.mint == mint.key()
-        && creator_token_account.owner == raffle_account.authority
+        && creator_token_account.owner == raffle_account.load()?.authority
         @ RaffleError::InvalidTokenAccount
     )]
     creator_token_account: Box<Account<'info, TokenAccount>>,
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{constants::*, error::*, events::PrizeClaimed, states::*, utils::bump};
+
+/// Claim prize Token-2022 NFT by winner
+pub fn claim_prize_2022(
+    ctx: Context<ClaimPrize2022Ctx>,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+) -> Result<()> {
+    // Check winner
+    let prize = {
+        let raffle_account = ctx.accounts.raffle_account.load()?;
+        let data = ctx.accounts.ticket_shard.as_ref().try_borrow_data()?;
+        let (ticket_shard, tickets) = TicketShard::split(&data);
+        *raffle_account.claimable_prize(ticket_shard, tickets, slot, &uid)?
+    };
+    require!(
+        prize.kind == PrizeKind::Nft2022 as u8,
+        RaffleError::InvalidPrizeKind
+    );
+    require!(
+        prize.mint == ctx.accounts.mint.key(),
+        RaffleError::InvalidMint
+    );
+
+    // Transfer nft to winner
+    let seeds = &[
+        PREFIX,
+        RAFFLE,
+        &idx.to_be_bytes(),
+        &[bump(&[PREFIX, RAFFLE, &idx.to_be_bytes()], ctx.program_id)],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.prize_escrow.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.winner_token_account.to_account_info(),
+                authority: ctx.accounts.raffle_account.to_account_info(),
+            },
+            &[seeds],
+        ),
+        1,
+        0,
+    )?;
+
+    // Return escrow rent to creator
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.prize_escrow.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: ctx.accounts.raffle_account.to_account_info(),
+        },
+        &[seeds],
+    ))?;
+
+    // Update status
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeClaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+        winner: ctx.accounts.authority.key(),
+        uid,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8, uid: [u8; ID_LENGTH])]
+pub struct ClaimPrize2022Ctx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        constraint = raffle_account.load()?.authority == creator.key() @RaffleError::InvalidOwner,
+        bump,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        constraint = ticket_shard.load()?.raffle == raffle_account.key() @ RaffleError::InvalidTicketShard,
+    )]
+    ticket_shard: AccountLoader<'info, TicketShard>,
+
+    #[account(
+        mut,
+        seeds=[USER, raffle_account.key().as_ref(), uid.as_ref()], 
+        has_one = authority,
+        bump,
+    )]
+    user_account: Box<Account<'info, UserAccount>>,
+
+    /// CHECK: raffle creator receiving escrow rent
+    #[account(mut)]
+    creator: UncheckedAccount<'info>,
+
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [PREFIX, raffle_account.key().as_ref(), PRIZE, &[slot]],
+        bump,
+    )]
+    prize_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = winner_token_account.mint == mint.key()
+        && winner_token_account.owner == authority.key()
+        @ RaffleError::InvalidTokenAccount
+    )]
+    winner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,165 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+
+use crate::{
+    bubblegum::{self, compression, noop, BUBBLEGUM_ID},
+    constants::*,
+    error::*,
+    events::PrizeClaimed,
+    states::*,
+    utils::bump,
+};
+
+/// Claim prize compressed NFT by winner
+pub fn claim_prize_cnft<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimPrizeCnftCtx<'info>>,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+    root: [u8; 32],
+) -> Result<()> {
+    // Check winner
+    let prize = {
+        let raffle_account = ctx.accounts.raffle_account.load()?;
+        let data = ctx.accounts.ticket_shard.as_ref().try_borrow_data()?;
+        let (ticket_shard, tickets) = TicketShard::split(&data);
+        *raffle_account.claimable_prize(ticket_shard, tickets, slot, &uid)?
+    };
+    require!(
+        prize.kind == PrizeKind::Cnft as u8,
+        RaffleError::InvalidPrizeKind
+    );
+
+    // Transfer leaf to winner
+    let raffle_account_key = ctx.accounts.raffle_account.key();
+    let seeds = &[
+        PREFIX,
+        raffle_account_key.as_ref(),
+        ESCROW,
+        &[bump(
+            &[PREFIX, raffle_account_key.as_ref(), ESCROW],
+            ctx.program_id,
+        )],
+    ];
+
+    let compressed_prize = &ctx.accounts.compressed_prize;
+    let escrow_key = ctx.accounts.escrow_account.key();
+    let mut account_infos = vec![
+        ctx.accounts.tree_authority.to_account_info(),
+        ctx.accounts.escrow_account.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.merkle_tree.to_account_info(),
+        ctx.accounts.log_wrapper.to_account_info(),
+        ctx.accounts.compression_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.bubblegum_program.to_account_info(),
+    ];
+    account_infos.extend_from_slice(ctx.remaining_accounts);
+
+    invoke_signed(
+        &bubblegum::transfer(
+            &compressed_prize.merkle_tree,
+            &escrow_key,
+            &escrow_key,
+            &ctx.accounts.authority.key(),
+            root,
+            compressed_prize.data_hash,
+            compressed_prize.creator_hash,
+            compressed_prize.nonce,
+            compressed_prize.index,
+            ctx.remaining_accounts,
+        ),
+        &account_infos,
+        &[seeds],
+    )?;
+
+    // Update status
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeClaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+        winner: ctx.accounts.authority.key(),
+        uid,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8, uid: [u8; ID_LENGTH])]
+pub struct ClaimPrizeCnftCtx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        constraint = raffle_account.load()?.authority == creator.key() @RaffleError::InvalidOwner,
+        bump,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        constraint = ticket_shard.load()?.raffle == raffle_account.key() @ RaffleError::InvalidTicketShard,
+    )]
+    ticket_shard: AccountLoader<'info, TicketShard>,
+
+    #[account(
+        mut,
+        seeds=[USER, raffle_account.key().as_ref(), uid.as_ref()], 
+        has_one = authority,
+        bump,
+    )]
+    user_account: Box<Account<'info, UserAccount>>,
+
+    /// CHECK: raffle creator receiving prize account rent
+    #[account(mut)]
+    creator: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PREFIX, raffle_account.key().as_ref(), PRIZE, &[slot]],
+        bump,
+        has_one = merkle_tree,
+        close = creator,
+    )]
+    compressed_prize: Box<Account<'info, CompressedPrize>>,
+
+    /// CHECK: leaf owner holding the prize
+    #[account(
+        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW],
+        bump
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: tree config of merkle tree
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        seeds::program = bubblegum_program.key(),
+    )]
+    tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: matched against compressed prize
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = noop::ID)]
+    log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = compression::ID)]
+    compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = BUBBLEGUM_ID)]
+    bubblegum_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
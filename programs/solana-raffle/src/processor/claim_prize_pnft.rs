@@ -10,32 +10,33 @@ use mpl_token_metadata::instruction::{
     InstructionBuilder, TransferArgs, UnlockArgs,
 };
 
-use crate::{constants::*, error::*, states::*, utils::bump};
+use crate::{constants::*, error::*, events::PrizeClaimed, states::*, utils::bump};
 
 /// Claim prize PNFT by winner
 pub fn claim_prize_pnft(
     ctx: Context<ClaimPrizePnftCtx>,
     idx: u32,
+    slot: u8,
     uid: [u8; ID_LENGTH],
 ) -> Result<()> {
-    let raffle_account = &mut ctx.accounts.raffle_account;
+    // Check winner
+    let prize = {
+        let raffle_account = ctx.accounts.raffle_account.load()?;
+        let data = ctx.accounts.ticket_shard.as_ref().try_borrow_data()?;
+        let (ticket_shard, tickets) = TicketShard::split(&data);
+        *raffle_account.claimable_prize(ticket_shard, tickets, slot, &uid)?
+    };
     require!(
-        raffle_account.winner_idx > 0,
-        RaffleError::WinnerNotRevealed
+        prize.kind == PrizeKind::Pnft as u8,
+        RaffleError::InvalidPrizeKind
+    );
+    require!(
+        prize.mint == ctx.accounts.mint.key(),
+        RaffleError::InvalidMint
     );
-    require!(!raffle_account.is_claimed, RaffleError::PrizeAlreadyClaimed);
-
-    // Get winner
-    let winner_idx = raffle_account
-        .winner_idx
-        .checked_sub(1)
-        .ok_or(RaffleError::NumericOverflow)?;
-
-    let winner_uid = raffle_account.tickets[winner_idx as usize].uid;
-    require!(winner_uid == uid, RaffleError::WinnerNotMatched);
 
     // Unlock nft first
-    let raffle_account_key = raffle_account.key();
+    let raffle_account_key = ctx.accounts.raffle_account.key();
     let seeds = &[
         PREFIX,
         raffle_account_key.as_ref(),
@@ -130,15 +131,26 @@ pub fn claim_prize_pnft(
 
     invoke_signed(&transfer_ix, &transfer_accounts, &[seeds])?;
 
-    // Update raffle account
-    raffle_account.mint = ctx.accounts.mint.key();
-    raffle_account.is_deposited = true;
+    // Update status
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeClaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+        winner: ctx.accounts.authority.key(),
+        uid,
+    });
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(idx: u32, uid: [u8; ID_LENGTH])]
+#[instruction(idx: u32, slot: u8, uid: [u8; ID_LENGTH])]
 pub struct ClaimPrizePnftCtx<'info> {
     #[account(mut)]
     authority: Signer<'info>,
@@ -146,11 +158,15 @@ pub struct ClaimPrizePnftCtx<'info> {
     #[account(
         mut, 
         seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
-        has_one = mint,
-        constraint = raffle_account.authority == owner.key() @RaffleError::InvalidOwner,
+        constraint = raffle_account.load()?.authority == owner.key() @RaffleError::InvalidOwner,
         bump,
     )]
-    raffle_account: Box<Account<'info, RaffleAccount>>,
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        constraint = ticket_shard.load()?.raffle == raffle_account.key() @ RaffleError::InvalidTicketShard,
+    )]
+    ticket_shard: AccountLoader<'info, TicketShard>,
 
     #[account(
         mut,
@@ -180,7 +196,7 @@ pub struct ClaimPrizePnftCtx<'info> {
     #[account(
         mut,
         constraint = owner_token_account.mint == mint.key()
-        && owner_token_account.owner == raffle_account.authority
+        && owner_token_account.owner == raffle_account.load()?.authority
         && owner_token_account.amount == 1
         @ RaffleError::InvalidTokenAccount
     )]
@@ -0,0 +1,107 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+
+use crate::{constants::*, error::*, events::PrizeClaimed, states::*, utils::bump};
+
+/// Claim prize SOL amount by winner
+pub fn claim_prize_sol(
+    ctx: Context<ClaimPrizeSolCtx>,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+) -> Result<()> {
+    // Check winner
+    let prize = {
+        let raffle_account = ctx.accounts.raffle_account.load()?;
+        let data = ctx.accounts.ticket_shard.as_ref().try_borrow_data()?;
+        let (ticket_shard, tickets) = TicketShard::split(&data);
+        *raffle_account.claimable_prize(ticket_shard, tickets, slot, &uid)?
+    };
+    require!(
+        prize.kind == PrizeKind::Sol as u8,
+        RaffleError::InvalidPrizeKind
+    );
+
+    // Transfer whole prize vault balance to winner
+    let raffle_account_key = ctx.accounts.raffle_account.key();
+    let seeds = &[
+        PREFIX,
+        raffle_account_key.as_ref(),
+        PRIZE,
+        &[slot],
+        &[bump(
+            &[PREFIX, raffle_account_key.as_ref(), PRIZE, &[slot]],
+            ctx.program_id,
+        )],
+    ];
+
+    invoke_signed(
+        &system_instruction::transfer(
+            &ctx.accounts.prize_vault.key(),
+            &ctx.accounts.authority.key(),
+            ctx.accounts.prize_vault.lamports(),
+        ),
+        &[
+            ctx.accounts.prize_vault.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[seeds],
+    )?;
+
+    // Update status
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeClaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+        winner: ctx.accounts.authority.key(),
+        uid,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8, uid: [u8; ID_LENGTH])]
+pub struct ClaimPrizeSolCtx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        constraint = ticket_shard.load()?.raffle == raffle_account.key() @ RaffleError::InvalidTicketShard,
+    )]
+    ticket_shard: AccountLoader<'info, TicketShard>,
+
+    #[account(
+        mut,
+        seeds=[USER, raffle_account.key().as_ref(), uid.as_ref()], 
+        has_one = authority,
+        bump,
+    )]
+    user_account: Box<Account<'info, UserAccount>>,
+
+    /// CHECK: system account holding the prize lamports
+    #[account(
+        mut,
+        seeds = [PREFIX, raffle_account.key().as_ref(), PRIZE, &[slot]],
+        bump
+    )]
+    prize_vault: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,124 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+use anchor_spl::{
+    token::spl_token::native_mint,
+    token_interface::{self, Mint, TokenInterface, TransferChecked},
+};
+
+use crate::{constants::*, error::*, events::ReferralClaimed, states::*, utils::*};
+
+/// Pay out referral rewards accrued since the last claim
+pub fn claim_referral(ctx: Context<ClaimReferralCtx>) -> Result<()> {
+    let referral_account = &ctx.accounts.referral_account;
+    let referral_escrow = &ctx.accounts.referral_escrow;
+    let referrer_token_account = &ctx.accounts.referrer_token_account;
+
+    let amount = referral_account
+        .accrued
+        .checked_sub(referral_account.claimed)
+        .ok_or(RaffleError::NumericOverflow)?;
+    require!(amount > 0, RaffleError::InvalidAmount);
+
+    let mint = referral_account.mint;
+    let referrer = referral_account.referrer;
+    let referral_key = referral_account.key();
+
+    if mint == native_mint::id() {
+        assert_keys_equal(referrer_token_account.key(), referrer)?;
+
+        let escrow_signer_seeds = &[
+            PREFIX,
+            referral_key.as_ref(),
+            ESCROW,
+            &[bump(
+                &[PREFIX, referral_key.as_ref(), ESCROW],
+                ctx.program_id,
+            )],
+        ];
+        invoke_signed(
+            &system_instruction::transfer(
+                &referral_escrow.key(),
+                &referrer_token_account.key(),
+                amount,
+            ),
+            &[
+                referral_escrow.to_account_info(),
+                referrer_token_account.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[escrow_signer_seeds],
+        )?;
+    } else {
+        assert_is_ata(&referrer_token_account.to_account_info(), &referrer, &mint)?;
+
+        let referral_signer_seeds = &[
+            PREFIX,
+            REFERRAL,
+            referrer.as_ref(),
+            mint.as_ref(),
+            &[bump(
+                &[PREFIX, REFERRAL, referrer.as_ref(), mint.as_ref()],
+                ctx.program_id,
+            )],
+        ];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: referral_escrow.to_account_info(),
+                    mint: ctx.accounts.spl_mint.to_account_info(),
+                    to: referrer_token_account.to_account_info(),
+                    authority: referral_account.to_account_info(),
+                },
+                &[referral_signer_seeds],
+            ),
+            amount,
+            ctx.accounts.spl_mint.decimals,
+        )?;
+    }
+
+    ctx.accounts.referral_account.claimed = referral_account.accrued;
+
+    emit_cpi!(ReferralClaimed {
+        referrer,
+        mint,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimReferralCtx<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[PREFIX, REFERRAL, referrer.key().as_ref(), referral_account.mint.as_ref()], 
+        bump, 
+        has_one = referrer,
+    )]
+    pub referral_account: Box<Account<'info, ReferralAccount>>,
+
+    #[account(address = referral_account.mint @ RaffleError::InvalidMint)]
+    pub spl_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: escrow holding referral rewards
+    #[account(
+        mut,
+        seeds = [PREFIX, referral_account.key().as_ref(), ESCROW],
+        bump
+    )]
+    pub referral_escrow: UncheckedAccount<'info>,
+
+    /// CHECK: referrer wallet for SOL, or its token account
+    #[account(mut)]
+    pub referrer_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
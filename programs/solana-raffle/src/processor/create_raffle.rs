@@ -1,23 +1,47 @@
-use std::vec;
-
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
+use anchor_spl::token_interface::Mint;
 
-use crate::{constants::*, error::*, states::*};
+use crate::{constants::*, error::*, events::RaffleCreated, states::*};
 
 /// Create raffle by creator
 pub fn create_raffle(
     ctx: Context<CreateRaffleCtx>,
     idx: u32,
     total_supply: u32,
+    total_prizes: u8,
     price: u64,
     start_date: u64,
     end_date: u64,
+    min_sales: u32,
+    total_shards: u8,
+    is_public: bool,
+    max_ticket_percent: Option<u16>,
 ) -> Result<()> {
     let global_account = &mut ctx.accounts.global_account;
-    let raffle_account = &mut ctx.accounts.raffle_account;
+    let mut raffle_account = ctx.accounts.raffle_account.load_init()?;
 
     require!(total_supply > 0, RaffleError::InvalidAmount);
+    require!(
+        total_prizes > 0
+            && total_prizes as usize <= MAX_PRIZES
+            && total_prizes as u32 <= total_supply,
+        RaffleError::InvalidAmount
+    );
+    require!(min_sales <= total_supply, RaffleError::InvalidAmount);
+    require!(
+        total_shards > 0
+            && total_shards as usize <= MAX_SHARDS
+            && total_shards as u32 <= total_supply,
+        RaffleError::InvalidAmount
+    );
+    let fee_percent = global_account.platform_fee_percent();
+    let max_ticket_percent =
+        max_ticket_percent.unwrap_or(global_account.default_max_ticket_percent());
+    GlobalAccount::validate_fees(
+        fee_percent,
+        max_ticket_percent,
+        global_account.referral_percent,
+    )?;
     require!(
         global_account.total_raffles == idx,
         RaffleError::InvalidRaffleIdx
@@ -34,27 +58,49 @@ pub fn create_raffle(
     raffle_account.start_date = start_date;
     raffle_account.end_date = end_date;
     raffle_account.total_sales = 0;
-    raffle_account.is_deposited = false;
-    raffle_account.is_withdrawn = false;
+    raffle_account.min_sales = min_sales;
     raffle_account.spl_mint = ctx.accounts.spl_mint.key();
+    raffle_account.is_public = is_public as u8;
 
-    // Initialize raffles vector
-    for _ in 0..total_supply {
-        raffle_account.tickets.push(RaffleTicket {
-            uid: [0; ID_LENGTH],
-        });
+    // Snapshot fees so later setting changes don't affect live raffle
+    raffle_account.fee_percent = fee_percent;
+    raffle_account.max_ticket_percent = max_ticket_percent;
+    raffle_account.crank_reward_percent = global_account.crank_reward_percent;
+    raffle_account.referral_percent = global_account.referral_percent;
+    if global_account.has_fee_config != 0 {
+        raffle_account.fee_config_version = global_account.fee_config_version;
     }
 
+    // Prize slots start zeroed, ticket shards are created by `create_shard`
+    raffle_account.total_prizes = total_prizes;
+    raffle_account.total_shards = total_shards;
+
     global_account.total_raffles = global_account
         .total_raffles
         .checked_add(1)
         .ok_or(RaffleError::NumericOverflow)?;
 
+    emit_cpi!(RaffleCreated {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        authority: raffle_account.authority,
+        spl_mint: raffle_account.spl_mint,
+        total_supply,
+        total_prizes,
+        price,
+        start_date,
+        end_date,
+        min_sales,
+        total_shards,
+        is_public,
+    });
+
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(idx: u32, total_supply: u32)]
+#[instruction(idx: u32)]
 pub struct CreateRaffleCtx<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -67,16 +113,16 @@ pub struct CreateRaffleCtx<'info> {
     pub global_account: Box<Account<'info, GlobalAccount>>,
 
     /// CHECK: token mint as SOL or USDC
-    pub spl_mint: Box<Account<'info, Mint>>,
+    pub spl_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init, 
         seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
         bump,
-        space=8 + RaffleAccount::size(total_supply),
+        space=RaffleAccountV2::SIZE,
         payer=authority
     )]
-    pub raffle_account: Box<Account<'info, RaffleAccount>>,
+    pub raffle_account: AccountLoader<'info, RaffleAccountV2>,
 
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
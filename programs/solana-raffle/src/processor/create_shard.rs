@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, events::ShardCreated, states::*, utils::bump};
+
+/// Create ticket shard of raffle by creator
+pub fn create_shard(ctx: Context<CreateShardCtx>, idx: u32, shard: u8) -> Result<()> {
+    let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+    require!(
+        shard < raffle_account.total_shards,
+        RaffleError::InvalidTicketShard
+    );
+    // Shard seeds are unique, so the count reaches `total_shards` only once all exist
+    raffle_account.created_shards += 1;
+
+    let raffle_key = ctx.accounts.raffle_account.key();
+    let mut ticket_shard = ctx.accounts.ticket_shard.load_init()?;
+    ticket_shard.raffle = raffle_key;
+    ticket_shard.shard = shard;
+    ticket_shard.capacity = raffle_account.shard_capacity(shard);
+    ticket_shard.escrow_bump = bump(
+        &[PREFIX, raffle_key.as_ref(), ESCROW, &[shard]],
+        ctx.program_id,
+    );
+
+    emit_cpi!(ShardCreated {
+        raffle: raffle_key,
+        idx,
+        shard,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, shard: u8)]
+pub struct CreateShardCtx<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+        has_one = authority,
+    )]
+    pub raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        init, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes(), &[shard]], 
+        bump,
+        space=TicketShard::space(0),
+        payer=authority
+    )]
+    pub ticket_shard: AccountLoader<'info, TicketShard>,
+
+    pub system_program: Program<'info, System>,
+}
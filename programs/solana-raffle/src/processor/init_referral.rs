@@ -0,0 +1,105 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+use anchor_spl::{
+    token::spl_token::native_mint,
+    token_interface::{Mint, TokenInterface},
+};
+
+use crate::{constants::*, events::ReferralInitialized, states::*, utils::*};
+
+/// Register referrer for a payment mint, creating the reward escrow
+pub fn init_referral(ctx: Context<InitReferralCtx>) -> Result<()> {
+    let referral_account = &mut ctx.accounts.referral_account;
+    let referral_escrow = &ctx.accounts.referral_escrow;
+    let referrer = &ctx.accounts.referrer;
+
+    referral_account.referrer = referrer.key();
+    referral_account.mint = ctx.accounts.spl_mint.key();
+
+    let is_native = referral_account.mint == native_mint::id();
+    if is_native {
+        // Fund escrow rent so small rewards can be credited
+        let rent_lamports = ctx
+            .accounts
+            .rent
+            .minimum_balance(0)
+            .saturating_sub(referral_escrow.lamports());
+        if rent_lamports > 0 {
+            invoke(
+                &system_instruction::transfer(
+                    &referrer.key(),
+                    &referral_escrow.key(),
+                    rent_lamports,
+                ),
+                &[
+                    referrer.to_account_info(),
+                    referral_escrow.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+        }
+    } else {
+        let referral_key = referral_account.key();
+        let escrow_signer_seeds = &[
+            PREFIX,
+            referral_key.as_ref(),
+            ESCROW,
+            &[bump(
+                &[PREFIX, referral_key.as_ref(), ESCROW],
+                ctx.program_id,
+            )],
+        ];
+        create_program_token_account_if_not_present(
+            referral_escrow,
+            &ctx.accounts.system_program,
+            referrer,
+            &ctx.accounts.token_program,
+            &ctx.accounts.spl_mint,
+            &referral_account.to_account_info(),
+            &ctx.accounts.rent,
+            &escrow_signer_seeds[..],
+            &[],
+            is_native,
+        )?;
+    }
+
+    emit_cpi!(ReferralInitialized {
+        referrer: referrer.key(),
+        referral: referral_account.key(),
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitReferralCtx<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    /// CHECK: token mint as SOL, SPL or Token-2022
+    pub spl_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init, 
+        seeds=[PREFIX, REFERRAL, referrer.key().as_ref(), spl_mint.key().as_ref()], 
+        bump, 
+        space=8+std::mem::size_of::<ReferralAccount>(),
+        payer=referrer,
+    )]
+    pub referral_account: Box<Account<'info, ReferralAccount>>,
+
+    /// CHECK: escrow holding referral rewards
+    #[account(
+        mut,
+        seeds = [PREFIX, referral_account.key().as_ref(), ESCROW],
+        bump
+    )]
+    pub referral_escrow: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, states::*};
+use crate::{constants::*, events::ProgramInitialized, states::*};
 
 /// Initialize global state
 pub fn initialize(ctx: Context<InitializeCtx>) -> Result<()> {
@@ -8,12 +8,23 @@ pub fn initialize(ctx: Context<InitializeCtx>) -> Result<()> {
 
     global_account.authority = ctx.accounts.authority.key();
     global_account.vault = ctx.accounts.vault.key();
+    global_account.permit_signer = ctx.accounts.permit_signer.key();
     global_account.total_raffles = 0;
     global_account.wl_collections = [Pubkey::default(); MAX_COLLECTIONS];
+    global_account.fee_percent = FEE_PERCENT;
+    global_account.max_ticket_percent = MAX_TICKET_PERCENT;
+    global_account.fees_initialized = 1;
+
+    emit_cpi!(ProgramInitialized {
+        authority: global_account.authority,
+        vault: global_account.vault,
+        permit_signer: global_account.permit_signer,
+    });
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializeCtx<'info> {
     #[account(mut)]
@@ -22,6 +33,9 @@ pub struct InitializeCtx<'info> {
     /// CHECK: vault account for receive payment fee
     pub vault: UncheckedAccount<'info>,
 
+    /// CHECK: key signing purchase permits
+    pub permit_signer: UncheckedAccount<'info>,
+
     #[account(
         init, 
         seeds=[PREFIX], 
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::RaffleError, events::WhitelistUpdated, states::*};
+
+/// Move collection from legacy global whitelist array to its own entry
+pub fn migrate_whitelist(ctx: Context<MigrateWhitelistCtx>, collection: Pubkey) -> Result<()> {
+    let global_account = &mut ctx.accounts.global_account;
+    let whitelist_entry = &mut ctx.accounts.whitelist_entry;
+
+    let idx = global_account
+        .wl_collections
+        .iter()
+        .position(|&_collection| _collection == collection)
+        .ok_or(RaffleError::InvalidCollection)?;
+    global_account.wl_collections[idx] = Pubkey::default();
+
+    whitelist_entry.key = collection;
+    whitelist_entry.kind = WhitelistKind::Collection as u8;
+    whitelist_entry.added_by = ctx.accounts.authority.key();
+    whitelist_entry.added_at = Clock::get()?.unix_timestamp;
+    whitelist_entry.is_enabled = 1;
+
+    emit_cpi!(WhitelistUpdated {
+        key: collection,
+        kind: WhitelistKind::Collection,
+        is_enabled: true,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(collection: Pubkey)]
+pub struct MigrateWhitelistCtx<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX], 
+        bump, 
+        has_one = authority
+    )]
+    pub global_account: Box<Account<'info, GlobalAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space=8+std::mem::size_of::<WhitelistEntry>(),
+        seeds=[PREFIX, WHITELIST, collection.as_ref()],
+        bump
+    )]
+    pub whitelist_entry: Box<Account<'info, WhitelistEntry>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,25 +1,97 @@
 pub mod add_prize;
+pub mod add_prize_2022;
+pub mod add_prize_cnft;
+pub mod add_prize_core;
 pub mod add_prize_pnft;
+pub mod add_prize_sol;
+pub mod add_prize_token;
 pub mod add_whitelist;
 pub mod buy_ticket;
+pub mod cancel_raffle;
 pub mod claim_prize;
+pub mod claim_prize_2022;
+pub mod claim_prize_cnft;
+pub mod claim_prize_core;
 pub mod claim_prize_pnft;
+pub mod claim_prize_sol;
+pub mod claim_prize_token;
+pub mod claim_referral;
 pub mod create_raffle;
+pub mod create_shard;
+pub mod init_referral;
 pub mod initialize;
+pub mod migrate_whitelist;
+pub mod push_prize;
+pub mod push_prize_2022;
+pub mod push_prize_cnft;
+pub mod push_prize_core;
+pub mod push_prize_pnft;
+pub mod push_prize_sol;
+pub mod push_prize_token;
+pub mod push_proceeds;
+pub mod reclaim_prize;
+pub mod reclaim_prize_2022;
+pub mod reclaim_prize_cnft;
+pub mod reclaim_prize_core;
+pub mod reclaim_prize_pnft;
+pub mod reclaim_prize_sol;
+pub mod reclaim_prize_token;
+pub mod refund_ticket;
 pub mod remove_whitelist;
-pub mod reveal_winner;
+pub mod request_randomness;
+pub mod set_fee_config;
+pub mod set_raffle_fee_config;
+pub mod settle_randomness;
+pub mod sweep_fees;
+pub mod update_fees;
 pub mod update_setting;
 pub mod withdraw_raffle;
 
 pub use add_prize::*;
+pub use add_prize_2022::*;
+pub use add_prize_cnft::*;
+pub use add_prize_core::*;
 pub use add_prize_pnft::*;
+pub use add_prize_sol::*;
+pub use add_prize_token::*;
 pub use add_whitelist::*;
 pub use buy_ticket::*;
+pub use cancel_raffle::*;
 pub use claim_prize::*;
+pub use claim_prize_2022::*;
+pub use claim_prize_cnft::*;
+pub use claim_prize_core::*;
 pub use claim_prize_pnft::*;
+pub use claim_prize_sol::*;
+pub use claim_prize_token::*;
+pub use claim_referral::*;
 pub use create_raffle::*;
+pub use create_shard::*;
+pub use init_referral::*;
 pub use initialize::*;
+pub use migrate_whitelist::*;
+pub use push_prize::*;
+pub use push_prize_2022::*;
+pub use push_prize_cnft::*;
+pub use push_prize_core::*;
+pub use push_prize_pnft::*;
+pub use push_prize_sol::*;
+pub use push_prize_token::*;
+pub use push_proceeds::*;
+pub use reclaim_prize::*;
+pub use reclaim_prize_2022::*;
+pub use reclaim_prize_cnft::*;
+pub use reclaim_prize_core::*;
+pub use reclaim_prize_pnft::*;
+pub use reclaim_prize_sol::*;
+pub use reclaim_prize_token::*;
+pub use refund_ticket::*;
 pub use remove_whitelist::*;
-pub use reveal_winner::*;
+pub use request_randomness::*;
+pub use set_fee_config::*;
+pub use set_raffle_fee_config::*;
+pub use settle_randomness::*;
+pub use sweep_fees::*;
+pub use update_fees::*;
 pub use update_setting::*;
 pub use withdraw_raffle::*;
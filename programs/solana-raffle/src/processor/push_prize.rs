@@ -0,0 +1,161 @@
+use std::vec;
+
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use mpl_token_metadata::instruction::thaw_delegated_account;
+
+use crate::{constants::*, error::*, events::PrizeClaimed, states::*, utils::bump};
+
+/// Push prize NFT to winner, callable by anyone
+pub fn push_prize(
+    ctx: Context<PushPrizeCtx>,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+) -> Result<()> {
+    // Check winner
+    let prize = {
+        let raffle_account = ctx.accounts.raffle_account.load()?;
+        let data = ctx.accounts.ticket_shard.as_ref().try_borrow_data()?;
+        let (ticket_shard, tickets) = TicketShard::split(&data);
+        *raffle_account.claimable_prize(ticket_shard, tickets, slot, &uid)?
+    };
+    require!(
+        prize.kind == PrizeKind::Nft as u8,
+        RaffleError::InvalidPrizeKind
+    );
+    require!(
+        prize.mint == ctx.accounts.mint.key(),
+        RaffleError::InvalidMint
+    );
+
+    // Thaw token account
+    let raffle_account_key = ctx.accounts.raffle_account.key();
+    let seeds = &[
+        PREFIX,
+        raffle_account_key.as_ref(),
+        ESCROW,
+        &[bump(
+            &[PREFIX, raffle_account_key.as_ref(), ESCROW],
+            ctx.program_id,
+        )],
+    ];
+
+    invoke_signed(
+        &thaw_delegated_account(
+            mpl_token_metadata::id(),
+            ctx.accounts.escrow_account.key(),
+            ctx.accounts.creator_token_account.key(),
+            *ctx.accounts.edition.key,
+            ctx.accounts.mint.key(),
+        ),
+        &[
+            ctx.accounts.escrow_account.to_account_info(),
+            ctx.accounts.creator_token_account.to_account_info(),
+            ctx.accounts.edition.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+        ],
+        &[seeds],
+    )?;
+
+    // Transfer nft to winner
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.creator_token_account.to_account_info(),
+                to: ctx.accounts.winner_token_account.to_account_info(),
+                authority: ctx.accounts.escrow_account.to_account_info(),
+            },
+            &[seeds],
+        ),
+        1,
+    )?;
+
+    // Update status
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeClaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+        winner: ctx.accounts.authority.key(),
+        uid,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8, uid: [u8; ID_LENGTH])]
+pub struct PushPrizeCtx<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: prize winner, checked by `has_one` of user account
+    authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        constraint = ticket_shard.load()?.raffle == raffle_account.key() @ RaffleError::InvalidTicketShard,
+    )]
+    ticket_shard: AccountLoader<'info, TicketShard>,
+
+    #[account(
+        mut,
+        seeds=[USER, raffle_account.key().as_ref(), uid.as_ref()], 
+        has_one = authority,
+        bump,
+    )]
+    user_account: Box<Account<'info, UserAccount>>,
+
+    mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: validate whitelist collection
+    metadata: UncheckedAccount<'info>,
+
+    /// CHECK: validate nft edition account
+    edition: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == mint.key()
+        && creator_token_account.owner == raffle_account.load()?.authority
+        @ RaffleError::InvalidTokenAccount
+    )]
+    creator_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = winner_token_account.mint == mint.key()
+        && winner_token_account.owner == authority.key()
+        @ RaffleError::InvalidTokenAccount
+    )]
+    winner_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: token account for escrow
+    #[account(
+        mut,
+        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW],
+        bump
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = mpl_token_metadata::id())]
+    metadata_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,161 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+
+use crate::{
+    constants::*,
+    error::*,
+    events::PrizeClaimed,
+    mpl_core::{self, CoreAsset, CorePlugin, MPL_CORE_ID},
+    states::*,
+    utils::bump,
+};
+
+/// Push prize Metaplex Core asset to winner, callable by anyone
+pub fn push_prize_core(
+    ctx: Context<PushPrizeCoreCtx>,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+) -> Result<()> {
+    // Check winner
+    let prize = {
+        let raffle_account = ctx.accounts.raffle_account.load()?;
+        let data = ctx.accounts.ticket_shard.as_ref().try_borrow_data()?;
+        let (ticket_shard, tickets) = TicketShard::split(&data);
+        *raffle_account.claimable_prize(ticket_shard, tickets, slot, &uid)?
+    };
+    require!(
+        prize.kind == PrizeKind::Core as u8,
+        RaffleError::InvalidPrizeKind
+    );
+    require!(
+        prize.mint == ctx.accounts.asset.key(),
+        RaffleError::InvalidMint
+    );
+
+    let asset = CoreAsset::from_account_info(&ctx.accounts.asset)?;
+    require!(
+        asset.collection == ctx.accounts.collection.as_ref().map(|c| c.key()),
+        RaffleError::InvalidAsset
+    );
+
+    // Thaw asset and transfer to winner
+    let raffle_account_key = ctx.accounts.raffle_account.key();
+    let seeds = &[
+        PREFIX,
+        raffle_account_key.as_ref(),
+        ESCROW,
+        &[bump(
+            &[PREFIX, raffle_account_key.as_ref(), ESCROW],
+            ctx.program_id,
+        )],
+    ];
+
+    let asset_key = ctx.accounts.asset.key();
+    let collection = asset.collection.as_ref();
+    let escrow_key = ctx.accounts.escrow_account.key();
+    let payer_key = ctx.accounts.payer.key();
+    let authority_key = ctx.accounts.authority.key();
+    let mut account_infos = vec![
+        ctx.accounts.asset.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.escrow_account.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.core_program.to_account_info(),
+    ];
+    if let Some(collection) = &ctx.accounts.collection {
+        account_infos.push(collection.to_account_info());
+    }
+
+    invoke_signed(
+        &mpl_core::update_plugin(
+            &asset_key,
+            collection,
+            &payer_key,
+            &escrow_key,
+            CorePlugin::FreezeDelegate { frozen: false },
+        ),
+        &account_infos,
+        &[seeds],
+    )?;
+
+    invoke_signed(
+        &mpl_core::transfer(
+            &asset_key,
+            collection,
+            &payer_key,
+            &escrow_key,
+            &authority_key,
+        ),
+        &account_infos,
+        &[seeds],
+    )?;
+
+    // Update status
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeClaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+        winner: ctx.accounts.authority.key(),
+        uid,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8, uid: [u8; ID_LENGTH])]
+pub struct PushPrizeCoreCtx<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: prize winner, checked by `has_one` of user account
+    authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        constraint = ticket_shard.load()?.raffle == raffle_account.key() @ RaffleError::InvalidTicketShard,
+    )]
+    ticket_shard: AccountLoader<'info, TicketShard>,
+
+    #[account(
+        mut,
+        seeds=[USER, raffle_account.key().as_ref(), uid.as_ref()], 
+        has_one = authority,
+        bump,
+    )]
+    user_account: Box<Account<'info, UserAccount>>,
+
+    /// CHECK: Core asset, matched against prize in handler
+    #[account(mut)]
+    asset: UncheckedAccount<'info>,
+
+    /// CHECK: collection of the asset, matched against asset in handler
+    #[account(mut)]
+    collection: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: plugin authority of deposited asset
+    #[account(
+        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW],
+        bump
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = MPL_CORE_ID)]
+    core_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
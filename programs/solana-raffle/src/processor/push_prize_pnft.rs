@@ -0,0 +1,245 @@
+use std::vec;
+
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_token_metadata::instruction::{
+    builders::{TransferBuilder, UnlockBuilder},
+    InstructionBuilder, TransferArgs, UnlockArgs,
+};
+
+use crate::{constants::*, error::*, events::PrizeClaimed, states::*, utils::bump};
+
+/// Push prize PNFT to winner, callable by anyone
+pub fn push_prize_pnft(
+    ctx: Context<PushPrizePnftCtx>,
+    idx: u32,
+    slot: u8,
+    uid: [u8; ID_LENGTH],
+) -> Result<()> {
+    // Check winner
+    let prize = {
+        let raffle_account = ctx.accounts.raffle_account.load()?;
+        let data = ctx.accounts.ticket_shard.as_ref().try_borrow_data()?;
+        let (ticket_shard, tickets) = TicketShard::split(&data);
+        *raffle_account.claimable_prize(ticket_shard, tickets, slot, &uid)?
+    };
+    require!(
+        prize.kind == PrizeKind::Pnft as u8,
+        RaffleError::InvalidPrizeKind
+    );
+    require!(
+        prize.mint == ctx.accounts.mint.key(),
+        RaffleError::InvalidMint
+    );
+
+    // Unlock nft first
+    let raffle_account_key = ctx.accounts.raffle_account.key();
+    let seeds = &[
+        PREFIX,
+        raffle_account_key.as_ref(),
+        ESCROW,
+        &[bump(
+            &[PREFIX, raffle_account_key.as_ref(), ESCROW],
+            ctx.program_id,
+        )],
+    ];
+
+    let unlock_ix = UnlockBuilder::new()
+        .authority(ctx.accounts.escrow_account.key())
+        .mint(ctx.accounts.mint.key())
+        .metadata(ctx.accounts.metadata.key())
+        .edition(ctx.accounts.edition.key())
+        .token(ctx.accounts.owner_token_account.key())
+        .token_record(ctx.accounts.owner_token_record.key())
+        .system_program(ctx.accounts.system_program.key())
+        .sysvar_instructions(ctx.accounts.sysvar_instructions.key())
+        .spl_token_program(ctx.accounts.token_program.key())
+        .authorization_rules(ctx.accounts.authorization_rules.key())
+        .authorization_rules_program(ctx.accounts.authorization_rules_program.key())
+        .payer(ctx.accounts.payer.key())
+        .build(UnlockArgs::V1 {
+            authorization_data: None,
+        })
+        .unwrap()
+        .instruction();
+
+    let unlock_accounts = [
+        ctx.accounts.metadata_program.to_account_info(),
+        ctx.accounts.escrow_account.to_account_info(),
+        ctx.accounts.owner_token_record.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.metadata.to_account_info(),
+        ctx.accounts.edition.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.authorization_rules_program.to_account_info(),
+        ctx.accounts.authorization_rules.to_account_info(),
+        ctx.accounts.sysvar_instructions.to_account_info(),
+    ];
+
+    invoke_signed(&unlock_ix, &unlock_accounts, &[seeds])?;
+
+    // Transfer nft to winner using escrow account
+    let transfer_ix = TransferBuilder::new()
+        .authority(ctx.accounts.escrow_account.key())
+        .mint(ctx.accounts.mint.key())
+        .metadata(ctx.accounts.metadata.key())
+        .edition(ctx.accounts.edition.key())
+        .token(ctx.accounts.owner_token_account.key())
+        .token_owner(ctx.accounts.owner.key())
+        .owner_token_record(ctx.accounts.owner_token_record.key())
+        .destination(ctx.accounts.dest_token_account.key())
+        .destination_owner(ctx.accounts.authority.key())
+        .destination_token_record(ctx.accounts.dest_token_record.key())
+        .sysvar_instructions(ctx.accounts.sysvar_instructions.key())
+        .spl_token_program(ctx.accounts.token_program.key())
+        .spl_ata_program(ctx.accounts.ata_program.key())
+        .authorization_rules(ctx.accounts.authorization_rules.key())
+        .authorization_rules_program(ctx.accounts.authorization_rules_program.key())
+        .payer(ctx.accounts.payer.key())
+        .build(TransferArgs::V1 {
+            amount: 1,
+            authorization_data: None,
+        })
+        .unwrap()
+        .instruction();
+
+    let transfer_accounts = [
+        ctx.accounts.metadata_program.to_account_info(),
+        ctx.accounts.escrow_account.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.dest_token_account.to_account_info(),
+        ctx.accounts.dest_token_record.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.owner_token_record.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.metadata.to_account_info(),
+        ctx.accounts.edition.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.ata_program.to_account_info(),
+        ctx.accounts.authorization_rules_program.to_account_info(),
+        ctx.accounts.authorization_rules.to_account_info(),
+        ctx.accounts.sysvar_instructions.to_account_info(),
+    ];
+
+    invoke_signed(&transfer_ix, &transfer_accounts, &[seeds])?;
+
+    // Update status
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeClaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+        winner: ctx.accounts.authority.key(),
+        uid,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8, uid: [u8; ID_LENGTH])]
+pub struct PushPrizePnftCtx<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: prize winner, checked by `has_one` of user account
+    authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        constraint = raffle_account.load()?.authority == owner.key() @RaffleError::InvalidOwner,
+        bump,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        constraint = ticket_shard.load()?.raffle == raffle_account.key() @ RaffleError::InvalidTicketShard,
+    )]
+    ticket_shard: AccountLoader<'info, TicketShard>,
+
+    #[account(
+        mut,
+        seeds=[USER, raffle_account.key().as_ref(), uid.as_ref()], 
+        has_one = authority,
+        bump,
+    )]
+    user_account: Box<Account<'info, UserAccount>>,
+
+    /// CHECK: validate nft edition account
+    owner: UncheckedAccount<'info>,
+
+    mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: validate whitelist collection
+    #[account(mut)]
+    metadata: UncheckedAccount<'info>,
+
+    /// CHECK: validate nft edition account
+    edition: UncheckedAccount<'info>,
+
+    /// CHECK: validate token record account
+    #[account(mut)]
+    owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: validate owner token account
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == mint.key()
+        && owner_token_account.owner == raffle_account.load()?.authority
+        && owner_token_account.amount == 1
+        @ RaffleError::InvalidTokenAccount
+    )]
+    owner_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: validate token record account
+    #[account(mut)]
+    dest_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: validate destination token account
+    #[account(
+        mut,
+        constraint = dest_token_account.mint == mint.key()
+        && dest_token_account.owner == authority.key()
+        @ RaffleError::InvalidTokenAccount
+    )]
+    dest_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: token account for escrow
+    #[account(
+        mut,
+        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW],
+        bump
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    authorization_rules: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    sysvar_instructions: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    ata_program: Program<'info, AssociatedToken>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = mpl_token_metadata::id())]
+    metadata_program: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    authorization_rules_program: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::{constants::*, events::RaffleWithdrawn, states::*};
+
+use super::withdraw_proceeds;
+
+/// Push proceeds of an ended raffle to its creator, callable by anyone
+pub fn push_proceeds<'info>(
+    ctx: Context<'_, '_, '_, 'info, PushProceedsCtx<'info>>,
+    idx: u32,
+) -> Result<()> {
+    let amount = withdraw_proceeds(
+        &ctx.accounts.raffle_account,
+        idx,
+        &ctx.accounts.authority.key(),
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.spl_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    emit_cpi!(RaffleWithdrawn {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        treasury: ctx.accounts.treasury_token_account.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32)]
+pub struct PushProceedsCtx<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: raffle creator, checked by `has_one` of raffle account
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    /// CHECK: token mint as SOL, SPL or Token-2022
+    pub spl_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: creator wallet for SOL, its token account otherwise
+    #[account(mut)]
+    pub treasury_token_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
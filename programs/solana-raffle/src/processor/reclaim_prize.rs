@@ -0,0 +1,111 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use mpl_token_metadata::instruction::thaw_delegated_account;
+
+use crate::{constants::*, error::*, events::PrizeReclaimed, states::*, utils::bump};
+
+/// Reclaim prize NFT by creator
+pub fn reclaim_prize(ctx: Context<ReclaimPrizeCtx>, idx: u32, slot: u8) -> Result<()> {
+    let now = (Clock::get().unwrap().unix_timestamp) as u64;
+    let prize = {
+        let raffle_key = ctx.accounts.raffle_account.key();
+        let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+        let total_shards = (raffle_account.total_shards as usize).min(ctx.remaining_accounts.len());
+        raffle_account.sync_sales(&raffle_key, &ctx.remaining_accounts[..total_shards])?;
+        *raffle_account.reclaimable_prize(slot, now)?
+    };
+    require!(
+        prize.kind == PrizeKind::Nft as u8,
+        RaffleError::InvalidPrizeKind
+    );
+    require!(
+        prize.mint == ctx.accounts.mint.key(),
+        RaffleError::InvalidMint
+    );
+
+    // Thaw token account
+    let raffle_account_key = ctx.accounts.raffle_account.key();
+    let seeds = &[
+        PREFIX,
+        raffle_account_key.as_ref(),
+        ESCROW,
+        &[bump(
+            &[PREFIX, raffle_account_key.as_ref(), ESCROW],
+            ctx.program_id,
+        )],
+    ];
+
+    invoke_signed(
+        &thaw_delegated_account(
+            mpl_token_metadata::id(),
+            ctx.accounts.escrow_account.key(),
+            ctx.accounts.token_account.key(),
+            *ctx.accounts.edition.key,
+            ctx.accounts.mint.key(),
+        ),
+        &[
+            ctx.accounts.escrow_account.to_account_info(),
+            ctx.accounts.token_account.to_account_info(),
+            ctx.accounts.edition.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+        ],
+        &[seeds],
+    )?;
+
+    // Update status
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeReclaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8)]
+pub struct ReclaimPrizeCtx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+        has_one = authority,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: validate nft edition account
+    edition: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = token_account.mint == mint.key()
+        && token_account.owner == authority.key()
+        @ RaffleError::InvalidTokenAccount
+    )]
+    token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: token account for escrow
+    #[account(
+        mut,
+        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW],
+        bump
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = mpl_token_metadata::id())]
+    metadata_program: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{constants::*, error::*, events::PrizeReclaimed, states::*, utils::bump};
+
+/// Reclaim prize Token-2022 NFT by creator
+pub fn reclaim_prize_2022(ctx: Context<ReclaimPrize2022Ctx>, idx: u32, slot: u8) -> Result<()> {
+    let now = (Clock::get().unwrap().unix_timestamp) as u64;
+    let prize = {
+        let raffle_key = ctx.accounts.raffle_account.key();
+        let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+        let total_shards = (raffle_account.total_shards as usize).min(ctx.remaining_accounts.len());
+        raffle_account.sync_sales(&raffle_key, &ctx.remaining_accounts[..total_shards])?;
+        *raffle_account.reclaimable_prize(slot, now)?
+    };
+    require!(
+        prize.kind == PrizeKind::Nft2022 as u8,
+        RaffleError::InvalidPrizeKind
+    );
+    require!(
+        prize.mint == ctx.accounts.mint.key(),
+        RaffleError::InvalidMint
+    );
+
+    // Return nft to creator
+    let seeds = &[
+        PREFIX,
+        RAFFLE,
+        &idx.to_be_bytes(),
+        &[bump(&[PREFIX, RAFFLE, &idx.to_be_bytes()], ctx.program_id)],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.prize_escrow.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.raffle_account.to_account_info(),
+            },
+            &[seeds],
+        ),
+        1,
+        0,
+    )?;
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.prize_escrow.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.raffle_account.to_account_info(),
+        },
+        &[seeds],
+    ))?;
+
+    // Update status
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeReclaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8)]
+pub struct ReclaimPrize2022Ctx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+        has_one = authority,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [PREFIX, raffle_account.key().as_ref(), PRIZE, &[slot]],
+        bump,
+    )]
+    prize_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = token_account.mint == mint.key()
+        && token_account.owner == authority.key()
+        @ RaffleError::InvalidTokenAccount
+    )]
+    token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,
+}
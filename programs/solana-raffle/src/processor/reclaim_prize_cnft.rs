@@ -0,0 +1,152 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+
+use crate::{
+    bubblegum::{self, compression, noop, BUBBLEGUM_ID},
+    constants::*,
+    error::*,
+    events::PrizeReclaimed,
+    states::*,
+    utils::bump,
+};
+
+/// Reclaim prize compressed NFT by creator, remaining accounts are
+/// ticket shards followed by the leaf proof
+pub fn reclaim_prize_cnft<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReclaimPrizeCnftCtx<'info>>,
+    idx: u32,
+    slot: u8,
+    root: [u8; 32],
+) -> Result<()> {
+    let now = (Clock::get().unwrap().unix_timestamp) as u64;
+    let (prize, proof) = {
+        let raffle_key = ctx.accounts.raffle_account.key();
+        let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+        let total_shards = raffle_account.total_shards as usize;
+        require!(
+            ctx.remaining_accounts.len() >= total_shards,
+            RaffleError::InvalidTicketShard
+        );
+        let (shards, proof) = ctx.remaining_accounts.split_at(total_shards);
+        raffle_account.sync_sales(&raffle_key, shards)?;
+        (*raffle_account.reclaimable_prize(slot, now)?, proof)
+    };
+    require!(
+        prize.kind == PrizeKind::Cnft as u8,
+        RaffleError::InvalidPrizeKind
+    );
+
+    // Return leaf to creator
+    let raffle_account_key = ctx.accounts.raffle_account.key();
+    let seeds = &[
+        PREFIX,
+        raffle_account_key.as_ref(),
+        ESCROW,
+        &[bump(
+            &[PREFIX, raffle_account_key.as_ref(), ESCROW],
+            ctx.program_id,
+        )],
+    ];
+
+    let compressed_prize = &ctx.accounts.compressed_prize;
+    let escrow_key = ctx.accounts.escrow_account.key();
+    let mut account_infos = vec![
+        ctx.accounts.tree_authority.to_account_info(),
+        ctx.accounts.escrow_account.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.merkle_tree.to_account_info(),
+        ctx.accounts.log_wrapper.to_account_info(),
+        ctx.accounts.compression_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.bubblegum_program.to_account_info(),
+    ];
+    account_infos.extend_from_slice(proof);
+
+    invoke_signed(
+        &bubblegum::transfer(
+            &compressed_prize.merkle_tree,
+            &escrow_key,
+            &escrow_key,
+            &ctx.accounts.authority.key(),
+            root,
+            compressed_prize.data_hash,
+            compressed_prize.creator_hash,
+            compressed_prize.nonce,
+            compressed_prize.index,
+            proof,
+        ),
+        &account_infos,
+        &[seeds],
+    )?;
+
+    // Update status
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeReclaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8)]
+pub struct ReclaimPrizeCnftCtx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+        has_one = authority,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        mut,
+        seeds = [PREFIX, raffle_account.key().as_ref(), PRIZE, &[slot]],
+        bump,
+        has_one = merkle_tree,
+        close = authority,
+    )]
+    compressed_prize: Box<Account<'info, CompressedPrize>>,
+
+    /// CHECK: leaf owner holding the prize
+    #[account(
+        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW],
+        bump
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: tree config of merkle tree
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        seeds::program = bubblegum_program.key(),
+    )]
+    tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: matched against compressed prize
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = noop::ID)]
+    log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = compression::ID)]
+    compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = BUBBLEGUM_ID)]
+    bubblegum_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
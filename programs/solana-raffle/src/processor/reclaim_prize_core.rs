@@ -0,0 +1,143 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+
+use crate::{
+    constants::*,
+    error::*,
+    events::PrizeReclaimed,
+    mpl_core::{self, CoreAsset, CorePlugin, MPL_CORE_ID},
+    states::*,
+    utils::bump,
+};
+
+/// Reclaim prize Metaplex Core asset by creator
+pub fn reclaim_prize_core(ctx: Context<ReclaimPrizeCoreCtx>, idx: u32, slot: u8) -> Result<()> {
+    let now = (Clock::get().unwrap().unix_timestamp) as u64;
+    let prize = {
+        let raffle_key = ctx.accounts.raffle_account.key();
+        let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+        let total_shards = (raffle_account.total_shards as usize).min(ctx.remaining_accounts.len());
+        raffle_account.sync_sales(&raffle_key, &ctx.remaining_accounts[..total_shards])?;
+        *raffle_account.reclaimable_prize(slot, now)?
+    };
+    require!(
+        prize.kind == PrizeKind::Core as u8,
+        RaffleError::InvalidPrizeKind
+    );
+    require!(
+        prize.mint == ctx.accounts.asset.key(),
+        RaffleError::InvalidMint
+    );
+
+    let asset = CoreAsset::from_account_info(&ctx.accounts.asset)?;
+    require!(
+        asset.collection == ctx.accounts.collection.as_ref().map(|c| c.key()),
+        RaffleError::InvalidAsset
+    );
+
+    // Thaw asset and hand plugin authorities back to creator
+    let raffle_account_key = ctx.accounts.raffle_account.key();
+    let seeds = &[
+        PREFIX,
+        raffle_account_key.as_ref(),
+        ESCROW,
+        &[bump(
+            &[PREFIX, raffle_account_key.as_ref(), ESCROW],
+            ctx.program_id,
+        )],
+    ];
+
+    let asset_key = ctx.accounts.asset.key();
+    let collection = asset.collection.as_ref();
+    let escrow_key = ctx.accounts.escrow_account.key();
+    let authority_key = ctx.accounts.authority.key();
+    let mut account_infos = vec![
+        ctx.accounts.asset.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.escrow_account.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.core_program.to_account_info(),
+    ];
+    if let Some(collection) = &ctx.accounts.collection {
+        account_infos.push(collection.to_account_info());
+    }
+
+    invoke_signed(
+        &mpl_core::update_plugin(
+            &asset_key,
+            collection,
+            &authority_key,
+            &escrow_key,
+            CorePlugin::FreezeDelegate { frozen: false },
+        ),
+        &account_infos,
+        &[seeds],
+    )?;
+
+    for plugin in [
+        CorePlugin::FreezeDelegate { frozen: false },
+        CorePlugin::TransferDelegate,
+    ] {
+        invoke_signed(
+            &mpl_core::revoke_plugin_authority(
+                &asset_key,
+                collection,
+                &authority_key,
+                &escrow_key,
+                plugin,
+            ),
+            &account_infos,
+            &[seeds],
+        )?;
+    }
+
+    // Update status
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeReclaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8)]
+pub struct ReclaimPrizeCoreCtx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+        has_one = authority,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    /// CHECK: Core asset, matched against prize in handler
+    #[account(mut)]
+    asset: UncheckedAccount<'info>,
+
+    /// CHECK: collection of the asset, matched against asset in handler
+    #[account(mut)]
+    collection: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: plugin authority of deposited asset
+    #[account(
+        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW],
+        bump
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: No need to deserialize.
+    #[account(address = MPL_CORE_ID)]
+    core_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,147 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use mpl_token_metadata::instruction::{builders::UnlockBuilder, InstructionBuilder, UnlockArgs};
+
+use crate::{constants::*, error::*, events::PrizeReclaimed, states::*, utils::bump};
+
+/// Reclaim prize PNFT by creator
+pub fn reclaim_prize_pnft(ctx: Context<ReclaimPrizePnftCtx>, idx: u32, slot: u8) -> Result<()> {
+    let now = (Clock::get().unwrap().unix_timestamp) as u64;
+    let prize = {
+        let raffle_key = ctx.accounts.raffle_account.key();
+        let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+        let total_shards = (raffle_account.total_shards as usize).min(ctx.remaining_accounts.len());
+        raffle_account.sync_sales(&raffle_key, &ctx.remaining_accounts[..total_shards])?;
+        *raffle_account.reclaimable_prize(slot, now)?
+    };
+    require!(
+        prize.kind == PrizeKind::Pnft as u8,
+        RaffleError::InvalidPrizeKind
+    );
+    require!(
+        prize.mint == ctx.accounts.mint.key(),
+        RaffleError::InvalidMint
+    );
+
+    // Unlock nft
+    let raffle_account_key = ctx.accounts.raffle_account.key();
+    let seeds = &[
+        PREFIX,
+        raffle_account_key.as_ref(),
+        ESCROW,
+        &[bump(
+            &[PREFIX, raffle_account_key.as_ref(), ESCROW],
+            ctx.program_id,
+        )],
+    ];
+
+    let unlock_ix = UnlockBuilder::new()
+        .authority(ctx.accounts.escrow_account.key())
+        .mint(ctx.accounts.mint.key())
+        .metadata(ctx.accounts.metadata.key())
+        .edition(ctx.accounts.edition.key())
+        .token(ctx.accounts.token_account.key())
+        .token_record(ctx.accounts.token_record.key())
+        .system_program(ctx.accounts.system_program.key())
+        .sysvar_instructions(ctx.accounts.sysvar_instructions.key())
+        .spl_token_program(ctx.accounts.token_program.key())
+        .authorization_rules(ctx.accounts.authorization_rules.key())
+        .authorization_rules_program(ctx.accounts.authorization_rules_program.key())
+        .payer(ctx.accounts.authority.key())
+        .build(UnlockArgs::V1 {
+            authorization_data: None,
+        })
+        .unwrap()
+        .instruction();
+
+    let unlock_accounts = [
+        ctx.accounts.metadata_program.to_account_info(),
+        ctx.accounts.escrow_account.to_account_info(),
+        ctx.accounts.token_record.to_account_info(),
+        ctx.accounts.token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.metadata.to_account_info(),
+        ctx.accounts.edition.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.authorization_rules_program.to_account_info(),
+        ctx.accounts.authorization_rules.to_account_info(),
+        ctx.accounts.sysvar_instructions.to_account_info(),
+    ];
+
+    invoke_signed(&unlock_ix, &unlock_accounts, &[seeds])?;
+
+    // Update status
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeReclaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8)]
+pub struct ReclaimPrizePnftCtx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+        has_one = authority,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: validate whitelist collection
+    #[account(mut)]
+    metadata: UncheckedAccount<'info>,
+
+    /// CHECK: validate nft edition account
+    edition: UncheckedAccount<'info>,
+
+    /// CHECK: validate token record account
+    #[account(mut)]
+    token_record: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = token_account.mint == mint.key()
+        && token_account.owner == authority.key()
+        && token_account.amount == 1
+        @ RaffleError::InvalidTokenAccount
+    )]
+    token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: token account for escrow
+    #[account(
+        mut,
+        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW],
+        bump
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    authorization_rules: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    sysvar_instructions: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = mpl_token_metadata::id())]
+    metadata_program: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    authorization_rules_program: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
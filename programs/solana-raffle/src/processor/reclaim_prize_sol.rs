@@ -0,0 +1,89 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+
+use crate::{constants::*, error::*, events::PrizeReclaimed, states::*, utils::bump};
+
+/// Reclaim prize SOL amount by creator
+pub fn reclaim_prize_sol(ctx: Context<ReclaimPrizeSolCtx>, idx: u32, slot: u8) -> Result<()> {
+    let now = (Clock::get().unwrap().unix_timestamp) as u64;
+    let prize = {
+        let raffle_key = ctx.accounts.raffle_account.key();
+        let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+        let total_shards = (raffle_account.total_shards as usize).min(ctx.remaining_accounts.len());
+        raffle_account.sync_sales(&raffle_key, &ctx.remaining_accounts[..total_shards])?;
+        *raffle_account.reclaimable_prize(slot, now)?
+    };
+    require!(
+        prize.kind == PrizeKind::Sol as u8,
+        RaffleError::InvalidPrizeKind
+    );
+
+    // Return whole prize vault balance to creator
+    let raffle_account_key = ctx.accounts.raffle_account.key();
+    let seeds = &[
+        PREFIX,
+        raffle_account_key.as_ref(),
+        PRIZE,
+        &[slot],
+        &[bump(
+            &[PREFIX, raffle_account_key.as_ref(), PRIZE, &[slot]],
+            ctx.program_id,
+        )],
+    ];
+
+    invoke_signed(
+        &system_instruction::transfer(
+            &ctx.accounts.prize_vault.key(),
+            &ctx.accounts.authority.key(),
+            ctx.accounts.prize_vault.lamports(),
+        ),
+        &[
+            ctx.accounts.prize_vault.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[seeds],
+    )?;
+
+    // Update status
+    ctx.accounts.raffle_account.load_mut()?.prizes_mut()[slot as usize].is_claimed = 1;
+
+    emit_cpi!(PrizeReclaimed {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        slot,
+        kind: prize.kind(),
+        mint: prize.mint,
+        amount: prize.amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, slot: u8)]
+pub struct ReclaimPrizeSolCtx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+        has_one = authority,
+    )]
+    raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    /// CHECK: system account holding the prize lamports
+    #[account(
+        mut,
+        seeds = [PREFIX, raffle_account.key().as_ref(), PRIZE, &[slot]],
+        bump
+    )]
+    prize_vault: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::spl_token::native_mint,
+    token_interface::{Mint, TokenInterface},
+};
+
+use crate::{
+    constants::*,
+    error::*,
+    events::TicketsRefunded,
+    states::*,
+    utils::{assert_is_ata, assert_keys_equal, shard_escrows, ProceedsAccounts},
+};
+
+/// Refund tickets of cancelled raffle, or raffle ended below minimum sales.
+///
+/// Anyone can trigger the refund, funds always go back to the ticket owner.
+/// Buyer gets back exactly what was deposited to escrow, the platform fee
+/// held for the vault is not returned.
+pub fn refund_ticket<'info>(
+    ctx: Context<'_, '_, '_, 'info, RefundTicketCtx<'info>>,
+    idx: u32,
+    uid: [u8; ID_LENGTH],
+) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let raffle_account = &ctx.accounts.raffle_account;
+    let user_account = &ctx.accounts.user_account;
+    let user_token_account = &ctx.accounts.user_token_account;
+
+    let now = (Clock::get().unwrap().unix_timestamp) as u64;
+    let (spl_mint, total_shards) = {
+        let mut raffle = raffle_account.load_mut()?;
+        let total_shards = (raffle.total_shards as usize).min(ctx.remaining_accounts.len());
+        raffle.sync_sales(
+            &raffle_account.key(),
+            &ctx.remaining_accounts[..total_shards],
+        )?;
+        require!(raffle.is_refundable(now), RaffleError::RaffleNotRefundable);
+        (raffle.spl_mint, total_shards)
+    };
+
+    // Check token mint
+    require!(
+        ctx.accounts.spl_mint.key() == spl_mint,
+        RaffleError::InvalidMint
+    );
+
+    if spl_mint == native_mint::id() {
+        assert_keys_equal(user_token_account.key(), authority.key())?;
+    } else {
+        assert_is_ata(
+            &user_token_account.to_account_info(),
+            &authority.key(),
+            &spl_mint,
+        )?;
+    }
+
+    // Deposit is paid out of whichever shard escrows still hold proceeds
+    let (shards, escrows) = ctx.remaining_accounts.split_at(total_shards);
+    let shard_escrows = shard_escrows(
+        &raffle_account.key(),
+        &spl_mint,
+        shards,
+        escrows,
+        ctx.program_id,
+    )?;
+
+    let raffle_info = raffle_account.to_account_info();
+    let proceeds_accounts = ProceedsAccounts::new(
+        &raffle_info,
+        idx,
+        &ctx.accounts.spl_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        ctx.program_id,
+    );
+    let amount = user_account.deposited;
+    let mut remaining = amount;
+    for escrow in &shard_escrows {
+        let part = remaining.min(escrow.proceeds);
+        proceeds_accounts.transfer(escrow, user_token_account, part)?;
+        remaining -= part;
+    }
+    require!(remaining == 0, RaffleError::InsufficientBalance);
+
+    emit_cpi!(TicketsRefunded {
+        raffle: raffle_account.key(),
+        idx,
+        buyer: authority.key(),
+        uid,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, uid: [u8; ID_LENGTH])]
+pub struct RefundTicketCtx<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: ticket owner receiving refund
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+    )]
+    pub raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        mut,
+        seeds=[USER, raffle_account.key().as_ref(), uid.as_ref()], 
+        bump,
+        has_one = authority,
+        close = authority,
+    )]
+    pub user_account: Box<Account<'info, UserAccount>>,
+
+    /// CHECK: token mint as SOL, SPL or Token-2022
+    pub spl_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: token account for receive refund
+    #[account(mut)]
+    pub user_token_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
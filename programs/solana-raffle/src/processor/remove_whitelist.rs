@@ -1,36 +1,48 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, error::RaffleError, states::*};
-
-/// Remove collection from whitelist
-pub fn remove_whitelist(ctx: Context<RemoveWhitelistCtx>, collection: Pubkey) -> Result<()> {
-    let global_account = &mut ctx.accounts.global_account;
-
-    if let Some(idx) = global_account
-        .wl_collections
-        .iter_mut()
-        .position(|&mut _collection| _collection == collection)
-    {
-        global_account.wl_collections[idx] = Pubkey::default();
-    } else {
-        return err!(RaffleError::InvalidCollection);
-    }
+use crate::{constants::*, error::RaffleError, events::WhitelistUpdated, states::*};
+
+/// Remove collection or creator from whitelist
+pub fn remove_whitelist(ctx: Context<RemoveWhitelistCtx>, key: Pubkey) -> Result<()> {
+    let whitelist_entry = &mut ctx.accounts.whitelist_entry;
+
+    require!(
+        whitelist_entry.is_enabled != 0,
+        RaffleError::InvalidCollection
+    );
+
+    whitelist_entry.is_enabled = 0;
+
+    emit_cpi!(WhitelistUpdated {
+        key,
+        kind: whitelist_entry.kind(),
+        is_enabled: false,
+        authority: ctx.accounts.authority.key(),
+    });
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(key: Pubkey)]
 pub struct RemoveWhitelistCtx<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
-        mut, 
         seeds=[PREFIX], 
         bump, 
         has_one = authority
     )]
     pub global_account: Box<Account<'info, GlobalAccount>>,
 
+    #[account(
+        mut,
+        seeds=[PREFIX, WHITELIST, key.as_ref()],
+        bump
+    )]
+    pub whitelist_entry: Box<Account<'info, WhitelistEntry>>,
+
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,284 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::spl_token::native_mint,
+    token_interface::{Mint, TokenInterface},
+};
+use bytemuck::Zeroable;
+
+use crate::{
+    constants::*,
+    error::*,
+    events::RandomnessRequested,
+    randomness::{
+        is_oracle_timed_out, is_slot_hash_expired, OracleProvider, RandomnessProvider,
+        SlotHashProvider,
+    },
+    states::*,
+    utils::{assert_is_ata, assert_keys_equal, shard_escrows, ProceedsAccounts},
+};
+
+/// Commit raffle draw to a future slot hash or an oracle request.
+/// Anyone may request once the raffle ended, authority also once sold out
+pub fn request_randomness<'info>(
+    ctx: Context<'_, '_, '_, 'info, RequestRandomnessCtx<'info>>,
+    idx: u32,
+    source: RandomnessSource,
+) -> Result<()> {
+    let payer = &ctx.accounts.payer;
+    let raffle_key = ctx.accounts.raffle_account.key();
+    let is_authority = payer.key() == ctx.accounts.global_account.authority;
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    let (randomness, total_sales, crank_reward_percent, total_shards) = {
+        let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+        require!(
+            raffle_account.is_deposited != 0,
+            RaffleError::PrizeNotDeposited
+        );
+        require!(
+            raffle_account.is_revealed == 0,
+            RaffleError::WinnerAlreadyRevealed
+        );
+        require!(
+            raffle_account.is_cancelled == 0,
+            RaffleError::RaffleCancelled
+        );
+        require!(
+            ctx.accounts.spl_mint.key() == raffle_account.spl_mint,
+            RaffleError::InvalidMint
+        );
+
+        // Final sales snapshot, ticket shards are passed as remaining accounts
+        // followed by their escrows
+        let total_shards = (raffle_account.total_shards as usize).min(ctx.remaining_accounts.len());
+        raffle_account.sync_sales(&raffle_key, &ctx.remaining_accounts[..total_shards])?;
+        require!(
+            raffle_account.total_sales >= raffle_account.min_sales,
+            RaffleError::MinSalesNotReached
+        );
+
+        // Request again only when committed slot hash fell out of `SlotHashes`,
+        // or oracle did not fulfill the request in time
+        let ended = now >= raffle_account.end_date;
+        match raffle_account.randomness.source() {
+            RandomnessSource::None if !ended => {
+                require!(
+                    raffle_account.total_supply == raffle_account.total_sales,
+                    RaffleError::RaffleNotEnded
+                );
+                require!(is_authority, RaffleError::EarlyRevealNotAllowed);
+            }
+            RandomnessSource::None => {}
+            RandomnessSource::SlotHash => {
+                require!(
+                    is_slot_hash_expired(&raffle_account.randomness, clock.slot),
+                    RaffleError::RandomnessAlreadyRequested
+                );
+            }
+            RandomnessSource::Oracle => {
+                require!(
+                    is_oracle_timed_out(&raffle_account.randomness, clock.slot),
+                    RaffleError::RandomnessAlreadyRequested
+                );
+
+                // Late answer of the oracle is settled rather than replaced
+                let request = ctx
+                    .accounts
+                    .committed_request
+                    .as_ref()
+                    .ok_or(RaffleError::InvalidRandomnessAccount)?;
+                require_keys_eq!(
+                    request.key(),
+                    raffle_account.randomness.request,
+                    RaffleError::InvalidRandomnessAccount
+                );
+                let provider = OracleProvider {
+                    request,
+                    raffle: raffle_key,
+                };
+                require!(
+                    !provider.is_fulfilled()?,
+                    RaffleError::RandomnessAlreadyRequested
+                );
+            }
+        }
+        // Crank reward is paid once, for the first request of an ended raffle
+        let is_crank = ended && raffle_account.randomness.source() == RandomnessSource::None;
+        let crank_reward_percent = if is_crank {
+            raffle_account.crank_reward_percent
+        } else {
+            0
+        };
+
+        let mut randomness = RaffleRandomness::zeroed();
+        randomness.source = source as u8;
+        if raffle_account.randomness.source() != RandomnessSource::None {
+            randomness.attempt = raffle_account
+                .randomness
+                .attempt
+                .checked_add(1)
+                .ok_or(RaffleError::NumericOverflow)?;
+        }
+        match source {
+            RandomnessSource::SlotHash => {
+                let provider = SlotHashProvider {
+                    slot_hashes: &ctx.accounts.recent_slothashes,
+                };
+                provider.commit(&mut randomness, clock.slot)?;
+            }
+            RandomnessSource::Oracle => {
+                let request = ctx
+                    .accounts
+                    .oracle_request
+                    .as_ref()
+                    .ok_or(RaffleError::InvalidRandomnessAccount)?;
+                let provider = OracleProvider {
+                    request,
+                    raffle: raffle_key,
+                };
+                provider.commit(&mut randomness, clock.slot)?;
+            }
+            RandomnessSource::None => return err!(RaffleError::InvalidRandomnessAccount),
+        }
+
+        raffle_account.randomness = randomness;
+        // Ticket sales are closed once the draw is committed
+        raffle_account.end_date = raffle_account.end_date.min(now);
+
+        (
+            randomness,
+            raffle_account.total_sales,
+            crank_reward_percent,
+            total_shards,
+        )
+    };
+
+    let crank_reward = pay_crank_reward(&ctx, idx, total_shards, crank_reward_percent)?;
+
+    emit_cpi!(RandomnessRequested {
+        raffle: raffle_key,
+        idx,
+        source,
+        request: randomness.request,
+        slot: randomness.slot,
+        total_sales,
+        requester: payer.key(),
+        crank_reward,
+    });
+
+    Ok(())
+}
+
+/// Pay `crank_reward_percent` of escrowed proceeds of every shard to the payer. Refunds never
+/// come short of it, the raffle can not be cancelled once randomness is requested
+fn pay_crank_reward<'info>(
+    ctx: &Context<'_, '_, '_, 'info, RequestRandomnessCtx<'info>>,
+    idx: u32,
+    total_shards: usize,
+    crank_reward_percent: u16,
+) -> Result<u64> {
+    if crank_reward_percent == 0 {
+        return Ok(0);
+    }
+
+    let payer = &ctx.accounts.payer;
+    let raffle_account = &ctx.accounts.raffle_account;
+    let payer_token_account = &ctx.accounts.payer_token_account;
+    let spl_mint = ctx.accounts.spl_mint.key();
+
+    let (shards, escrows) = ctx.remaining_accounts.split_at(total_shards);
+    let shard_escrows = shard_escrows(
+        &raffle_account.key(),
+        &spl_mint,
+        shards,
+        escrows,
+        ctx.program_id,
+    )?;
+    let rewards = shard_escrows
+        .iter()
+        .map(|escrow| {
+            let reward = (escrow.proceeds as u128)
+                .checked_mul(crank_reward_percent as u128)
+                .ok_or(RaffleError::NumericOverflow)?
+                / BASIS_POINTS as u128;
+            Ok(reward as u64)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Nothing to pay out of empty escrows, payer token account is not needed
+    if rewards.iter().all(|reward| *reward == 0) {
+        return Ok(0);
+    }
+
+    if spl_mint == native_mint::id() {
+        assert_keys_equal(payer_token_account.key(), payer.key())?;
+    } else {
+        assert_is_ata(
+            &payer_token_account.to_account_info(),
+            &payer.key(),
+            &spl_mint,
+        )?;
+    }
+
+    let raffle_info = raffle_account.to_account_info();
+    let proceeds_accounts = ProceedsAccounts::new(
+        &raffle_info,
+        idx,
+        &ctx.accounts.spl_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        ctx.program_id,
+    );
+    let mut total_reward = 0u64;
+    for (escrow, reward) in shard_escrows.iter().zip(rewards) {
+        proceeds_accounts.transfer(escrow, payer_token_account, reward)?;
+        total_reward = total_reward
+            .checked_add(reward)
+            .ok_or(RaffleError::NumericOverflow)?;
+    }
+
+    Ok(total_reward)
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32)]
+pub struct RequestRandomnessCtx<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds=[PREFIX],
+        bump,
+    )]
+    pub global_account: Box<Account<'info, GlobalAccount>>,
+
+    #[account(
+        mut,
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()],
+        bump,
+    )]
+    pub raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    /// CHECK: token mint as SOL, SPL or Token-2022
+    pub spl_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: payer wallet for SOL, its token account otherwise, receives crank reward
+    #[account(mut)]
+    pub payer_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: owner, layout and raffle bound seed checked by oracle provider
+    pub oracle_request: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: timed out oracle request being replaced, checked against raffle randomness
+    pub committed_request: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: account constraints checked in account trait
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::id())]
+    pub recent_slothashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, events::FeeConfigUpdated, states::*};
+
+/// Set global fee split, empty recipients send the whole fee to vault.
+/// Each split is a new version, raffles keep the one set at their creation
+pub fn set_fee_config(ctx: Context<SetFeeConfigCtx>, recipients: Vec<FeeRecipient>) -> Result<()> {
+    let global_account = &mut ctx.accounts.global_account;
+
+    if recipients.is_empty() {
+        global_account.has_fee_config = 0;
+    } else {
+        let fee_config = ctx
+            .accounts
+            .fee_config
+            .as_mut()
+            .ok_or(RaffleError::InvalidFeeConfig)?;
+        let version = global_account
+            .fee_config_version
+            .checked_add(1)
+            .ok_or(RaffleError::NumericOverflow)?;
+        fee_config.set(Pubkey::default(), version, &recipients)?;
+        global_account.fee_config_version = version;
+        global_account.has_fee_config = 1;
+    }
+
+    emit_cpi!(FeeConfigUpdated {
+        raffle: Pubkey::default(),
+        recipients,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetFeeConfigCtx<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX], 
+        bump, 
+        has_one = authority
+    )]
+    pub global_account: Box<Account<'info, GlobalAccount>>,
+
+    /// Next version of the global split, omitted when clearing it
+    #[account(
+        init,
+        seeds=[PREFIX, FEE, &(global_account.fee_config_version + 1).to_be_bytes()],
+        bump,
+        space=FeeConfig::SIZE,
+        payer=authority,
+    )]
+    pub fee_config: Option<Box<Account<'info, FeeConfig>>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, events::FeeConfigUpdated, states::*};
+
+/// Set fee split of a single raffle by admin, overriding the global one.
+/// Empty recipients fall back to the global split snapshot at creation.
+///
+/// Fees held by shard escrows are split when swept, so the split can only
+/// change once every shard, passed as remaining accounts, has been swept
+pub fn set_raffle_fee_config<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetRaffleFeeConfigCtx<'info>>,
+    _idx: u32,
+    recipients: Vec<FeeRecipient>,
+) -> Result<()> {
+    let raffle_key = ctx.accounts.raffle_account.key();
+    let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+
+    // Tickets are sold only once every shard exists
+    if raffle_account.created_shards == raffle_account.total_shards {
+        let total_shards = (raffle_account.total_shards as usize).min(ctx.remaining_accounts.len());
+        let shards = &ctx.remaining_accounts[..total_shards];
+        raffle_account.sync_sales(&raffle_key, shards)?;
+        for shard_info in shards {
+            let shard = AccountLoader::<TicketShard>::try_from(shard_info)?;
+            require!(shard.load()?.fees == 0, RaffleError::FeesNotSwept);
+        }
+    }
+
+    if recipients.is_empty() {
+        raffle_account.has_fee_config = 0;
+    } else {
+        ctx.accounts.fee_config.set(raffle_key, 0, &recipients)?;
+        raffle_account.has_fee_config = 1;
+    }
+
+    emit_cpi!(FeeConfigUpdated {
+        raffle: raffle_key,
+        recipients,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32)]
+pub struct SetRaffleFeeConfigCtx<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds=[PREFIX], 
+        bump, 
+        has_one = authority
+    )]
+    pub global_account: Box<Account<'info, GlobalAccount>>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+    )]
+    pub raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        init_if_needed, 
+        seeds=[PREFIX, raffle_account.key().as_ref(), FEE], 
+        bump, 
+        space=FeeConfig::SIZE,
+        payer=authority,
+    )]
+    pub fee_config: Box<Account<'info, FeeConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::*,
+    events::WinnersRevealed,
+    randomness::{
+        draw_seed, recompute_winners, OracleProvider, RandomnessProvider, SlotHashProvider,
+    },
+    states::*,
+};
+
+/// Consume requested randomness and draw winners of every prize slot
+pub fn settle_randomness(ctx: Context<SettleRandomnessCtx>, _idx: u32) -> Result<()> {
+    let raffle_key = ctx.accounts.raffle_account.key();
+    let mut raffle_account = ctx.accounts.raffle_account.load_mut()?;
+    require!(
+        raffle_account.is_revealed == 0,
+        RaffleError::WinnerAlreadyRevealed
+    );
+    require!(
+        raffle_account.is_cancelled == 0,
+        RaffleError::RaffleCancelled
+    );
+
+    let clock = Clock::get()?;
+    let slot = clock.slot;
+    let value = match raffle_account.randomness.source() {
+        RandomnessSource::SlotHash => {
+            let provider = SlotHashProvider {
+                slot_hashes: &ctx.accounts.recent_slothashes,
+            };
+            provider.reveal(&raffle_account.randomness, slot)?
+        }
+        RandomnessSource::Oracle => {
+            let request = ctx
+                .accounts
+                .oracle_request
+                .as_ref()
+                .ok_or(RaffleError::InvalidRandomnessAccount)?;
+            let provider = OracleProvider {
+                request,
+                raffle: raffle_key,
+            };
+            provider.reveal(&raffle_account.randomness, slot)?
+        }
+        RandomnessSource::None => return err!(RaffleError::RandomnessNotRequested),
+    };
+
+    // Draw distinct winning tickets, one per prize slot
+    let winners = recompute_winners(
+        &value,
+        &raffle_key,
+        raffle_account.total_sales,
+        raffle_account.prizes().len() as u32,
+    );
+    for (prize, winner_idx) in raffle_account.prizes_mut().iter_mut().zip(winners.iter()) {
+        prize.winner_idx = *winner_idx;
+    }
+
+    raffle_account.randomness.value = value;
+    raffle_account.randomness.seed = draw_seed(&value, &raffle_key);
+    raffle_account.randomness.timestamp = clock.unix_timestamp as u64;
+    raffle_account.is_revealed = 1;
+
+    let randomness = &raffle_account.randomness;
+    emit_cpi!(WinnersRevealed {
+        raffle: raffle_key,
+        idx: raffle_account.idx,
+        source: randomness.source(),
+        request: randomness.request,
+        slot: randomness.slot,
+        value: randomness.value,
+        seed: randomness.seed,
+        timestamp: randomness.timestamp,
+        total_sales: raffle_account.total_sales,
+        winners,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32)]
+pub struct SettleRandomnessCtx<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()], 
+        bump,
+    )]
+    pub raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    /// CHECK: matched against committed request by oracle provider
+    pub oracle_request: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: account constraints checked in account trait
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::id())]
+    pub recent_slothashes: UncheckedAccount<'info>,
+}
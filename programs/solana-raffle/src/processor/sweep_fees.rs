@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::spl_token::native_mint,
+    token_interface::{Mint, TokenInterface},
+};
+
+use crate::{
+    constants::*,
+    error::*,
+    events::FeesSwept,
+    states::*,
+    utils::{assert_is_ata, shard_escrows, ProceedsAccounts},
+};
+
+/// Sweep fees held by a shard escrow to the fee config recipients, or to the
+/// vault when no fee config is set. Anyone can sweep.
+///
+/// Payout is deferred to here so buying a ticket needs neither the split nor
+/// its recipient accounts. The split used is fixed for the raffle: its own one,
+/// which can't change while fees are held, else the global version snapshot
+/// at creation
+pub fn sweep_fees<'info>(
+    ctx: Context<'_, '_, '_, 'info, SweepFeesCtx<'info>>,
+    idx: u32,
+    shard: u8,
+) -> Result<()> {
+    let global_account = &ctx.accounts.global_account;
+    let raffle_account = &ctx.accounts.raffle_account;
+
+    let (spl_mint, has_fee_config, fee_config_version) = {
+        let raffle = raffle_account.load()?;
+        (
+            raffle.spl_mint,
+            raffle.has_fee_config != 0,
+            raffle.fee_config_version,
+        )
+    };
+
+    // Raffle split takes priority over the global one
+    let fee_config = if has_fee_config {
+        Some(
+            ctx.accounts
+                .raffle_fee_config
+                .as_ref()
+                .ok_or(RaffleError::InvalidFeeConfig)?,
+        )
+    } else if fee_config_version != 0 {
+        Some(
+            ctx.accounts
+                .global_fee_config
+                .as_ref()
+                .ok_or(RaffleError::InvalidFeeConfig)?,
+        )
+    } else {
+        None
+    };
+
+    // Check token mint
+    require!(
+        ctx.accounts.spl_mint.key() == spl_mint,
+        RaffleError::InvalidMint
+    );
+
+    let shard_info = ctx.accounts.ticket_shard.to_account_info();
+    let escrow_info = ctx.accounts.escrow_account.to_account_info();
+    let shard_escrows = shard_escrows(
+        &raffle_account.key(),
+        &spl_mint,
+        std::slice::from_ref(&shard_info),
+        std::slice::from_ref(&escrow_info),
+        ctx.program_id,
+    )?;
+    let escrow = &shard_escrows[0];
+
+    // Split fee by fee config, whose recipients are passed as remaining
+    // accounts in order, otherwise vault takes the whole fee
+    let vault_info = ctx.accounts.vault_token_account.to_account_info();
+    let (fee_splits, fee_accounts) = match fee_config {
+        Some(fee_config) => (fee_config.split(escrow.fees)?, ctx.remaining_accounts),
+        None => (
+            vec![(global_account.vault, escrow.fees)],
+            std::slice::from_ref(&vault_info),
+        ),
+    };
+    require!(
+        fee_accounts.len() >= fee_splits.len(),
+        RaffleError::InvalidFeeRecipient
+    );
+
+    let raffle_info = raffle_account.to_account_info();
+    let proceeds_accounts = ProceedsAccounts::new(
+        &raffle_info,
+        idx,
+        &ctx.accounts.spl_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        ctx.program_id,
+    );
+    for ((wallet, split_amount), fee_account) in fee_splits.into_iter().zip(fee_accounts) {
+        if spl_mint == native_mint::id() {
+            require!(
+                fee_account.key() == wallet,
+                RaffleError::InvalidFeeRecipient
+            );
+        } else {
+            assert_is_ata(fee_account, &wallet, &spl_mint)?;
+        }
+        proceeds_accounts.transfer(escrow, fee_account, split_amount)?;
+    }
+
+    let amount = escrow.fees;
+    ctx.accounts.ticket_shard.load_mut()?.fees = 0;
+
+    emit_cpi!(FeesSwept {
+        raffle: raffle_account.key(),
+        idx,
+        shard,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(idx: u32, shard: u8)]
+pub struct SweepFeesCtx<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds=[PREFIX],
+        bump,
+    )]
+    pub global_account: Box<Account<'info, GlobalAccount>>,
+
+    #[account(
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes()],
+        bump,
+    )]
+    pub raffle_account: AccountLoader<'info, RaffleAccountV2>,
+
+    #[account(
+        mut,
+        seeds=[PREFIX, RAFFLE, &idx.to_be_bytes(), &[shard]],
+        bump,
+    )]
+    pub ticket_shard: AccountLoader<'info, TicketShard>,
+
+    /// CHECK: token mint as SOL, SPL or Token-2022
+    pub spl_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: token account for shard escrow
+    #[account(
+        mut,
+        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW, &[shard]],
+        bump = ticket_shard.load()?.escrow_bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: token account for receive platform fee
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+
+    /// Fee split of the raffle, when it has one
+    #[account(
+        seeds=[PREFIX, raffle_account.key().as_ref(), FEE],
+        bump,
+    )]
+    pub raffle_fee_config: Option<Box<Account<'info, FeeConfig>>>,
+
+    /// Global fee split version snapshot by the raffle, when it has none
+    #[account(
+        seeds=[PREFIX, FEE, &raffle_account.load()?.fee_config_version.to_be_bytes()],
+        bump,
+    )]
+    pub global_fee_config: Option<Box<Account<'info, FeeConfig>>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, events::FeesUpdated, states::*};
+
+/// Update platform fee and default wallet cap, applied to new raffles only,
+/// referral slice of the fee and crank reward of permissionless reveals
+pub fn update_fees(
+    ctx: Context<UpdateFeesCtx>,
+    fee_percent: u16,
+    max_ticket_percent: u16,
+    referral_percent: u16,
+    crank_reward_percent: u16,
+) -> Result<()> {
+    GlobalAccount::validate_fees(fee_percent, max_ticket_percent, referral_percent)?;
+    require!(
+        crank_reward_percent <= MAX_CRANK_REWARD_PERCENT,
+        RaffleError::InvalidFeeConfig
+    );
+
+    let global_account = &mut ctx.accounts.global_account;
+    global_account.fee_percent = fee_percent;
+    global_account.max_ticket_percent = max_ticket_percent;
+    global_account.fees_initialized = 1;
+    global_account.referral_percent = referral_percent;
+    global_account.crank_reward_percent = crank_reward_percent;
+
+    emit_cpi!(FeesUpdated {
+        fee_percent,
+        max_ticket_percent,
+        referral_percent,
+        crank_reward_percent,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateFeesCtx<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds=[PREFIX], 
+        bump, 
+        has_one = authority
+    )]
+    pub global_account: Box<Account<'info, GlobalAccount>>,
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, states::*};
+use crate::{constants::*, events::SettingsUpdated, states::*};
 
 /// Update global setting
 pub fn update_setting(ctx: Context<UpdateSettingCtx>) -> Result<()> {
@@ -8,10 +8,18 @@ pub fn update_setting(ctx: Context<UpdateSettingCtx>) -> Result<()> {
 
     global_account.authority = ctx.accounts.new_authority.key();
     global_account.vault = ctx.accounts.vault.key();
+    global_account.permit_signer = ctx.accounts.permit_signer.key();
+
+    emit_cpi!(SettingsUpdated {
+        authority: global_account.authority,
+        vault: global_account.vault,
+        permit_signer: global_account.permit_signer,
+    });
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateSettingCtx<'info> {
     #[account(mut)]
@@ -23,6 +31,9 @@ pub struct UpdateSettingCtx<'info> {
     /// CHECK: vault account address
     pub vault: UncheckedAccount<'info>,
 
+    /// CHECK: key signing purchase permits
+    pub permit_signer: UncheckedAccount<'info>,
+
     #[account(
         mut, 
         seeds=[PREFIX], 
@@ -1,114 +1,127 @@
-use anchor_lang::{
-    prelude::*,
-    solana_program::{program::invoke_signed, system_instruction},
-};
-use anchor_spl::token::{
-    spl_token::{instruction::transfer, native_mint, state::Account as SplAccount},
-    Mint, Token,
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::spl_token::native_mint,
+    token_interface::{Mint, TokenInterface},
 };
 
 use crate::{
     constants::*,
     error::*,
+    events::RaffleWithdrawn,
     states::*,
-    utils::{assert_initialized, assert_is_ata, assert_keys_equal, bump},
+    utils::{assert_is_ata, assert_keys_equal, shard_escrows, ProceedsAccounts},
 };
 
 /// Withdraw raffle by creator
-pub fn withdraw_raffle(ctx: Context<WithdrawRaffleCtx>, idx: u32) -> Result<()> {
-    let authority = &ctx.accounts.authority;
-    let raffle_account = &mut ctx.accounts.raffle_account;
-    let escrow_account = &ctx.accounts.escrow_account;
-    let treasury_token_account = &ctx.accounts.treasury_token_account;
+pub fn withdraw_raffle<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawRaffleCtx<'info>>,
+    idx: u32,
+) -> Result<()> {
+    let amount = withdraw_proceeds(
+        &ctx.accounts.raffle_account,
+        idx,
+        &ctx.accounts.authority.key(),
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.spl_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    emit_cpi!(RaffleWithdrawn {
+        raffle: ctx.accounts.raffle_account.key(),
+        idx,
+        treasury: ctx.accounts.treasury_token_account.key(),
+        amount,
+    });
 
-    let now = (Clock::get().unwrap().unix_timestamp) as u64;
-    require!(
-        !raffle_account.is_withdrawn,
-        RaffleError::RaffleAlreadyWithdrawn
-    );
+    Ok(())
+}
 
-    require!(
-        now >= raffle_account.end_date || raffle_account.total_sales == raffle_account.total_supply,
-        RaffleError::RaffleNotEnded
-    );
+/// Pay proceeds of every shard escrow of an ended raffle to `treasury`, the
+/// `authority` wallet for SOL and its token account otherwise. Shards then
+/// their escrows are passed as `remaining_accounts`
+pub fn withdraw_proceeds<'info>(
+    raffle_account: &AccountLoader<'info, RaffleAccountV2>,
+    idx: u32,
+    authority: &Pubkey,
+    treasury_token_account: &AccountInfo<'info>,
+    spl_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    system_program: &Program<'info, System>,
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+) -> Result<u64> {
+    let now = (Clock::get().unwrap().unix_timestamp) as u64;
+    let (raffle_mint, total_shards) = {
+        let mut raffle = raffle_account.load_mut()?;
+        let total_shards = (raffle.total_shards as usize).min(remaining_accounts.len());
+        raffle.sync_sales(&raffle_account.key(), &remaining_accounts[..total_shards])?;
+        require!(
+            raffle.is_withdrawn == 0,
+            RaffleError::RaffleAlreadyWithdrawn
+        );
+        require!(raffle.is_cancelled == 0, RaffleError::RaffleCancelled);
+        require!(
+            raffle.total_sales >= raffle.min_sales,
+            RaffleError::MinSalesNotReached
+        );
+
+        require!(
+            now >= raffle.end_date || raffle.total_sales == raffle.total_supply,
+            RaffleError::RaffleNotEnded
+        );
+
+        (raffle.spl_mint, total_shards)
+    };
 
     // Check token mint
-    let spl_mint = raffle_account.spl_mint;
+    require!(spl_mint.key() == raffle_mint, RaffleError::InvalidMint);
+
+    if raffle_mint == native_mint::id() {
+        assert_keys_equal(treasury_token_account.key(), *authority)?;
+    } else {
+        assert_is_ata(treasury_token_account, authority, &raffle_mint)?;
+    }
+
+    // Proceeds of every shard escrow, which must have had their fees swept
+    let (shards, escrows) = remaining_accounts.split_at(total_shards);
+    let shard_escrows = shard_escrows(
+        &raffle_account.key(),
+        &raffle_mint,
+        shards,
+        escrows,
+        program_id,
+    )?;
     require!(
-        ctx.accounts.spl_mint.key() == spl_mint,
-        RaffleError::InvalidMint
+        shard_escrows.iter().all(|escrow| escrow.fees == 0),
+        RaffleError::FeesNotSwept
     );
 
-    let is_native = spl_mint == native_mint::id();
-
-    if is_native {
-        assert_keys_equal(treasury_token_account.key(), authority.key())?;
-        let amount = escrow_account.lamports();
-
-        let escrow_signer_seeds = &[
-            PREFIX,
-            &raffle_account.key().to_bytes(),
-            ESCROW,
-            &[bump(
-                &[PREFIX, &raffle_account.key().to_bytes(), ESCROW],
-                ctx.program_id,
-            )],
-        ];
-        let escrow_signer = &[&escrow_signer_seeds[..]];
-
-        invoke_signed(
-            &system_instruction::transfer(
-                &escrow_account.key(),
-                &treasury_token_account.key(),
-                amount,
-            ),
-            &[
-                escrow_account.to_account_info(),
-                ctx.accounts.authority.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-            ],
-            escrow_signer,
-        )?;
-    } else {
-        assert_is_ata(
-            &treasury_token_account.to_account_info(),
-            &authority.key(),
-            &spl_mint,
-        )?;
-
-        let escrow_token_account: SplAccount = assert_initialized(escrow_account)?;
-        let escrow_signer_seeds = &[
-            PREFIX,
-            RAFFLE,
-            &idx.to_be_bytes(),
-            &[bump(&[PREFIX, RAFFLE, &idx.to_be_bytes()], ctx.program_id)],
-        ];
-        let escrow_signer = &[&escrow_signer_seeds[..]];
-
-        invoke_signed(
-            &transfer(
-                &ctx.accounts.token_program.key(),
-                &escrow_account.key(),
-                &treasury_token_account.key(),
-                &raffle_account.key(),
-                &[],
-                escrow_token_account.amount,
-            )?,
-            &[
-                raffle_account.to_account_info(),
-                escrow_account.to_account_info(),
-                treasury_token_account.to_account_info(),
-                ctx.accounts.token_program.to_account_info(),
-            ],
-            escrow_signer,
-        )?;
+    let raffle_info = raffle_account.to_account_info();
+    let proceeds_accounts = ProceedsAccounts::new(
+        &raffle_info,
+        idx,
+        spl_mint,
+        token_program,
+        system_program,
+        program_id,
+    );
+    let mut amount = 0u64;
+    for escrow in &shard_escrows {
+        proceeds_accounts.transfer(escrow, treasury_token_account, escrow.proceeds)?;
+        amount = amount
+            .checked_add(escrow.proceeds)
+            .ok_or(RaffleError::NumericOverflow)?;
     }
 
-    ctx.accounts.raffle_account.is_withdrawn = true;
+    raffle_account.load_mut()?.is_withdrawn = 1;
 
-    Ok(())
+    Ok(amount)
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(idx: u32)]
 pub struct WithdrawRaffleCtx<'info> {
@@ -121,24 +134,16 @@ pub struct WithdrawRaffleCtx<'info> {
         bump,
         has_one = authority,
     )]
-    pub raffle_account: Box<Account<'info, RaffleAccount>>,
+    pub raffle_account: AccountLoader<'info, RaffleAccountV2>,
 
-    /// CHECK: token mint as SOL or USDC
-    pub spl_mint: Box<Account<'info, Mint>>,
+    /// CHECK: token mint as SOL, SPL or Token-2022
+    pub spl_mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// CHECK: token account for receive funds
     #[account(mut)]
     pub treasury_token_account: UncheckedAccount<'info>,
 
-    /// CHECK: token account for escrow
-    #[account(
-        mut,
-        seeds = [PREFIX, raffle_account.key().as_ref(), ESCROW],
-        bump
-    )]
-    pub escrow_account: UncheckedAccount<'info>,
-
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub rent: Sysvar<'info, Rent>,
 }
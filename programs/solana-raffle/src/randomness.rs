@@ -0,0 +1,333 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{keccak, slot_hashes::MAX_ENTRIES},
+};
+use arrayref::array_ref;
+
+use crate::{
+    constants::{ORACLE_TIMEOUT_SLOTS, RANDOMNESS_SLOT_DELAY},
+    error::RaffleError,
+    states::RaffleRandomness,
+    utils::draw_winners,
+};
+
+/// ORAO VRF program, also deployed by the local mock oracle
+pub mod oracle {
+    use anchor_lang::declare_id;
+
+    declare_id!("VRFzZoJdhFWL8rkvu87LpKM3RbcVezpMEc6X5GVDr7y");
+}
+
+/// Source of randomness used to draw raffle winners
+pub trait RandomnessProvider {
+    /// Commit to randomness which is not known yet, filling request details
+    fn commit(&self, randomness: &mut RaffleRandomness, slot: u64) -> Result<()>;
+
+    /// Read committed randomness once it is available
+    fn reveal(&self, randomness: &RaffleRandomness, slot: u64) -> Result<[u8; 64]>;
+}
+
+/// Commit to the hash of a future slot, read back from `SlotHashes` sysvar
+pub struct SlotHashProvider<'a, 'info> {
+    pub slot_hashes: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> RandomnessProvider for SlotHashProvider<'a, 'info> {
+    fn commit(&self, randomness: &mut RaffleRandomness, slot: u64) -> Result<()> {
+        randomness.request = Pubkey::default();
+        randomness.slot = slot
+            .checked_add(RANDOMNESS_SLOT_DELAY)
+            .ok_or(RaffleError::NumericOverflow)?;
+
+        Ok(())
+    }
+
+    fn reveal(&self, randomness: &RaffleRandomness, slot: u64) -> Result<[u8; 64]> {
+        require!(slot > randomness.slot, RaffleError::RandomnessNotReady);
+
+        // Committed slot may have been skipped, the next produced slot stands in for it so
+        // the leader can't re-roll the draw by skipping its slot
+        let hash = find_slot_hash(&self.slot_hashes.data.borrow(), randomness.slot).ok_or(
+            if is_slot_hash_expired(randomness, slot) {
+                RaffleError::RandomnessExpired
+            } else {
+                RaffleError::RandomnessNotReady
+            },
+        )?;
+
+        let mut value = [0u8; 64];
+        value[..32].copy_from_slice(&hash);
+        Ok(value)
+    }
+}
+
+/// Commit to a VRF request account of the oracle program, using ORAO-style
+/// `Randomness { seed, randomness, .. }` account layout
+pub struct OracleProvider<'a, 'info> {
+    pub request: &'a AccountInfo<'info>,
+    /// Raffle the request is bound to through its seed
+    pub raffle: Pubkey,
+}
+
+impl<'a, 'info> OracleProvider<'a, 'info> {
+    /// Anchor discriminator of the `Randomness` account, `sha256("account:Randomness")[..8]`
+    const DISCRIMINATOR: [u8; 8] = [188, 96, 216, 248, 93, 94, 49, 112];
+    const SEED_OFFSET: usize = 8;
+    const RANDOMNESS_OFFSET: usize = 8 + 32;
+
+    /// Account data, once checked to be a randomness account of the oracle
+    fn data(&self) -> Result<std::cell::Ref<'_, &'info mut [u8]>> {
+        require!(*self.request.owner == oracle::ID, RaffleError::InvalidOwner);
+
+        let data = self.request.data.borrow();
+        require!(
+            oracle_randomness(&data).is_some(),
+            RaffleError::InvalidRandomnessAccount
+        );
+
+        Ok(data)
+    }
+
+    fn read_randomness(&self) -> Result<[u8; 64]> {
+        let data = self.data()?;
+        Ok(*array_ref![data, Self::RANDOMNESS_OFFSET, 64])
+    }
+
+    /// Check oracle already answered the request
+    pub fn is_fulfilled(&self) -> Result<bool> {
+        Ok(self.read_randomness()?.iter().any(|byte| *byte != 0))
+    }
+
+    /// Seed used by the oracle request
+    pub fn seed(&self) -> Result<[u8; 32]> {
+        let data = self.data()?;
+        Ok(*array_ref![data, Self::SEED_OFFSET, 32])
+    }
+}
+
+impl<'a, 'info> RandomnessProvider for OracleProvider<'a, 'info> {
+    fn commit(&self, randomness: &mut RaffleRandomness, slot: u64) -> Result<()> {
+        // Request must be made for this raffle attempt, so it was opened for it
+        require!(
+            self.seed()? == oracle_seed(&self.raffle, randomness.attempt),
+            RaffleError::InvalidRandomnessAccount
+        );
+
+        // Request must be still pending, otherwise result is already known
+        let value = self.read_randomness()?;
+        require!(
+            value.iter().all(|byte| *byte == 0),
+            RaffleError::InvalidRandomnessAccount
+        );
+
+        randomness.request = self.request.key();
+        randomness.slot = slot;
+
+        Ok(())
+    }
+
+    fn reveal(&self, randomness: &RaffleRandomness, _slot: u64) -> Result<[u8; 64]> {
+        require!(
+            self.request.key() == randomness.request,
+            RaffleError::InvalidRandomnessAccount
+        );
+
+        let value = self.read_randomness()?;
+        require!(
+            value.iter().any(|byte| *byte != 0),
+            RaffleError::RandomnessNotReady
+        );
+
+        Ok(value)
+    }
+}
+
+/// Randomness of oracle request account data, zeroed while pending.
+/// `None` when the data is not a randomness account
+pub fn oracle_randomness(data: &[u8]) -> Option<[u8; 64]> {
+    let offset = OracleProvider::RANDOMNESS_OFFSET;
+    (data.len() >= offset + 64 && data[..8] == OracleProvider::DISCRIMINATOR)
+        .then(|| *array_ref![data, offset, 64])
+}
+
+/// Seed of the oracle request for `attempt` of `raffle`
+pub fn oracle_seed(raffle: &Pubkey, attempt: u8) -> [u8; 32] {
+    keccak::hashv(&[&raffle.to_bytes(), &[attempt]]).to_bytes()
+}
+
+/// Seed of the winner draw, binding revealed randomness to the raffle
+pub fn draw_seed(value: &[u8; 64], raffle: &Pubkey) -> [u8; 32] {
+    keccak::hashv(&[value, &raffle.to_bytes()]).to_bytes()
+}
+
+/// Recompute 1-based winning ticket index of every prize slot from revealed
+/// randomness, so a draw can be verified off-chain
+pub fn recompute_winners(
+    value: &[u8; 64],
+    raffle: &Pubkey,
+    total_sales: u32,
+    total_prizes: u32,
+) -> Vec<u32> {
+    draw_winners(&draw_seed(value, raffle), total_sales, total_prizes)
+        .into_iter()
+        .map(|winner_idx| winner_idx + 1)
+        .collect()
+}
+
+/// Check slot hash committed to is no longer available
+pub fn is_slot_hash_expired(randomness: &RaffleRandomness, slot: u64) -> bool {
+    slot > randomness.slot.saturating_add(MAX_ENTRIES as u64)
+}
+
+/// Check pending oracle request can be replaced by a new one
+pub fn is_oracle_timed_out(randomness: &RaffleRandomness, slot: u64) -> bool {
+    slot > randomness.slot.saturating_add(ORACLE_TIMEOUT_SLOTS)
+}
+
+/// Look up hash of the first produced slot at or after `slot` in raw `SlotHashes`
+/// sysvar data, whose entries are `(slot, hash)` pairs sorted by descending slot.
+///
+/// Returns `None` until a slot at or after `slot` was produced, and once the
+/// oldest entry no longer proves that no earlier slot at or after it was evicted
+pub fn find_slot_hash(data: &[u8], slot: u64) -> Option<[u8; 32]> {
+    const ENTRY_SIZE: usize = 8 + 32;
+
+    if data.len() < 8 {
+        return None;
+    }
+    let len =
+        (u64::from_le_bytes(*array_ref![data, 0, 8]) as usize).min((data.len() - 8) / ENTRY_SIZE);
+
+    let entry_slot = |i: usize| u64::from_le_bytes(*array_ref![data, 8 + i * ENTRY_SIZE, 8]);
+
+    // Number of entries at or after `slot`, the oldest of them is the one looked up
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = (low + high) / 2;
+        if entry_slot(mid) >= slot {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    let idx = low.checked_sub(1)?;
+
+    // Without an older entry, earlier slots at or after `slot` may have been evicted
+    if entry_slot(idx) != slot && low == len {
+        return None;
+    }
+
+    Some(*array_ref![data, 8 + idx * ENTRY_SIZE + 8, 32])
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    #[test]
+    fn oracle_seed_is_bound_to_raffle_and_attempt() {
+        let raffle = Pubkey::new_unique();
+
+        assert_eq!(oracle_seed(&raffle, 0), oracle_seed(&raffle, 0));
+        assert_ne!(oracle_seed(&raffle, 0), oracle_seed(&raffle, 1));
+        assert_ne!(
+            oracle_seed(&raffle, 0),
+            oracle_seed(&Pubkey::new_unique(), 0)
+        );
+    }
+
+    #[test]
+    fn recompute_winners_is_one_based_and_bound_to_raffle() {
+        let value = [7u8; 64];
+        let raffle = Pubkey::new_unique();
+
+        let winners = recompute_winners(&value, &raffle, 50, 3);
+        assert_eq!(winners, recompute_winners(&value, &raffle, 50, 3));
+        assert_eq!(
+            winners,
+            draw_winners(&draw_seed(&value, &raffle), 50, 3)
+                .iter()
+                .map(|idx| idx + 1)
+                .collect::<Vec<_>>()
+        );
+        assert!(winners.iter().all(|&idx| (1..=50).contains(&idx)));
+
+        assert_ne!(
+            winners,
+            recompute_winners(&value, &Pubkey::new_unique(), 50, 3)
+        );
+    }
+
+    /// Raw `SlotHashes` data of `(slot, hash)` entries, hash filled with the slot's low byte
+    fn slot_hashes(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[*slot as u8; 32]);
+        }
+        data
+    }
+
+    #[test]
+    fn find_slot_hash_hit() {
+        let data = slot_hashes(&[110, 108, 105, 103, 100]);
+
+        for slot in [110, 105, 100] {
+            assert_eq!(find_slot_hash(&data, slot), Some([slot as u8; 32]));
+        }
+    }
+
+    #[test]
+    fn find_slot_hash_skipped_slot() {
+        let data = slot_hashes(&[110, 108, 105, 103, 100]);
+
+        // Next produced slot stands in for a skipped one
+        assert_eq!(find_slot_hash(&data, 109), Some([110; 32]));
+        assert_eq!(find_slot_hash(&data, 106), Some([108; 32]));
+        assert_eq!(find_slot_hash(&data, 101), Some([103; 32]));
+    }
+
+    #[test]
+    fn find_slot_hash_miss() {
+        let data = slot_hashes(&[110, 108, 105, 103, 100]);
+
+        // Not produced yet, and evicted
+        for slot in [111, 99] {
+            assert_eq!(find_slot_hash(&data, slot), None);
+        }
+        assert_eq!(find_slot_hash(&slot_hashes(&[]), 100), None);
+        assert_eq!(find_slot_hash(&[], 100), None);
+
+        // Entry count beyond the data is clamped
+        let mut truncated = data.clone();
+        truncated[..8].copy_from_slice(&10u64.to_le_bytes());
+        assert_eq!(find_slot_hash(&truncated, 100), Some([100; 32]));
+    }
+
+    #[test]
+    fn oracle_times_out_after_timeout_slots() {
+        let mut randomness = RaffleRandomness::zeroed();
+        randomness.slot = 100;
+
+        assert!(!is_oracle_timed_out(
+            &randomness,
+            100 + ORACLE_TIMEOUT_SLOTS
+        ));
+        assert!(is_oracle_timed_out(&randomness, 101 + ORACLE_TIMEOUT_SLOTS));
+    }
+
+    #[test]
+    fn oracle_randomness_reads_request_layout() {
+        let mut data = vec![0u8; OracleProvider::RANDOMNESS_OFFSET + 64];
+        assert_eq!(oracle_randomness(&data), None);
+
+        data[..8].copy_from_slice(&OracleProvider::DISCRIMINATOR);
+        assert_eq!(oracle_randomness(&data), Some([0; 64]));
+
+        data[OracleProvider::RANDOMNESS_OFFSET] = 7;
+        assert_eq!(oracle_randomness(&data).unwrap()[0], 7);
+        assert_eq!(oracle_randomness(&data[..data.len() - 1]), None);
+    }
+}
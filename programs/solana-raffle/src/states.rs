@@ -1,6 +1,12 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{ID_LENGTH, MAX_COLLECTIONS};
+use crate::{
+    constants::{
+        BASIS_POINTS, FEE_PERCENT, ID_LENGTH, MAX_COLLECTIONS, MAX_FEE_PERCENT, MAX_FEE_RECIPIENTS,
+        MAX_PRIZES, MAX_SHARDS, MAX_TICKET_PERCENT,
+    },
+    error::RaffleError,
+};
 
 #[account]
 pub struct GlobalAccount {
@@ -9,53 +15,601 @@ pub struct GlobalAccount {
 
     pub total_raffles: u32,
 
+    /// Legacy whitelist, drained into [`WhitelistEntry`] accounts by `migrate_whitelist`
     pub wl_collections: [Pubkey; MAX_COLLECTIONS],
 
-    pub reserved0: u128,
-    pub reserved1: u128,
-    pub reserved2: u128,
+    /// Off-chain key signing purchase permits
+    pub permit_signer: Pubkey,
+
+    /// Platform fee in basis points, snapshot by new raffles.
+    /// Read through [`GlobalAccount::platform_fee_percent`], unset on older deployments
+    pub fee_percent: u16,
+    /// Default per-wallet ticket cap in basis points of total supply, 0 for no cap
+    pub max_ticket_percent: u16,
+    /// Fee of new raffles is split by the global [`FeeConfig`]
+    pub has_fee_config: u8,
+    /// Slice of the fee credited to referrer, in basis points of the fee
+    pub referral_percent: u16,
+    /// Paid from escrow to whoever requests the draw of an ended raffle,
+    /// in basis points of the proceeds
+    pub crank_reward_percent: u16,
+    /// Fee and wallet cap were written by `initialize` or `update_fees`
+    pub fees_initialized: u8,
+    /// Version of the latest global [`FeeConfig`], each split is a new account
+    pub fee_config_version: u32,
+    pub reserved2: [u8; 2],
     pub reserved3: u128,
 }
 
-#[account]
-#[derive(Default)]
-pub struct RaffleAccount {
-    pub idx: u32,
+/// Raffle header, tickets are stored across `total_shards` [`TicketShard`] accounts.
+///
+/// Versioned name gives the zero-copy layout its own discriminator, so raffles
+/// created with the former Borsh `RaffleAccount` fail to load instead of being
+/// read with the wrong layout. Those have to be settled and withdrawn with the
+/// program release which created them before upgrading; their idx is never reused
+/// as `total_raffles` keeps counting up.
+#[account(zero_copy)]
+pub struct RaffleAccountV2 {
     pub authority: Pubkey,
     pub spl_mint: Pubkey,
-    pub mint: Pubkey,
 
     pub price: u64,
+    pub start_date: u64,
+    pub end_date: u64,
+
+    pub idx: u32,
     pub total_supply: u32,
+    /// Sum of shard sales as of the last sync
     pub total_sales: u32,
+    pub min_sales: u32,
+    /// Platform fee snapshot at creation, in basis points
+    pub fee_percent: u16,
+    /// Per-wallet ticket cap in basis points of total supply, 0 for no cap
+    pub max_ticket_percent: u16,
+    pub total_prizes: u8,
+    pub total_shards: u8,
+
+    pub is_deposited: u8,
+    pub is_revealed: u8,
+    pub is_withdrawn: u8,
+    pub is_cancelled: u8,
+    /// Tickets are bought without permit, `uid` derived from buyer wallet
+    pub is_public: u8,
+    /// Fee is split by the raffle [`FeeConfig`] instead of the global one
+    pub has_fee_config: u8,
+    /// Ticket shards created so far, tickets are sold once every shard exists
+    pub created_shards: u8,
+    pub padding: [u8; 3],
+
+    /// Sales of every shard as of the last sync, maps winning index to shard
+    pub shard_sales: [u32; MAX_SHARDS],
+
+    pub randomness: RaffleRandomness,
+    pub prizes: [RafflePrize; MAX_PRIZES],
+
+    /// Crank reward snapshot at creation, in basis points of the proceeds
+    pub crank_reward_percent: u16,
+    /// Referral slice snapshot at creation, in basis points of the fee
+    pub referral_percent: u16,
+    /// Global [`FeeConfig`] version snapshot at creation, 0 when fee goes to vault
+    pub fee_config_version: u32,
+    pub reserved: [u64; 7],
+}
+
+impl GlobalAccount {
+    /// Platform fee, defaults until fees are initialized
+    pub fn platform_fee_percent(&self) -> u16 {
+        if self.fees_initialized != 0 {
+            self.fee_percent
+        } else {
+            FEE_PERCENT
+        }
+    }
+
+    /// Default wallet cap, defaults until fees are initialized
+    pub fn default_max_ticket_percent(&self) -> u16 {
+        if self.fees_initialized != 0 {
+            self.max_ticket_percent
+        } else {
+            MAX_TICKET_PERCENT
+        }
+    }
+
+    /// Validate fee and wallet cap are within hard bounds
+    pub fn validate_fees(
+        fee_percent: u16,
+        max_ticket_percent: u16,
+        referral_percent: u16,
+    ) -> Result<()> {
+        require!(
+            referral_percent as usize <= BASIS_POINTS,
+            RaffleError::InvalidFeeConfig
+        );
+        require!(
+            fee_percent <= MAX_FEE_PERCENT,
+            RaffleError::InvalidFeeConfig
+        );
+        require!(
+            max_ticket_percent as usize <= BASIS_POINTS,
+            RaffleError::InvalidFeeConfig
+        );
+
+        Ok(())
+    }
+}
+
+impl RaffleAccountV2 {
+    pub const SIZE: usize = 8 + std::mem::size_of::<RaffleAccountV2>();
+
+    pub fn prizes(&self) -> &[RafflePrize] {
+        &self.prizes[..self.total_prizes as usize]
+    }
+
+    pub fn prizes_mut(&mut self) -> &mut [RafflePrize] {
+        &mut self.prizes[..self.total_prizes as usize]
+    }
+
+    /// Ticket capacity of a shard, supply remainder goes to the first shards
+    pub fn shard_capacity(&self, shard: u8) -> u32 {
+        let total_shards = self.total_shards as u32;
+        let capacity = self.total_supply / total_shards;
+
+        if (shard as u32) < self.total_supply % total_shards {
+            capacity + 1
+        } else {
+            capacity
+        }
+    }
+
+    /// Refresh sales totals from every ticket shard, passed in shard order
+    pub fn sync_sales(&mut self, raffle: &Pubkey, shards: &[AccountInfo]) -> Result<()> {
+        require!(
+            shards.len() == self.total_shards as usize,
+            RaffleError::InvalidTicketShard
+        );
+
+        let mut total_sales = 0u32;
+        for (idx, shard_info) in shards.iter().enumerate() {
+            let loader = AccountLoader::<TicketShard>::try_from(shard_info)?;
+            let shard = loader.load()?;
+            require!(
+                shard.raffle == *raffle && shard.shard as usize == idx,
+                RaffleError::InvalidTicketShard
+            );
+
+            self.shard_sales[idx] = shard.total_sales;
+            total_sales = total_sales
+                .checked_add(shard.total_sales)
+                .ok_or(RaffleError::NumericOverflow)?;
+        }
+        self.total_sales = total_sales;
+
+        Ok(())
+    }
+
+    /// Map 0-based global ticket index to its shard and index within the shard
+    pub fn locate_ticket(&self, ticket_idx: u32) -> Option<(u8, u32)> {
+        let mut offset = 0u32;
+        for (shard, sales) in self.shard_sales[..self.total_shards as usize]
+            .iter()
+            .enumerate()
+        {
+            if ticket_idx < offset + sales {
+                return Some((shard as u8, ticket_idx - offset));
+            }
+            offset += sales;
+        }
+
+        None
+    }
+
+    /// Validate prize slot is revealed, unclaimed and won by `uid`, whose
+    /// ticket is held by given shard
+    pub fn claimable_prize(
+        &self,
+        shard: &TicketShard,
+        tickets: &[u8],
+        slot: u8,
+        uid: &[u8; ID_LENGTH],
+    ) -> Result<&RafflePrize> {
+        let prize = self
+            .prizes()
+            .get(slot as usize)
+            .ok_or(RaffleError::InvalidPrizeSlot)?;
+        require!(prize.winner_idx > 0, RaffleError::WinnerNotRevealed);
+        require!(prize.is_claimed == 0, RaffleError::PrizeAlreadyClaimed);
+
+        let winner_idx = prize
+            .winner_idx
+            .checked_sub(1)
+            .ok_or(RaffleError::NumericOverflow)?;
+
+        let (winner_shard, shard_idx) = self
+            .locate_ticket(winner_idx)
+            .ok_or(RaffleError::WinnerNotMatched)?;
+        require!(shard.shard == winner_shard, RaffleError::InvalidTicketShard);
+
+        let winner = shard
+            .find_ticket(tickets, shard_idx)
+            .ok_or(RaffleError::WinnerNotMatched)?;
+        require!(winner.uid == *uid, RaffleError::WinnerNotMatched);
+
+        Ok(prize)
+    }
+
+    /// Check raffle was cancelled or ended without reaching minimum sales
+    pub fn is_refundable(&self, now: u64) -> bool {
+        self.is_cancelled != 0 || (now >= self.end_date && self.total_sales < self.min_sales)
+    }
+
+    /// Validate prize slot can be returned to creator, which is the case when
+    /// the raffle is refundable, nobody bought a ticket or the slot has no winner
+    pub fn reclaimable_prize(&self, slot: u8, now: u64) -> Result<&RafflePrize> {
+        let prize = self
+            .prizes()
+            .get(slot as usize)
+            .ok_or(RaffleError::InvalidPrizeSlot)?;
+        require!(prize.is_deposited != 0, RaffleError::PrizeNotDeposited);
+        require!(prize.is_claimed == 0, RaffleError::PrizeAlreadyClaimed);
+
+        let is_unwon = self.is_revealed != 0 && prize.winner_idx == 0;
+        if !(self.is_refundable(now) || is_unwon) {
+            require!(now >= self.end_date, RaffleError::RaffleNotEnded);
+            require!(self.total_sales == 0, RaffleError::RaffleHasSales);
+        }
+
+        Ok(prize)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FeeRecipient {
+    pub wallet: Pubkey,
+    /// Share of the fee in basis points
+    pub share: u16,
+}
+
+/// Split of the platform fee, global or for a single raffle.
+///
+/// Global splits are never rewritten, every change creates a new version
+/// which only raffles created afterwards use
+#[account]
+#[derive(Default)]
+pub struct FeeConfig {
+    /// Raffle using this split, default key for the global split
+    pub raffle: Pubkey,
+    /// Version of the global split, 0 for a raffle split
+    pub version: u32,
+    pub total_recipients: u8,
+    pub recipients: [FeeRecipient; MAX_FEE_RECIPIENTS],
+}
+
+impl FeeConfig {
+    pub const SIZE: usize = 8 + 32 + 4 + 1 + (32 + 2) * MAX_FEE_RECIPIENTS;
+
+    pub fn recipients(&self) -> &[FeeRecipient] {
+        &self.recipients[..self.total_recipients as usize]
+    }
+
+    /// Replace recipients, shares must sum to the whole fee
+    pub fn set(&mut self, raffle: Pubkey, version: u32, recipients: &[FeeRecipient]) -> Result<()> {
+        require!(
+            !recipients.is_empty() && recipients.len() <= MAX_FEE_RECIPIENTS,
+            RaffleError::InvalidFeeConfig
+        );
+        require!(
+            recipients.iter().all(|r| r.share > 0),
+            RaffleError::InvalidFeeConfig
+        );
+        let total_share: usize = recipients.iter().map(|r| r.share as usize).sum();
+        require!(total_share == BASIS_POINTS, RaffleError::InvalidFeeConfig);
+
+        self.raffle = raffle;
+        self.version = version;
+        self.total_recipients = recipients.len() as u8;
+        self.recipients = Default::default();
+        self.recipients[..recipients.len()].copy_from_slice(recipients);
+
+        Ok(())
+    }
+
+    /// Split fee amount by shares, rounding remainder goes to the first recipient
+    pub fn split(&self, fee_amount: u64) -> Result<Vec<(Pubkey, u64)>> {
+        let mut splits = self
+            .recipients()
+            .iter()
+            .map(|r| {
+                let amount = (fee_amount as u128)
+                    .checked_mul(r.share as u128)
+                    .ok_or(RaffleError::NumericOverflow)?
+                    / BASIS_POINTS as u128;
+                Ok((r.wallet, amount as u64))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let distributed: u64 = splits.iter().map(|(_, amount)| amount).sum();
+        if let Some(first) = splits.first_mut() {
+            first.1 += fee_amount - distributed;
+        }
+
+        Ok(splits)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RandomnessSource {
+    #[default]
+    None,
+    SlotHash,
+    Oracle,
+}
+
+impl From<u8> for RandomnessSource {
+    fn from(source: u8) -> Self {
+        match source {
+            1 => Self::SlotHash,
+            2 => Self::Oracle,
+            _ => Self::None,
+        }
+    }
+}
+
+/// Randomness committed to by `request_randomness` and consumed by `settle_randomness`
+#[zero_copy]
+pub struct RaffleRandomness {
+    /// Slot whose hash is used, or slot of the oracle request
+    pub slot: u64,
+    /// Unix timestamp when randomness was settled
+    pub timestamp: u64,
+    /// Oracle request account, unused for slot hash source
+    pub request: Pubkey,
+    /// Randomness revealed by the provider
+    pub value: [u8; 64],
+    /// Seed of the winner draw, `keccak(value || raffle)`
+    pub seed: [u8; 32],
+    /// [`RandomnessSource`] discriminant
+    pub source: u8,
+    /// Index of the request, bumped on every re-request, oracle seeds derive from it
+    pub attempt: u8,
+    pub padding: [u8; 6],
+}
+
+impl RaffleRandomness {
+    pub fn source(&self) -> RandomnessSource {
+        self.source.into()
+    }
+
+    /// Oracle request committed to, if any
+    pub fn oracle_request(&self) -> Option<Pubkey> {
+        (self.source() == RandomnessSource::Oracle).then_some(self.request)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PrizeKind {
+    #[default]
+    Nft,
+    Pnft,
+    Token,
+    Sol,
+    Nft2022,
+    Core,
+    Cnft,
+}
+
+impl From<u8> for PrizeKind {
+    fn from(kind: u8) -> Self {
+        match kind {
+            1 => Self::Pnft,
+            2 => Self::Token,
+            3 => Self::Sol,
+            4 => Self::Nft2022,
+            5 => Self::Core,
+            6 => Self::Cnft,
+            _ => Self::Nft,
+        }
+    }
+}
+
+#[zero_copy]
+pub struct RafflePrize {
+    pub amount: u64,
+    pub mint: Pubkey,
     pub winner_idx: u32,
 
-    pub start_date: u64,
-    pub end_date: u64,
+    /// [`PrizeKind`] discriminant
+    pub kind: u8,
+    pub is_deposited: u8,
+    pub is_claimed: u8,
+    pub padding: u8,
+}
 
-    pub is_deposited: bool,
-    pub is_claimed: bool,
-    pub is_withdrawn: bool,
+impl RafflePrize {
+    pub fn kind(&self) -> PrizeKind {
+        self.kind.into()
+    }
+}
 
-    pub reserved0: u128,
-    pub reserved1: u128,
-    pub reserved2: u128,
-    pub reserved3: u128,
+/// Leaf of a compressed nft prize held by raffle escrow, at `[PREFIX, raffle, PRIZE, slot]`
+#[account]
+#[derive(Default)]
+pub struct CompressedPrize {
+    pub merkle_tree: Pubkey,
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
+
+/// Ticket shard header, followed in account data by `total_tickets`
+/// [`RaffleTicket`] entries which grow by realloc on every purchase
+#[account(zero_copy)]
+pub struct TicketShard {
+    pub raffle: Pubkey,
+
+    pub capacity: u32,
+    pub total_sales: u32,
+    pub total_tickets: u32,
+    pub shard: u8,
+    /// Bump of the shard proceeds escrow `[PREFIX, raffle, ESCROW, shard]`
+    pub escrow_bump: u8,
+    pub padding: [u8; 2],
+
+    /// Fee held by the shard proceeds escrow until swept to its recipients.
+    /// Paying it on purchase would make every buyer pass the split and lock
+    /// its recipient accounts, so fee is only credited here
+    pub fees: u64,
 
-    pub tickets: Vec<RaffleTicket>,
+    pub reserved: [u64; 3],
 }
 
-impl RaffleAccount {
-    pub fn size(total_supply: u32) -> usize {
-        4 + 32 * 3 + 8 + 4 * 3 + 8 * 2 + 1 * 3 + 16 * 4 + (4 + (12) * (total_supply as usize))
+impl TicketShard {
+    /// Offset of ticket entries in account data
+    pub const TICKETS_OFFSET: usize = 8 + std::mem::size_of::<TicketShard>();
+
+    /// Account space holding `total_tickets` ticket entries
+    pub fn space(total_tickets: usize) -> usize {
+        Self::TICKETS_OFFSET + RaffleTicket::SIZE * total_tickets
+    }
+
+    /// Split shard account data into header and ticket region
+    pub fn split(data: &[u8]) -> (&TicketShard, &[u8]) {
+        let (header, tickets) = data.split_at(Self::TICKETS_OFFSET);
+        (bytemuck::from_bytes(&header[8..]), tickets)
+    }
+
+    pub fn split_mut(data: &mut [u8]) -> (&mut TicketShard, &mut [u8]) {
+        let (header, tickets) = data.split_at_mut(Self::TICKETS_OFFSET);
+        (bytemuck::from_bytes_mut(&mut header[8..]), tickets)
+    }
+
+    /// Read ticket entry at `pos` from ticket region
+    pub fn ticket(&self, tickets: &[u8], pos: usize) -> Option<RaffleTicket> {
+        if pos >= self.total_tickets as usize {
+            return None;
+        }
+
+        let offset = pos * RaffleTicket::SIZE;
+        let bytes = tickets.get(offset..offset + RaffleTicket::SIZE)?;
+        Some(bytemuck::pod_read_unaligned(bytes))
+    }
+
+    /// Find purchase entry holding 0-based ticket index within the shard
+    pub fn find_ticket(&self, tickets: &[u8], ticket_idx: u32) -> Option<RaffleTicket> {
+        // Entries are appended in sales order, so start indexes are sorted
+        let (mut low, mut high) = (0, self.total_tickets as usize);
+        while low < high {
+            let mid = (low + high) / 2;
+            if self.ticket(tickets, mid)?.start_idx <= ticket_idx {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        let ticket = self.ticket(tickets, low.checked_sub(1)?)?;
+
+        (ticket_idx - ticket.start_idx < ticket.amount).then_some(ticket)
+    }
+
+    /// Append ticket range to ticket region, which must already be reallocated
+    pub fn push_ticket(
+        &mut self,
+        tickets: &mut [u8],
+        uid: [u8; ID_LENGTH],
+        amount: u32,
+    ) -> Result<()> {
+        let total_sales = self
+            .total_sales
+            .checked_add(amount)
+            .ok_or(RaffleError::NumericOverflow)?;
+        require!(total_sales <= self.capacity, RaffleError::SupplyLimitExceed);
+
+        let ticket = RaffleTicket {
+            start_idx: self.total_sales,
+            amount,
+            uid,
+        };
+        let offset = self.total_tickets as usize * RaffleTicket::SIZE;
+        tickets
+            .get_mut(offset..offset + RaffleTicket::SIZE)
+            .ok_or(RaffleError::NumericOverflow)?
+            .copy_from_slice(bytemuck::bytes_of(&ticket));
+
+        self.total_tickets = self
+            .total_tickets
+            .checked_add(1)
+            .ok_or(RaffleError::NumericOverflow)?;
+        self.total_sales = total_sales;
+
+        Ok(())
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Eq, PartialEq)]
+/// Purchase of `amount` consecutive tickets starting at `start_idx`
+#[zero_copy]
 pub struct RaffleTicket {
+    pub start_idx: u32,
+    pub amount: u32,
     pub uid: [u8; ID_LENGTH],
 }
 
+impl RaffleTicket {
+    pub const SIZE: usize = std::mem::size_of::<RaffleTicket>();
+}
+
+/// Referral rewards of a referrer in a single payment mint, held by the
+/// escrow at `[PREFIX, referral_account, ESCROW]` until claimed
+#[account]
+#[derive(Default)]
+pub struct ReferralAccount {
+    pub referrer: Pubkey,
+    pub mint: Pubkey,
+
+    /// Total rewards credited by ticket purchases
+    pub accrued: u64,
+    /// Total rewards paid out by `claim_referral`
+    pub claimed: u64,
+
+    pub reserved: [u64; 4],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum WhitelistKind {
+    #[default]
+    Collection,
+    Creator,
+}
+
+impl From<u8> for WhitelistKind {
+    fn from(kind: u8) -> Self {
+        match kind {
+            1 => Self::Creator,
+            _ => Self::Collection,
+        }
+    }
+}
+
+/// Whitelisted prize collection at `[PREFIX, WHITELIST, key]`
+#[account]
+#[derive(Default)]
+pub struct WhitelistEntry {
+    /// Verified collection key, or verified first creator for legacy collections
+    pub key: Pubkey,
+    pub added_by: Pubkey,
+    pub added_at: i64,
+    pub is_enabled: u8,
+    /// [`WhitelistKind`] discriminant
+    pub kind: u8,
+
+    pub reserved: [u8; 30],
+}
+
+impl WhitelistEntry {
+    pub fn kind(&self) -> WhitelistKind {
+        self.kind.into()
+    }
+}
+
 #[account]
 #[derive(Default)]
 pub struct UserAccount {
@@ -63,9 +617,190 @@ pub struct UserAccount {
     pub authority: Pubkey,
     pub uid: [u8; ID_LENGTH],
     pub amount: u32,
+    pub deposited: u64,
 
-    pub reserved0: u128,
+    /// Nonce of the last purchase permit used
+    pub permit_nonce: u64,
     pub reserved1: u128,
     pub reserved2: u128,
     pub reserved3: u128,
 }
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    fn raffle_with_sales(shard_sales: &[u32]) -> RaffleAccountV2 {
+        let mut raffle = RaffleAccountV2::zeroed();
+        raffle.total_shards = shard_sales.len() as u8;
+        raffle.shard_sales[..shard_sales.len()].copy_from_slice(shard_sales);
+        raffle
+    }
+
+    #[test]
+    fn locate_ticket_across_shards() {
+        let raffle = raffle_with_sales(&[3, 0, 2]);
+
+        assert_eq!(raffle.locate_ticket(0), Some((0, 0)));
+        assert_eq!(raffle.locate_ticket(2), Some((0, 2)));
+        // Empty shard is skipped
+        assert_eq!(raffle.locate_ticket(3), Some((2, 0)));
+        assert_eq!(raffle.locate_ticket(4), Some((2, 1)));
+        assert_eq!(raffle.locate_ticket(5), None);
+
+        assert_eq!(raffle_with_sales(&[]).locate_ticket(0), None);
+    }
+
+    /// Shard header and ticket region holding `(start_idx, amount)` ranges
+    fn shard_with_tickets(ranges: &[(u32, u32)]) -> (TicketShard, Vec<u8>) {
+        let mut shard = TicketShard::zeroed();
+        shard.total_tickets = ranges.len() as u32;
+
+        let mut tickets = Vec::new();
+        for (i, &(start_idx, amount)) in ranges.iter().enumerate() {
+            let ticket = RaffleTicket {
+                start_idx,
+                amount,
+                uid: [i as u8; ID_LENGTH],
+            };
+            tickets.extend_from_slice(bytemuck::bytes_of(&ticket));
+        }
+        (shard, tickets)
+    }
+
+    #[test]
+    fn find_ticket_range_bounds() {
+        let (shard, tickets) = shard_with_tickets(&[(0, 2), (2, 1), (3, 4)]);
+        let owner = |idx| shard.find_ticket(&tickets, idx).map(|t| t.uid[0]);
+
+        assert_eq!(owner(0), Some(0));
+        assert_eq!(owner(1), Some(0));
+        assert_eq!(owner(2), Some(1));
+        assert_eq!(owner(3), Some(2));
+        assert_eq!(owner(6), Some(2));
+        assert_eq!(owner(7), None);
+    }
+
+    #[test]
+    fn find_ticket_gaps() {
+        let (shard, tickets) = shard_with_tickets(&[(0, 2), (5, 1)]);
+        let owner = |idx| shard.find_ticket(&tickets, idx).map(|t| t.uid[0]);
+
+        assert_eq!(owner(2), None);
+        assert_eq!(owner(4), None);
+        assert_eq!(owner(5), Some(1));
+
+        let (empty, tickets) = shard_with_tickets(&[]);
+        assert!(empty.find_ticket(&tickets, 0).is_none());
+    }
+
+    #[test]
+    fn find_ticket_matches_linear_scan() {
+        // Ranges of varying size with an occasional gap, more than one binary search step deep
+        let mut ranges = Vec::new();
+        let mut start_idx = 0;
+        for i in 0..100u32 {
+            let amount = i % 7 + 1;
+            ranges.push((start_idx, amount));
+            start_idx += amount + u32::from(i % 5 == 0);
+        }
+        let (shard, tickets) = shard_with_tickets(&ranges);
+
+        for idx in 0..start_idx + 2 {
+            let expected = ranges
+                .iter()
+                .position(|&(start, amount)| idx >= start && idx < start + amount);
+            let found = shard.find_ticket(&tickets, idx).map(|t| t.start_idx);
+
+            assert_eq!(found, expected.map(|i| ranges[i].0), "ticket {idx}");
+        }
+    }
+
+    #[test]
+    fn push_ticket_appends_ranges() {
+        let mut shard = TicketShard::zeroed();
+        shard.capacity = 5;
+        let mut tickets = vec![0u8; RaffleTicket::SIZE * 3];
+
+        shard.push_ticket(&mut tickets, [1; ID_LENGTH], 2).unwrap();
+        shard.push_ticket(&mut tickets, [2; ID_LENGTH], 3).unwrap();
+        assert!(shard.push_ticket(&mut tickets, [3; ID_LENGTH], 1).is_err());
+
+        assert_eq!(shard.total_sales, 5);
+        assert_eq!(shard.find_ticket(&tickets, 1).unwrap().uid, [1; ID_LENGTH]);
+        assert_eq!(shard.find_ticket(&tickets, 2).unwrap().start_idx, 2);
+    }
+
+    fn fee_config(shares: &[u16]) -> FeeConfig {
+        let recipients: Vec<_> = shares
+            .iter()
+            .map(|&share| FeeRecipient {
+                wallet: Pubkey::new_unique(),
+                share,
+            })
+            .collect();
+        let mut config = FeeConfig::default();
+        config.set(Pubkey::default(), 1, &recipients).unwrap();
+        config
+    }
+
+    #[test]
+    fn split_fee_by_shares() {
+        let config = fee_config(&[5000, 3000, 2000]);
+        let amounts: Vec<_> = config
+            .split(1000)
+            .unwrap()
+            .iter()
+            .map(|(_, amount)| *amount)
+            .collect();
+
+        assert_eq!(amounts, [500, 300, 200]);
+    }
+
+    #[test]
+    fn split_fee_dust_goes_to_first_recipient() {
+        let config = fee_config(&[3333, 3333, 3334]);
+        let splits = config.split(10).unwrap();
+
+        assert_eq!(splits[0].0, config.recipients[0].wallet);
+        let amounts: Vec<_> = splits.iter().map(|(_, amount)| *amount).collect();
+        assert_eq!(amounts, [4, 3, 3]);
+        assert_eq!(amounts.iter().sum::<u64>(), 10);
+
+        // Amounts below one unit per recipient all go to the first
+        let amounts: Vec<_> = config.split(1).unwrap().iter().map(|s| s.1).collect();
+        assert_eq!(amounts, [1, 0, 0]);
+    }
+
+    #[test]
+    fn fee_shares_must_sum_to_whole_fee() {
+        let recipient = |share| FeeRecipient {
+            wallet: Pubkey::new_unique(),
+            share,
+        };
+        let mut config = FeeConfig::default();
+
+        assert!(config.set(Pubkey::default(), 1, &[]).is_err());
+        assert!(config
+            .set(Pubkey::default(), 1, &[recipient(5000), recipient(4999)])
+            .is_err());
+        assert!(config
+            .set(Pubkey::default(), 1, &[recipient(10000), recipient(0)])
+            .is_err());
+    }
+
+    #[test]
+    fn fees_default_until_initialized() {
+        // Deployment initialized before fee fields existed
+        let mut global = GlobalAccount::deserialize(&mut &[0u8; 1024][..]).unwrap();
+        assert_eq!(global.platform_fee_percent(), FEE_PERCENT);
+        assert_eq!(global.default_max_ticket_percent(), MAX_TICKET_PERCENT);
+
+        // Zero is honoured once set by `update_fees`
+        global.fees_initialized = 1;
+        assert_eq!(global.platform_fee_percent(), 0);
+        assert_eq!(global.default_max_ticket_percent(), 0);
+    }
+}
@@ -1,32 +1,59 @@
-use crate::error::RaffleError;
+use crate::{
+    bubblegum::MetadataArgs,
+    constants::{ESCROW, ID_LENGTH, PREFIX, RAFFLE, WHITELIST},
+    error::RaffleError,
+    states::{TicketShard, WhitelistEntry, WhitelistKind},
+};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{
+    keccak,
     program::invoke_signed,
     program_memory::sol_memcmp,
     program_pack::{IsInitialized, Pack},
     pubkey::PUBKEY_BYTES,
     system_instruction,
 };
-use anchor_spl::token::{
-    spl_token::{
-        id as spl_token_id, instruction::initialize_account2, state::Account as SplAccount,
+use anchor_spl::{
+    token::spl_token::{self, native_mint},
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            non_transferable::NonTransferable, permanent_delegate::PermanentDelegate,
+            transfer_fee::TransferFeeConfig, BaseStateWithExtensions, ExtensionType,
+            StateWithExtensions,
+        },
+        instruction::initialize_account2,
+        state::{Account as SplAccount, Mint as SplMint},
     },
-    Mint, Token,
+    token_interface::{self, Mint, TokenInterface, TransferChecked},
 };
+use arrayref::array_ref;
 use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
 
 pub fn is_zero_account(account_info: &AccountInfo) -> bool {
     account_info.data.borrow().iter().all(|byte| byte.eq(&0))
 }
 
+/// User id of a wallet in public raffles, so user PDA is keyed by wallet
+pub fn wallet_uid(wallet: &Pubkey) -> [u8; ID_LENGTH] {
+    *array_ref![wallet.as_ref(), 0, ID_LENGTH]
+}
+
 pub fn bump(seeds: &[&[u8]], program_id: &Pubkey) -> u8 {
     let (_found_key, bump) = Pubkey::find_program_address(seeds, program_id);
     bump
 }
 
+/// Check token account of either token program, extensions are skipped
 pub fn assert_is_ata(ata: &AccountInfo, wallet: &Pubkey, mint: &Pubkey) -> Result<SplAccount> {
-    assert_owned_by(ata, &spl_token_id())?;
-    let ata_account: SplAccount = assert_initialized(ata)?;
+    if *ata.owner != spl_token_2022::ID {
+        assert_owned_by(ata, &spl_token::ID)?;
+    }
+    let ata_account = StateWithExtensions::<SplAccount>::unpack(&ata.data.borrow())?.base;
+    require!(
+        ata_account.is_initialized(),
+        RaffleError::UninitializedAccount
+    );
     assert_keys_equal(ata_account.owner, *wallet)?;
     assert_keys_equal(ata_account.mint, *mint)?;
 
@@ -118,12 +145,66 @@ pub fn create_or_allocate_account_raw<'a>(
     Ok(())
 }
 
+/// Amount withheld from `amount` by the transfer fee extension of Token-2022 mints
+pub fn transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+
+    let data = mint.data.borrow();
+    let mint_state = StateWithExtensions::<SplMint>::unpack(&data)?;
+    match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or_else(|| error!(RaffleError::NumericOverflow)),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Check Token-2022 nft can be held by a prize escrow: one unit, no decimals,
+/// and no extension letting anyone else move or withhold it
+pub fn assert_escrowable_mint(mint: &AccountInfo) -> Result<()> {
+    require!(
+        transfer_fee(mint, 1)? == 0,
+        RaffleError::UnsupportedMintExtension
+    );
+
+    let data = mint.data.borrow();
+    let mint_state = StateWithExtensions::<SplMint>::unpack(&data)?;
+    require!(
+        mint_state.base.supply == 1 && mint_state.base.decimals == 0,
+        RaffleError::InvalidMint
+    );
+    require!(
+        mint_state.get_extension::<NonTransferable>().is_err(),
+        RaffleError::UnsupportedMintExtension
+    );
+    if let Ok(permanent_delegate) = mint_state.get_extension::<PermanentDelegate>() {
+        require!(
+            Option::<Pubkey>::from(permanent_delegate.delegate).is_none(),
+            RaffleError::UnsupportedMintExtension
+        );
+    }
+
+    Ok(())
+}
+
+/// Space of a token account of `mint`, with the extensions its mint requires
+pub fn token_account_space(mint: &AccountInfo) -> Result<usize> {
+    let data = mint.data.borrow();
+    let mint_state = StateWithExtensions::<SplMint>::unpack(&data)?;
+    let extensions =
+        ExtensionType::get_required_init_account_extensions(&mint_state.get_extension_types()?);
+
+    Ok(ExtensionType::get_account_len::<SplAccount>(&extensions))
+}
+
 pub fn create_program_token_account_if_not_present<'a>(
     payment_account: &UncheckedAccount<'a>,
     system_program: &Program<'a, System>,
     fee_payer: &AccountInfo<'a>,
-    token_program: &Program<'a, Token>,
-    treasury_mint: &anchor_lang::prelude::Account<'a, Mint>,
+    token_program: &Interface<'a, TokenInterface>,
+    treasury_mint: &InterfaceAccount<'a, Mint>,
     owner: &AccountInfo<'a>,
     rent: &Sysvar<'a, Rent>,
     signer_seeds: &[&[u8]],
@@ -137,7 +218,7 @@ pub fn create_program_token_account_if_not_present<'a>(
             &rent.to_account_info(),
             system_program,
             fee_payer,
-            SplAccount::LEN,
+            token_account_space(&treasury_mint.to_account_info())?,
             fee_seeds,
             signer_seeds,
         )?;
@@ -162,15 +243,316 @@ pub fn create_program_token_account_if_not_present<'a>(
     Ok(())
 }
 
+/// Proceeds escrow of a ticket shard, `[PREFIX, raffle, ESCROW, shard]`, holding
+/// the shard's ticket proceeds along with its fees until they are swept
+pub struct ShardEscrow<'a, 'info> {
+    pub account: &'a AccountInfo<'info>,
+    pub shard: u8,
+    pub bump: u8,
+    /// Escrow balance not counting unswept fees
+    pub proceeds: u64,
+    pub fees: u64,
+}
+
+/// Proceeds escrows of every ticket shard, in the order of `shards` which the
+/// caller already checked with [`RaffleAccountV2::sync_sales`]
+///
+/// [`RaffleAccountV2::sync_sales`]: crate::states::RaffleAccountV2::sync_sales
+pub fn shard_escrows<'a, 'info>(
+    raffle: &Pubkey,
+    spl_mint: &Pubkey,
+    shards: &[AccountInfo<'info>],
+    escrows: &'a [AccountInfo<'info>],
+    program_id: &Pubkey,
+) -> Result<Vec<ShardEscrow<'a, 'info>>> {
+    require!(
+        escrows.len() == shards.len(),
+        RaffleError::InvalidShardEscrow
+    );
+
+    shards
+        .iter()
+        .zip(escrows)
+        .map(|(shard_info, escrow)| {
+            let loader = AccountLoader::<TicketShard>::try_from(shard_info)?;
+            let shard = loader.load()?;
+            let escrow_key = Pubkey::create_program_address(
+                &[
+                    PREFIX,
+                    raffle.as_ref(),
+                    ESCROW,
+                    &[shard.shard],
+                    &[shard.escrow_bump],
+                ],
+                program_id,
+            )
+            .map_err(|_| RaffleError::InvalidShardEscrow)?;
+            require!(
+                escrow.key() == escrow_key,
+                RaffleError::InvalidShardEscrow
+            );
+
+            // Escrow of a shard nobody bought from is never created as a token account
+            let balance = if *spl_mint == native_mint::id() {
+                escrow.lamports()
+            } else if escrow.data_is_empty() || *escrow.owner == System::id() {
+                0
+            } else {
+                assert_is_ata(escrow, raffle, spl_mint)?.amount
+            };
+
+            Ok(ShardEscrow {
+                account: escrow,
+                shard: shard.shard,
+                bump: shard.escrow_bump,
+                proceeds: balance.saturating_sub(shard.fees),
+                fees: shard.fees,
+            })
+        })
+        .collect()
+}
+
+/// Accounts paying out of shard proceeds escrows. SOL escrows sign for
+/// themselves, SPL escrows are token accounts owned by the raffle
+pub struct ProceedsAccounts<'a, 'info> {
+    pub raffle: &'a AccountInfo<'info>,
+    pub idx: u32,
+    pub raffle_bump: u8,
+    pub spl_mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub system_program: &'a Program<'info, System>,
+}
+
+impl<'a, 'info> ProceedsAccounts<'a, 'info> {
+    pub fn new(
+        raffle: &'a AccountInfo<'info>,
+        idx: u32,
+        spl_mint: &'a InterfaceAccount<'info, Mint>,
+        token_program: &'a Interface<'info, TokenInterface>,
+        system_program: &'a Program<'info, System>,
+        program_id: &Pubkey,
+    ) -> Self {
+        Self {
+            raffle,
+            idx,
+            raffle_bump: bump(&[PREFIX, RAFFLE, &idx.to_be_bytes()], program_id),
+            spl_mint,
+            token_program,
+            system_program,
+        }
+    }
+
+    /// Transfer `amount` out of `escrow` to `to`, a wallet for SOL and a token account otherwise
+    pub fn transfer(
+        &self,
+        escrow: &ShardEscrow<'_, 'info>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        if self.spl_mint.key() == native_mint::id() {
+            let raffle_key = self.raffle.key();
+            invoke_signed(
+                &system_instruction::transfer(escrow.account.key, to.key, amount),
+                &[
+                    escrow.account.clone(),
+                    to.clone(),
+                    self.system_program.to_account_info(),
+                ],
+                &[&[
+                    PREFIX,
+                    raffle_key.as_ref(),
+                    ESCROW,
+                    &[escrow.shard],
+                    &[escrow.bump],
+                ]],
+            )?;
+        } else {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: escrow.account.clone(),
+                        mint: self.spl_mint.to_account_info(),
+                        to: to.clone(),
+                        authority: self.raffle.clone(),
+                    },
+                    &[&[
+                        PREFIX,
+                        RAFFLE,
+                        &self.idx.to_be_bytes(),
+                        &[self.raffle_bump],
+                    ]],
+                ),
+                amount,
+                self.spl_mint.decimals,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Verified collection and first creator of a prize, matched against whitelist
+pub struct PrizeOrigin {
+    pub collection: Option<Pubkey>,
+    pub creator: Option<Pubkey>,
+}
+
+impl PrizeOrigin {
+    pub fn from_metadata(metadata: &Metadata) -> Self {
+        Self {
+            collection: metadata
+                .collection
+                .as_ref()
+                .filter(|collection| collection.verified)
+                .map(|collection| collection.key),
+            creator: metadata
+                .data
+                .creators
+                .as_ref()
+                .and_then(|creators| creators.first())
+                .filter(|creator| creator.verified)
+                .map(|creator| creator.address),
+        }
+    }
+
+    pub fn from_compressed(metadata: &MetadataArgs) -> Self {
+        Self {
+            collection: metadata
+                .collection
+                .as_ref()
+                .filter(|collection| collection.verified)
+                .map(|collection| collection.key),
+            creator: metadata
+                .creators
+                .first()
+                .filter(|creator| creator.verified)
+                .map(|creator| creator.address),
+        }
+    }
+}
+
+/// Check prize origin matches the enabled [`WhitelistEntry`] passed as `whitelist_entry`,
+/// by verified collection or verified first creator depending on entry kind
+pub fn assert_prize_eligible(
+    origin: &PrizeOrigin,
+    whitelist_entry: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(
+        *whitelist_entry.owner,
+        *program_id,
+        RaffleError::InvalidCollection
+    );
+    let entry = WhitelistEntry::try_deserialize(&mut &whitelist_entry.data.borrow()[..])?;
+    require!(entry.is_enabled != 0, RaffleError::InvalidCollection);
+
+    let (entry_key, _) =
+        Pubkey::find_program_address(&[PREFIX, WHITELIST, entry.key.as_ref()], program_id);
+    require_keys_eq!(
+        whitelist_entry.key(),
+        entry_key,
+        RaffleError::InvalidCollection
+    );
+
+    let eligible_key = match entry.kind() {
+        WhitelistKind::Collection => origin.collection,
+        WhitelistKind::Creator => origin.creator,
+    };
+    require!(
+        eligible_key == Some(entry.key),
+        RaffleError::InvalidCollection
+    );
+
+    Ok(())
+}
+
 #[inline(never)]
-pub fn assert_decode_metadata<'info>(
-    nft_mint: &Account<'info, Mint>,
-    metadata_account: &AccountInfo<'info>,
+pub fn assert_decode_metadata(
+    nft_mint: &Pubkey,
+    metadata_account: &AccountInfo,
 ) -> Result<Metadata> {
     assert_keys_equal(*metadata_account.owner, mpl_token_metadata::ID)?;
 
     let metadata = Metadata::from_account_info(metadata_account)?;
-    assert_keys_equal(metadata.mint, nft_mint.key())?;
+    assert_keys_equal(metadata.mint, *nft_mint)?;
 
     Ok(metadata)
 }
+
+/// Draw `count` distinct ticket indices out of `total_sales` using a partial
+/// Fisher-Yates shuffle, so no ticket can win more than one prize slot.
+pub fn draw_winners(seed: &[u8; 32], total_sales: u32, count: u32) -> Vec<u32> {
+    let count = count.min(total_sales);
+    let mut swaps: Vec<(u32, u32)> = Vec::with_capacity(count as usize * 2);
+    let lookup = |swaps: &Vec<(u32, u32)>, key: u32| {
+        swaps
+            .iter()
+            .rev()
+            .find(|(k, _)| *k == key)
+            .map_or(key, |(_, v)| *v)
+    };
+
+    let mut winners = Vec::with_capacity(count as usize);
+    for draw in 0..count {
+        let hash = keccak::hashv(&[seed, &draw.to_le_bytes()]).to_bytes();
+        // 8 bytes keep the modulo bias negligible for any u32 range
+        let rand = u64::from_le_bytes(*array_ref![hash, 0, 8]);
+
+        let pick = draw + (rand % (total_sales - draw) as u64) as u32;
+        let picked = lookup(&swaps, pick);
+        let current = lookup(&swaps, draw);
+        swaps.push((pick, current));
+        swaps.push((draw, picked));
+
+        winners.push(picked);
+    }
+
+    winners
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_winners_are_distinct_and_in_range() {
+        let seed = keccak::hash(b"draw").to_bytes();
+
+        for total_sales in [1, 2, 7, 100] {
+            let winners = draw_winners(&seed, total_sales, 10);
+            assert_eq!(winners.len(), total_sales.min(10) as usize);
+            assert!(winners.iter().all(|&idx| idx < total_sales));
+
+            let mut distinct = winners.clone();
+            distinct.sort_unstable();
+            distinct.dedup();
+            assert_eq!(distinct.len(), winners.len());
+        }
+
+        // Drawing every ticket is a permutation of all sales
+        let mut winners = draw_winners(&seed, 10, 10);
+        winners.sort_unstable();
+        assert_eq!(winners, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn draw_winners_is_deterministic() {
+        let seed = keccak::hash(b"draw").to_bytes();
+        let other = keccak::hash(b"other").to_bytes();
+
+        assert_eq!(draw_winners(&seed, 1000, 5), draw_winners(&seed, 1000, 5));
+        assert_ne!(draw_winners(&seed, 1000, 5), draw_winners(&other, 1000, 5));
+
+        // Fewer slots draw a prefix of the same order
+        assert_eq!(
+            draw_winners(&seed, 1000, 3),
+            draw_winners(&seed, 1000, 5)[..3]
+        );
+        assert!(draw_winners(&seed, 0, 5).is_empty());
+    }
+}